@@ -0,0 +1,159 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use clap::{Args, ValueEnum};
+use serde_json::{json, Value};
+use sign_document_sdk::solana_sdk::pubkey::Pubkey;
+use sign_document_sdk::{ApprovalStatus, FormApproval, SignDocumentClient};
+
+#[derive(Args)]
+pub struct ExportArgs {
+    /// Report format
+    #[arg(long, value_enum, default_value = "csv")]
+    format: ExportFormat,
+
+    /// Only export approvals signed by this key
+    #[arg(long)]
+    signer: Option<Pubkey>,
+
+    /// Only export approvals approved at or after this unix time
+    #[arg(long)]
+    from: Option<i64>,
+
+    /// Only export approvals approved before this unix time
+    #[arg(long)]
+    to: Option<i64>,
+
+    /// Output report file
+    #[arg(long)]
+    out: PathBuf,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ExportFormat {
+    Csv,
+    Json,
+}
+
+/// Report columns, in CSV order
+const COLUMNS: [&str; 13] = [
+    "form_id",
+    "address",
+    "form_hash",
+    "signer",
+    "approved_at",
+    "approved_slot",
+    "status",
+    "revoked_at",
+    "expires_at",
+    "imported",
+    "metadata",
+    "pda_verified",
+    "verification",
+];
+
+pub async fn export(rpc_url: &str, args: ExportArgs) -> Result<()> {
+    let client = SignDocumentClient::new(rpc_url);
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() as i64);
+
+    let mut approvals: Vec<(Pubkey, FormApproval)> = client
+        .fetch_all::<FormApproval>()
+        .await?
+        .into_iter()
+        .filter(|(_, approval)| args.signer.is_none_or(|signer| approval.signer == signer))
+        .filter(|(_, approval)| args.from.is_none_or(|from| approval.approved_at >= from))
+        .filter(|(_, approval)| args.to.is_none_or(|to| approval.approved_at < to))
+        .collect();
+    approvals.sort_by_key(|(address, approval)| (approval.approved_at, *address));
+
+    let rows: Vec<Value> = approvals
+        .iter()
+        .map(|(address, approval)| report_row(address, approval, now))
+        .collect();
+    let report = match args.format {
+        ExportFormat::Csv => to_csv(&rows),
+        ExportFormat::Json => serde_json::to_string_pretty(&rows)?,
+    };
+
+    fs::write(&args.out, report)
+        .with_context(|| format!("failed to write {}", args.out.display()))?;
+    let unverified = rows
+        .iter()
+        .filter(|row| row["verification"] != "valid")
+        .count();
+    println!(
+        "Exported {} approvals to {} ({} not in force)",
+        rows.len(),
+        args.out.display(),
+        unverified
+    );
+    Ok(())
+}
+
+/// One report row; `pda_verified` is false for accounts not at their form's derived address
+fn report_row(address: &Pubkey, approval: &FormApproval, now: i64) -> Value {
+    let pda_verified = approval.is_primary_record(address, &approval.form_id)
+        || approval.is_signer_record(address, &approval.form_id)
+        || approval.is_org_record(address, &approval.form_id);
+    let verification = if !pda_verified {
+        "address_mismatch"
+    } else if approval.is_in_force(now) {
+        "valid"
+    } else if approval.is_revoked() {
+        "revoked"
+    } else if approval.is_expired(now) {
+        "expired"
+    } else {
+        match approval.status {
+            ApprovalStatus::Pending => "pending",
+            ApprovalStatus::Rejected => "rejected",
+            _ => "not_approved",
+        }
+    };
+
+    json!({
+        "form_id": approval.form_id,
+        "address": address.to_string(),
+        "form_hash": hex::encode(approval.form_hash),
+        "signer": approval.signer.to_string(),
+        "approved_at": approval.approved_at,
+        "approved_slot": approval.approved_slot,
+        "status": format!("{:?}", approval.status),
+        "revoked_at": approval.revoked_at,
+        "expires_at": approval.expires_at,
+        "imported": approval.imported,
+        "metadata": approval.metadata,
+        "pda_verified": pda_verified,
+        "verification": verification,
+    })
+}
+
+fn to_csv(rows: &[Value]) -> String {
+    let mut csv = COLUMNS.join(",");
+    csv.push('\n');
+    for row in rows {
+        let fields: Vec<String> = COLUMNS
+            .iter()
+            .map(|column| match &row[*column] {
+                Value::String(value) => csv_field(value),
+                value => value.to_string(),
+            })
+            .collect();
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Quote a field containing separators, quotes or line breaks (RFC 4180)
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
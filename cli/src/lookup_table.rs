@@ -0,0 +1,56 @@
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Result};
+use clap::Args;
+use sign_document_sdk::lookup_table::{bootstrap_lookup_table, hot_accounts};
+use sign_document_sdk::solana_sdk::commitment_config::CommitmentConfig;
+use sign_document_sdk::solana_sdk::signature::{read_keypair_file, Signer};
+use sign_document_sdk::solana_sdk::transaction::Transaction;
+use sign_document_sdk::SignDocumentClient;
+
+#[derive(Args)]
+pub struct BootstrapLookupTableArgs {
+    /// Keypair that pays for and owns the lookup table
+    #[arg(long)]
+    keypair: PathBuf,
+
+    /// Form category whose threshold policy should be included (repeatable)
+    #[arg(long = "category")]
+    categories: Vec<String>,
+}
+
+pub async fn bootstrap(rpc_url: &str, args: BootstrapLookupTableArgs) -> Result<()> {
+    let client = SignDocumentClient::new(rpc_url);
+    let keypair = read_keypair_file(&args.keypair)
+        .map_err(|err| anyhow!("failed to read {}: {err}", args.keypair.display()))?;
+
+    let categories: Vec<&str> = args.categories.iter().map(String::as_str).collect();
+    let addresses = hot_accounts(&client.program_id(), &categories);
+    let recent_slot = client
+        .rpc()
+        .get_slot_with_commitment(CommitmentConfig::finalized())
+        .await?;
+    let (table, instructions) =
+        bootstrap_lookup_table(keypair.pubkey(), keypair.pubkey(), recent_slot, &addresses);
+
+    for instruction in instructions {
+        let blockhash = client.rpc().get_latest_blockhash().await?;
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&keypair.pubkey()),
+            &[&keypair],
+            blockhash,
+        );
+        client
+            .rpc()
+            .send_and_confirm_transaction(&transaction)
+            .await?;
+    }
+
+    println!(
+        "Created lookup table {} with {} addresses",
+        table,
+        addresses.len()
+    );
+    Ok(())
+}
@@ -0,0 +1,59 @@
+//! Command-line tools for operating and auditing sign-document-contract deployments.
+
+mod export;
+mod lookup_table;
+mod offline;
+mod test_vectors;
+
+use clap::{Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(name = "sign-document", version, about)]
+struct Cli {
+    /// Solana RPC endpoint
+    #[arg(
+        long,
+        global = true,
+        env = "SIGN_DOCUMENT_RPC_URL",
+        default_value = "http://127.0.0.1:8899"
+    )]
+    rpc_url: String,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Sign a checkpoint root off-chain as an admin
+    Attest(offline::AttestArgs),
+
+    /// Package an approval and its checkpoint proof into a portable file
+    Bundle(offline::BundleArgs),
+
+    /// Verify a bundle without RPC access
+    VerifyOffline(offline::VerifyOfflineArgs),
+
+    /// Write an audit report of approvals and their verification status as CSV or JSON
+    Export(export::ExportArgs),
+
+    /// Create an address lookup table holding the program's hot accounts
+    BootstrapLookupTable(lookup_table::BootstrapLookupTableArgs),
+
+    /// Write canonical PDAs, accounts, events and hashes for SDK compatibility tests
+    TestVectors(test_vectors::TestVectorsArgs),
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Attest(args) => offline::attest(&cli.rpc_url, args).await,
+        Command::Bundle(args) => offline::bundle(&cli.rpc_url, args).await,
+        Command::VerifyOffline(args) => offline::verify_offline(args),
+        Command::Export(args) => export::export(&cli.rpc_url, args).await,
+        Command::BootstrapLookupTable(args) => lookup_table::bootstrap(&cli.rpc_url, args).await,
+        Command::TestVectors(args) => test_vectors::write(args),
+    }
+}
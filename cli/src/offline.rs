@@ -0,0 +1,155 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{anyhow, bail, Context, Result};
+use clap::Args;
+use sha2::{Digest, Sha256};
+use sign_document_sdk::proof::sign_attestation;
+use sign_document_sdk::solana_sdk::signature::read_keypair_file;
+use sign_document_sdk::{pda, Attestation, Checkpoint, ProofBundle, SignDocumentClient};
+
+#[derive(Args)]
+pub struct AttestArgs {
+    /// Checkpoint to attest
+    #[arg(long)]
+    checkpoint_id: u64,
+
+    /// Admin keypair file
+    #[arg(long)]
+    keypair: PathBuf,
+
+    /// Attestations file to append to (created if missing)
+    #[arg(long)]
+    out: PathBuf,
+}
+
+#[derive(Args)]
+pub struct BundleArgs {
+    /// Form ID of the approval
+    #[arg(long)]
+    form_id: String,
+
+    /// Checkpoint covering the approval
+    #[arg(long)]
+    checkpoint_id: u64,
+
+    /// JSON file of detached attestations collected with `attest`
+    #[arg(long)]
+    attestations: PathBuf,
+
+    /// Output bundle file
+    #[arg(long)]
+    out: PathBuf,
+}
+
+#[derive(Args)]
+pub struct VerifyOfflineArgs {
+    /// Bundle file produced by `bundle`
+    #[arg(long)]
+    bundle: PathBuf,
+
+    /// Document to hash with SHA-256 and compare against the approval
+    #[arg(long, conflicts_with = "hash")]
+    document: Option<PathBuf>,
+
+    /// Expected hex-encoded document hash
+    #[arg(long)]
+    hash: Option<String>,
+}
+
+pub async fn attest(rpc_url: &str, args: AttestArgs) -> Result<()> {
+    let client = SignDocumentClient::new(rpc_url);
+    let keypair = read_keypair_file(&args.keypair)
+        .map_err(|err| anyhow!("failed to read {}: {err}", args.keypair.display()))?;
+
+    let (address, _) = pda::checkpoint_address(args.checkpoint_id, &client.program_id());
+    let checkpoint: Checkpoint = client
+        .fetch_account(&address)
+        .await?
+        .1
+        .with_context(|| format!("checkpoint {} does not exist", args.checkpoint_id))?;
+
+    let mut attestations: Vec<Attestation> = if args.out.exists() {
+        serde_json::from_slice(&fs::read(&args.out)?)?
+    } else {
+        Vec::new()
+    };
+    let attestation = sign_attestation(&keypair, &client.program_id(), &checkpoint);
+    attestations.retain(|existing| existing.signer != attestation.signer);
+    attestations.push(attestation);
+
+    fs::write(&args.out, serde_json::to_vec_pretty(&attestations)?)?;
+    println!(
+        "Attested checkpoint {} ({} attestations in {})",
+        args.checkpoint_id,
+        attestations.len(),
+        args.out.display()
+    );
+    Ok(())
+}
+
+pub async fn bundle(rpc_url: &str, args: BundleArgs) -> Result<()> {
+    let client = SignDocumentClient::new(rpc_url);
+    let attestations: Vec<Attestation> = serde_json::from_slice(
+        &fs::read(&args.attestations)
+            .with_context(|| format!("failed to read {}", args.attestations.display()))?,
+    )?;
+
+    let bundle = client
+        .export_proof(&args.form_id, args.checkpoint_id, attestations)
+        .await?;
+
+    // Refuse to write a bundle that would not verify on the receiving side
+    bundle
+        .verify(None)
+        .context("exported bundle does not verify")?;
+
+    fs::write(&args.out, serde_json::to_vec_pretty(&bundle)?)?;
+    println!(
+        "Wrote offline bundle for {} to {}",
+        args.form_id,
+        args.out.display()
+    );
+    Ok(())
+}
+
+pub fn verify_offline(args: VerifyOfflineArgs) -> Result<()> {
+    let bundle: ProofBundle = serde_json::from_slice(
+        &fs::read(&args.bundle)
+            .with_context(|| format!("failed to read {}", args.bundle.display()))?,
+    )?;
+
+    let expected_hash = match (&args.document, &args.hash) {
+        (Some(path), _) => Some(Sha256::digest(fs::read(path)?).into()),
+        (None, Some(hash)) => Some(parse_hash(hash)?),
+        (None, None) => None,
+    };
+
+    let verified = bundle.verify(expected_hash.as_ref())?;
+    println!("Bundle verified");
+    println!("  form id:     {}", verified.approval.form_id);
+    println!(
+        "  form hash:   {}",
+        hex::encode(verified.approval.form_hash)
+    );
+    println!("  signer:      {}", verified.approval.signer);
+    println!("  approved at: {}", verified.approval.approved_at);
+    println!(
+        "  checkpoint:  {} ({} of {} attestations)",
+        verified.checkpoint.checkpoint_id,
+        verified.attested_by.len(),
+        verified.checkpoint.required_attestations
+    );
+    if expected_hash.is_none() {
+        println!("  note: no document or hash supplied, content was not compared");
+    }
+    Ok(())
+}
+
+fn parse_hash(hash: &str) -> Result<[u8; 32]> {
+    let bytes = hex::decode(hash.trim_start_matches("0x")).context("hash must be hex encoded")?;
+    match bytes.try_into() {
+        Ok(hash) => Ok(hash),
+        Err(_) => bail!("hash must be 32 bytes"),
+    }
+}
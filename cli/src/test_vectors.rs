@@ -0,0 +1,24 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::Args;
+use sign_document_sdk::test_vectors::generate;
+
+#[derive(Args)]
+pub struct TestVectorsArgs {
+    /// File to write the vectors to as JSON
+    #[arg(long)]
+    out: PathBuf,
+}
+
+pub fn write(args: TestVectorsArgs) -> Result<()> {
+    let vectors = generate();
+    fs::write(&args.out, serde_json::to_vec_pretty(&vectors)?)?;
+    println!(
+        "Wrote {} test vectors to {}",
+        vectors.len(),
+        args.out.display()
+    );
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::state::SemVer;
 
 /// Configuration constants for the form approval system
 pub struct Config;
@@ -16,8 +17,258 @@ impl Config {
     /// Seed for the admin config account derivation
     pub const ADMIN_CONFIG_SEED: &'static [u8] = b"admin_config";
     
+    /// Seed for the program config account derivation
+    pub const PROGRAM_CONFIG_SEED: &'static [u8] = b"program_config";
+    
+    /// Seed for the feature gates account derivation
+    pub const FEATURE_GATES_SEED: &'static [u8] = b"feature_gates";
+    
+    /// Seed for checkpoint account derivation
+    pub const CHECKPOINT_SEED: &'static [u8] = b"checkpoint";
+    
+    /// Seed for per-form approval tally PDAs
+    pub const APPROVAL_TALLY_SEED: &'static [u8] = b"approval_tally";
+    
+    /// Seed for per-admin registry record PDAs
+    pub const ADMIN_RECORD_SEED: &'static [u8] = b"admin_record";
+    
+    /// Seed for pending admin invitation PDAs
+    pub const ADMIN_INVITATION_SEED: &'static [u8] = b"admin_invitation";
+    
+    /// Seed for scheduled admin removal PDAs
+    pub const ADMIN_REMOVAL_SEED: &'static [u8] = b"admin_removal";
+    
+    /// Seed for timelocked admin change PDAs
+    pub const ADMIN_CHANGE_SEED: &'static [u8] = b"admin_change";
+    
+    /// Longest delay that can be set on admin changes (30 days)
+    pub const MAX_ADMIN_CHANGE_DELAY: i64 = 30 * 24 * 60 * 60;
+    
+    /// Seed for registered application PDAs
+    pub const REGISTERED_APP_SEED: &'static [u8] = b"registered_app";
+    
+    /// Maximum length for registered application names
+    pub const MAX_APP_NAME_LENGTH: usize = 32;
+    
+    /// Seed for per-category threshold policy PDAs
+    pub const CATEGORY_POLICY_SEED: &'static [u8] = b"category_policy";
+    
+    /// Maximum length for category names
+    pub const MAX_CATEGORY_LENGTH: usize = 32;
+    
+    /// Seed for template version PDAs
+    pub const TEMPLATE_VERSION_SEED: &'static [u8] = b"template_version";
+    
+    /// Maximum length for template IDs
+    pub const MAX_TEMPLATE_ID_LENGTH: usize = 32;
+    
+    /// Maximum length for BCP-47 locale tags
+    pub const MAX_LOCALE_LENGTH: usize = 35;
+    
+    /// Maximum number of locales a template version can allow
+    pub const MAX_TEMPLATE_LOCALES: usize = 8;
+    
+    /// Maximum length for ISO 3166 jurisdiction codes (e.g. `US-CA`)
+    pub const MAX_JURISDICTION_LENGTH: usize = 6;
+    
+    /// Maximum number of jurisdictions a template version can allow
+    pub const MAX_TEMPLATE_JURISDICTIONS: usize = 16;
+    
+    /// Maximum length for the URI of a signed document
+    pub const MAX_DOCUMENT_URI_LENGTH: usize = 200;
+    
+    /// Maximum length for the MIME type of a signed document
+    pub const MAX_CONTENT_TYPE_LENGTH: usize = 64;
+    
+    /// URI schemes a signed document may be located at
+    pub const DOCUMENT_URI_SCHEMES: [&'static str; 3] = ["ipfs://", "ar://", "https://"];
+    
+    /// Seed for the issuer branding account derivation
+    pub const BRANDING_CONFIG_SEED: &'static [u8] = b"branding_config";
+    
+    /// Seed for per-approval verification counter PDAs
+    pub const VERIFICATION_STATS_SEED: &'static [u8] = b"verification_stats";
+    
+    /// Seed for per-verifier verification counter PDAs
+    pub const VERIFIER_RECORD_SEED: &'static [u8] = b"verifier_record";
+    
+    /// Length of the window the per-verifier rate limit applies to, in seconds
+    pub const VERIFICATION_RATE_WINDOW: i64 = 60 * 60;
+    
+    /// Seed for the webhook manifest PDA
+    pub const WEBHOOK_MANIFEST_SEED: &'static [u8] = b"webhook_manifest";
+    
+    /// Seed for the policy catalog PDA
+    pub const POLICY_CATALOG_SEED: &'static [u8] = b"policy_catalog";
+    
+    /// Seed for form hash index PDAs
+    pub const HASH_INDEX_SEED: &'static [u8] = b"hash_index";
+    
+    /// Seed for chunked document upload PDAs
+    pub const DOCUMENT_UPLOAD_SEED: &'static [u8] = b"document_upload";
+    
+    /// Maximum number of pages a document root can cover
+    pub const MAX_DOCUMENT_PAGES: u32 = 65_536;
+    
+    /// Largest document, in bytes, that can be uploaded for on-chain hashing
+    pub const MAX_DOCUMENT_UPLOAD_SIZE: usize = 8192;
+    
+    /// Seed for the global event sequence PDA
+    pub const EVENT_SEQUENCE_SEED: &'static [u8] = b"event_sequence";
+    
+    /// Seed for per-approval history PDAs
+    pub const FORM_HISTORY_SEED: &'static [u8] = b"form_history";
+    
+    /// Most recent history entries kept on-chain; older ones remain committed in the history head
+    pub const MAX_FORM_HISTORY_ENTRIES: usize = 16;
+    
+    /// Seed for the abuse counters PDA
+    pub const ABUSE_COUNTERS_SEED: &'static [u8] = b"abuse_counters";
+    
+    /// Seed for organization PDAs
+    pub const ORGANIZATION_SEED: &'static [u8] = b"organization";
+    
+    /// Seed for form approval PDAs namespaced under an organization
+    pub const ORG_FORM_APPROVAL_SEED: &'static [u8] = b"org_form_approval";
+    
+    /// Maximum length for an organization ID, which is used as a PDA seed
+    pub const MAX_ORG_ID_LENGTH: usize = 32;
+    
+    /// Maximum length for the issuer display name
+    pub const MAX_ISSUER_NAME_LENGTH: usize = 64;
+    
+    /// Default tolerance, in seconds, for client-supplied signing timestamps
+    pub const DEFAULT_MAX_CLOCK_SKEW: i64 = 300;
+    
+    /// Default lifetime of an admin invitation in seconds (7 days)
+    pub const DEFAULT_INVITATION_TTL: i64 = 7 * 24 * 60 * 60;
+    
+    /// Maximum length for revocation reasons
+    pub const MAX_REVOCATION_REASON_LENGTH: usize = 128;
+    
+    /// Seed for the approval chain PDA
+    pub const APPROVAL_CHAIN_SEED: &'static [u8] = b"approval_chain";
+    
+    /// Seed for form observer PDAs
+    pub const OBSERVER_SEED: &'static [u8] = b"observer";
+    
+    /// Seed for observer PDAs of forms namespaced under an organization
+    pub const ORG_OBSERVER_SEED: &'static [u8] = b"org_observer";
+    
+    /// Seed for approval archive record PDAs
+    pub const ARCHIVE_RECORD_SEED: &'static [u8] = b"archive_record";
+    
+    /// Maximum length for archive location URIs
+    pub const MAX_ARCHIVE_URI_LENGTH: usize = 200;
+    
+    /// Seed for Arweave archive bundle PDAs
+    pub const ARCHIVE_BUNDLE_SEED: &'static [u8] = b"archive_bundle";
+    
+    /// Maximum number of approvals recorded in one archive bundle
+    pub const MAX_ARCHIVE_BATCH: usize = 16;
+    
+    /// Seed for mirror attestation PDAs
+    pub const MIRROR_ATTESTATION_SEED: &'static [u8] = b"mirror_attestation";
+    
+    /// Seed for form rejection PDAs
+    pub const FORM_REJECTION_SEED: &'static [u8] = b"form_rejection";
+    
+    /// Maximum length for rejection reasons
+    pub const MAX_REJECTION_REASON_LENGTH: usize = 128;
+    
+    /// Seed for time-bound access grant PDAs on confidential metadata
+    pub const ACCESS_GRANT_SEED: &'static [u8] = b"access_grant";
+    
+    /// Maximum length for wrapped key material or key-wrap references in access grants
+    pub const MAX_WRAPPED_KEY_LENGTH: usize = 128;
+    
+    /// Maximum number of required signers tracked on a form approval (bits in `signatures_collected`)
+    pub const MAX_REQUIRED_SIGNERS: usize = 16;
+    
+    /// Seed for the per-approval annotation count PDA
+    pub const ANNOTATION_LOG_SEED: &'static [u8] = b"annotation_log";
+    
+    /// Seed for approval annotation PDAs
+    pub const ANNOTATION_SEED: &'static [u8] = b"annotation";
+    
+    /// Maximum number of annotations attached to one approval
+    pub const MAX_ANNOTATIONS_PER_APPROVAL: u16 = 32;
+    
+    /// Seed for per-admin notification preference PDAs
+    pub const NOTIFICATION_PREFS_SEED: &'static [u8] = b"notification_prefs";
+    
+    /// Shortest reminder interval, in seconds, an admin can opt into
+    pub const MIN_REMINDER_INTERVAL: i64 = 3_600;
+    
+    /// Age, in seconds, after which reminders for a pending approval are escalated
+    pub const ESCALATION_DELAY: i64 = 3 * 24 * 3_600;
+    
+    /// Seed for counter-signature PDAs of non-admin parties
+    pub const COUNTER_SIGNATURE_SEED: &'static [u8] = b"counter_signature";
+    
+    /// Maximum number of counterparties named on a form approval
+    pub const MAX_COUNTERPARTIES: usize = 4;
+    
+    /// Maximum number of approvals a form approval can depend on
+    pub const MAX_DEPENDENCIES: usize = 4;
+    
+    /// Seed for escrow PDAs holding funds against a form's approval
+    pub const ESCROW_SEED: &'static [u8] = b"escrow";
+    
+    /// Seed for the token vaults of SPL escrows
+    pub const ESCROW_VAULT_SEED: &'static [u8] = b"escrow_vault";
+    
+    /// Seed for per-signer consent record PDAs
+    pub const CONSENT_SEED: &'static [u8] = b"consent";
+    
+    /// Seed for search key PDAs indexing approvals by external references
+    pub const SEARCH_KEY_SEED: &'static [u8] = b"search_key";
+    
+    /// Seed for per-signer form approval PDAs
+    pub const SIGNER_APPROVAL_SEED: &'static [u8] = b"signer_approval";
+    
+    /// Seed external programs use for the PDA that signs approvals via CPI
+    pub const CPI_SIGNER_SEED: &'static [u8] = b"document_signer";
+    
+    /// Maximum number of programs on the CPI allow-list
+    pub const MAX_CPI_PROGRAMS: usize = 8;
+    
     /// Maximum number of admins allowed
     pub const MAX_ADMINS: usize = 10;
+    
+    /// Maximum compute units a single transaction may request
+    pub const MAX_TRANSACTION_CU: u32 = 1_400_000;
+    
+    /// Compute units budgeted for `verify_form_signatures` before any approval accounts
+    pub const VERIFY_SIGNATURES_BASE_CU: u32 = 8_000;
+    
+    /// Compute units budgeted per approval account passed to `verify_form_signatures`
+    pub const VERIFY_SIGNATURES_CU_PER_APPROVAL: u32 = 9_000;
+    
+    /// Compute units budgeted for `assert_invariants` before any signer approvals
+    pub const ASSERT_INVARIANTS_BASE_CU: u32 = 20_000;
+    
+    /// Compute units budgeted per signer approval passed to `assert_invariants`
+    pub const ASSERT_INVARIANTS_CU_PER_APPROVAL: u32 = 8_000;
+    
+    /// Semantic version of this program build, taken from Cargo.toml
+    pub const PROGRAM_VERSION: SemVer = SemVer {
+        major: parse_version_part(env!("CARGO_PKG_VERSION_MAJOR")),
+        minor: parse_version_part(env!("CARGO_PKG_VERSION_MINOR")),
+        patch: parse_version_part(env!("CARGO_PKG_VERSION_PATCH")),
+    };
+}
+
+/// Parse a numeric version component at compile time
+const fn parse_version_part(part: &str) -> u16 {
+    let bytes = part.as_bytes();
+    let mut value = 0u16;
+    let mut i = 0;
+    while i < bytes.len() {
+        value = value * 10 + (bytes[i] - b'0') as u16;
+        i += 1;
+    }
+    value
 }
 
 /// Error codes for the smart contract
@@ -49,4 +300,367 @@ pub enum FormApprovalError {
     
     #[msg("Cannot remove the last admin")]
     CannotRemoveLastAdmin,
+    
+    #[msg("Client version is required by the program config")]
+    ClientVersionRequired,
+    
+    #[msg("Client version is older than the minimum supported version")]
+    ClientVersionTooOld,
+    
+    #[msg("Feature is disabled on this cluster")]
+    FeatureDisabled,
+    
+    #[msg("Instruction has been deprecated")]
+    InstructionDeprecated,
+    
+    #[msg("Account is not a form approval")]
+    InvalidFormApprovalAccount,
+    
+    #[msg("Checkpoint period is invalid or overlaps the previous checkpoint")]
+    InvalidCheckpointPeriod,
+    
+    #[msg("Invalid Merkle root")]
+    InvalidMerkleRoot,
+    
+    #[msg("Quorum must be between 1 and the number of admins")]
+    InvalidQuorum,
+    
+    #[msg("Admin has already attested this checkpoint")]
+    CheckpointAlreadyAttested,
+    
+    #[msg("Checkpoint has already reached its quorum")]
+    CheckpointAlreadyFinalized,
+    
+    #[msg("Signing program returned no verification result")]
+    MissingReturnData,
+    
+    #[msg("Calling program is not on the CPI allow-list")]
+    CpiProgramNotAllowed,
+    
+    #[msg("Program is already on the CPI allow-list")]
+    CpiProgramAlreadyAllowed,
+    
+    #[msg("Maximum number of CPI programs reached")]
+    MaxCpiProgramsReached,
+    
+    #[msg("Threshold policy cannot be met by the current admins")]
+    InvalidThresholdPolicy,
+    
+    #[msg("Form hash does not match the hash other signers approved")]
+    TallyHashMismatch,
+    
+    #[msg("Admin weight must be at least 1")]
+    InvalidAdminWeight,
+    
+    #[msg("Category exceeds maximum length")]
+    CategoryTooLong,
+    
+    #[msg("Form signatures do not yet meet the threshold policy")]
+    ThresholdNotMet,
+    
+    #[msg("Form has already been finalized")]
+    TallyAlreadyFinalized,
+    
+    #[msg("Signer has not signed this form")]
+    SignatureNotFound,
+    
+    #[msg("Form approval has already been revoked")]
+    FormAlreadyRevoked,
+    
+    #[msg("Form approval has not been revoked")]
+    FormNotRevoked,
+    
+    #[msg("Re-approval window has closed")]
+    ReapprovalWindowClosed,
+    
+    #[msg("Re-approval window cannot be negative")]
+    InvalidReapprovalWindow,
+    
+    #[msg("Reason code does not describe an approval")]
+    InvalidReasonCode,
+    
+    #[msg("Timestamp token hash cannot be zero")]
+    InvalidTimestampToken,
+    
+    #[msg("Signatures must be submitted through a registered application")]
+    AppSignatureRequired,
+    
+    #[msg("Registered application is inactive")]
+    AppInactive,
+    
+    #[msg("Application key did not sign the transaction")]
+    InvalidAppSigner,
+    
+    #[msg("Application name exceeds maximum length")]
+    AppNameTooLong,
+    
+    #[msg("Admin removal must be scheduled in the future")]
+    InvalidRemovalTime,
+    
+    #[msg("Scheduled admin removal is not yet effective")]
+    RemovalNotYetEffective,
+    
+    #[msg("Signer limit must be between 1 and the maximum number of admins")]
+    InvalidSignerLimit,
+    
+    #[msg("Form has reached the maximum number of signers")]
+    SignerLimitReached,
+    
+    #[msg("Threshold policy requires more signers than the signer limit allows")]
+    PolicyExceedsSignerLimit,
+    
+    #[msg("Bulk admin import is not enabled")]
+    AdminImportDisabled,
+    
+    #[msg("Admin key is not included in the import root")]
+    InvalidAdminProof,
+    
+    #[msg("Search key hash cannot be zero")]
+    InvalidSearchKey,
+    
+    #[msg("Template ID exceeds maximum length")]
+    TemplateIdTooLong,
+    
+    #[msg("Template version has been deprecated")]
+    TemplateVersionDeprecated,
+    
+    #[msg("Locale is not a valid BCP-47 tag")]
+    InvalidLocale,
+    
+    #[msg("Locale is not allowed by the template version")]
+    LocaleNotAllowed,
+    
+    #[msg("Template version allows too many locales")]
+    TooManyLocales,
+    
+    #[msg("Jurisdiction is not a valid ISO 3166 code")]
+    InvalidJurisdiction,
+    
+    #[msg("Jurisdiction is not allowed by the template version")]
+    JurisdictionNotAllowed,
+    
+    #[msg("Template version allows too many jurisdictions")]
+    TooManyJurisdictions,
+    
+    #[msg("Signer has not consented to electronic signature")]
+    ConsentRequired,
+    
+    #[msg("Intent statement hash cannot be zero")]
+    InvalidIntentStatement,
+    
+    #[msg("Signer has not recorded consent for this form")]
+    ConsentNotRecorded,
+    
+    #[msg("Consent disclosure hash cannot be zero")]
+    InvalidConsentDisclosure,
+    
+    #[msg("Issuer display name exceeds maximum length")]
+    IssuerNameTooLong,
+    
+    #[msg("Archive URI exceeds maximum length")]
+    ArchiveUriTooLong,
+    
+    #[msg("Observer account is not registered for this form in this namespace")]
+    InvalidObserverAccount,
+    
+    #[msg("Revocation reason exceeds maximum length")]
+    RevocationReasonTooLong,
+    
+    #[msg("Approval must be archived in its current state before it can be closed")]
+    ArchiveRequired,
+    
+    #[msg("Client timestamp is outside the allowed clock skew")]
+    ClientTimestampOutOfRange,
+    
+    #[msg("Clock skew tolerance must not be negative")]
+    InvalidClockSkew,
+    
+    #[msg("Required signatures must be between 1 and the per-form signer limit")]
+    InvalidRequiredSignatures,
+    
+    #[msg("Form approval is not awaiting co-signatures")]
+    FormNotPending,
+    
+    #[msg("Admin has already signed this form")]
+    AlreadyCoSigned,
+    
+    #[msg("The approval lifecycle does not allow this status change")]
+    InvalidStatusTransition,
+    
+    #[msg("Reason code does not describe a rejection")]
+    InvalidRejectionCode,
+    
+    #[msg("Rejection reason exceeds maximum length")]
+    RejectionReasonTooLong,
+    
+    #[msg("Expiry must be in the future and later than the current expiry")]
+    InvalidExpiry,
+    
+    #[msg("Form approval has expired")]
+    ApprovalExpired,
+    
+    #[msg("Escrow amount must be greater than zero")]
+    InvalidEscrowAmount,
+    
+    #[msg("Token escrows need the mint, both token accounts and the token program")]
+    IncompleteTokenAccounts,
+    
+    #[msg("Escrow can be settled once the form is approved, rejected or past its refund time")]
+    EscrowNotSettleable,
+    
+    #[msg("Too many counterparties, or a counterparty is listed twice")]
+    InvalidCounterparties,
+    
+    #[msg("Wallet is not a counterparty named on this form")]
+    NotACounterparty,
+    
+    #[msg("Counter-signed hash does not match the approved form hash")]
+    CounterSignatureHashMismatch,
+    
+    #[msg("Reminder interval must be zero or at least one hour")]
+    InvalidReminderInterval,
+    
+    #[msg("Admin has disabled reminders or was reminded too recently")]
+    ReminderNotDue,
+    
+    #[msg("Annotation note hash cannot be zero")]
+    InvalidNoteHash,
+    
+    #[msg("Approval has reached its annotation limit")]
+    AnnotationLimitReached,
+    
+    #[msg("Too many required signers, or a signer is listed twice")]
+    InvalidRequiredSigners,
+    
+    #[msg("Wrapped key must be between 1 and 128 bytes")]
+    InvalidWrappedKey,
+    
+    #[msg("Archive batch must list 1 to 16 distinct approval accounts")]
+    InvalidArchiveBatch,
+    
+    #[msg("Admin lacks the permission required for this instruction")]
+    MissingPermission,
+    
+    #[msg("Permissions contain unknown flags")]
+    InvalidPermissions,
+    
+    #[msg("Signer is not the proposed authority")]
+    NotPendingAuthority,
+    
+    #[msg("Authority was renounced; the admin set is frozen")]
+    AuthorityRenounced,
+    
+    #[msg("Remote transaction reference must not be zero")]
+    InvalidRemoteReference,
+    
+    #[msg("Admin changes are timelocked; queue the change instead")]
+    AdminChangeTimelocked,
+    
+    #[msg("Admin change delay must be between 0 and 30 days")]
+    InvalidAdminChangeDelay,
+    
+    #[msg("Queued admin change is not effective yet")]
+    AdminChangeNotReady,
+    
+    #[msg("Admin invitation has expired")]
+    InvitationExpired,
+    
+    #[msg("Invitation lifetime cannot be negative")]
+    InvalidInvitationTtl,
+    
+    #[msg("Verifier exceeded the verification rate limit for this approval")]
+    VerificationRateLimited,
+    
+    #[msg("Admin expiry must be zero or in the future")]
+    InvalidAdminExpiry,
+    
+    #[msg("Imported approval timestamps must be positive and not in the future")]
+    InvalidImportTimestamp,
+    
+    #[msg("Program is paused")]
+    ProgramPaused,
+    
+    #[msg("Signing window must end in the future and after it opens")]
+    InvalidSigningWindow,
+    
+    #[msg("Signing window has not opened yet")]
+    SigningWindowNotOpen,
+    
+    #[msg("Signing window has closed")]
+    SigningWindowClosed,
+    
+    #[msg("Form approval is frozen")]
+    FormFrozen,
+    
+    #[msg("Admin batch must list between 1 and MAX_ADMINS keys")]
+    InvalidAdminBatch,
+    
+    #[msg("Dependencies must be at most MAX_DEPENDENCIES distinct other approvals")]
+    InvalidDependencies,
+    
+    #[msg("A dependency approval was not passed among the remaining accounts")]
+    MissingDependency,
+    
+    #[msg("A dependency approval is not in force")]
+    DependencyNotMet,
+    
+    #[msg("The reported key is an active admin")]
+    ReportedKeyIsAdmin,
+    
+    #[msg("Document URI must be an ipfs://, ar:// or https:// URI of at most MAX_DOCUMENT_URI_LENGTH characters")]
+    InvalidDocumentUri,
+    
+    #[msg("Content type must be a MIME type of at most MAX_CONTENT_TYPE_LENGTH characters")]
+    InvalidContentType,
+    
+    #[msg("Hash tail must be supplied for 64-byte digest algorithms and only for them")]
+    InvalidDigestLength,
+    
+    #[msg("Upload size must be between 1 and MAX_DOCUMENT_UPLOAD_SIZE bytes")]
+    InvalidUploadSize,
+    
+    #[msg("Chunk does not continue the upload or overruns its declared size")]
+    UploadChunkOutOfOrder,
+    
+    #[msg("Upload has not received its declared size yet")]
+    UploadIncomplete,
+    
+    #[msg("Uploaded content does not hash to the approval's SHA-256 form hash")]
+    DocumentHashMismatch,
+    
+    #[msg("Page count must be between 1 and MAX_DOCUMENT_PAGES, over a SHA-256 document root")]
+    InvalidPageCount,
+    
+    #[msg("The form hash is already anchored under another form ID")]
+    DuplicateFormHash,
+    
+    #[msg("Organization ID must be 1 to 32 bytes")]
+    InvalidOrgId,
+    
+    #[msg("Organization approvals are write-once and cannot wait for co-signatures")]
+    OrgCoSigningUnsupported,
+    
+    #[msg("Expected the admin record address of the key being added")]
+    InvalidAdminRecordAccount,
+    
+    #[msg("Finalizing a record addition needs a payer and the system program")]
+    AdminRecordPayerRequired,
+    
+    #[msg("Removing the admin would leave fewer admins than the checkpoint quorum")]
+    QuorumExceedsAdmins,
+    
+    #[msg("An approval's counter-party organization must differ from its own")]
+    InvalidCounterpartyOrg,
+    
+    #[msg("The organization is not this approval's counter-party")]
+    NotCounterpartyOrg,
+    
+    #[msg("The counter-party organization has already signed this approval")]
+    CounterpartyOrgAlreadySigned,
+    
+    #[msg("The organization is suspended")]
+    OrgSuspended,
+    
+    #[msg("The organization is not suspended")]
+    OrgNotSuspended,
 }
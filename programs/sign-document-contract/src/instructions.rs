@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use crate::state::*;
 use crate::config::*;
 
@@ -20,59 +21,114 @@ pub struct InitializeAdminConfig<'info> {
     pub system_program: Program<'info, System>,
 }
 
-/// Context for signing a form submission
+/// Context for initializing the program configuration
 #[derive(Accounts)]
-#[instruction(form_id: String)]
-pub struct SignFormSubmission<'info> {
+pub struct InitializeProgramConfig<'info> {
     #[account(
         init,
-        payer = admin,
-        space = FormApproval::space(form_id.len(), 0),
-        seeds = [Config::FORM_APPROVAL_SEED, form_id.as_bytes()],
+        payer = authority,
+        space = ProgramConfig::space(),
+        seeds = [Config::PROGRAM_CONFIG_SEED],
         bump
     )]
-    pub form_approval: Account<'info, FormApproval>,
+    pub program_config: Account<'info, ProgramConfig>,
     
     #[account(
         seeds = [Config::ADMIN_CONFIG_SEED],
         bump = admin_config.bump,
-        constraint = admin_config.is_admin(&admin.key()) @ FormApprovalError::UnauthorizedAdmin
+        constraint = admin_config.authority == authority.key() @ FormApprovalError::UnauthorizedAdmin
     )]
     pub admin_config: Account<'info, AdminConfig>,
     
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub authority: Signer<'info>,
     
     pub system_program: Program<'info, System>,
 }
 
-/// Context for updating form approval with metadata
+/// Context for changing program configuration settings
+#[cfg_attr(feature = "event-cpi", event_cpi)]
 #[derive(Accounts)]
-#[instruction(form_id: String)]
-pub struct UpdateFormApproval<'info> {
+pub struct UpdateProgramConfig<'info> {
     #[account(
         mut,
-        seeds = [Config::FORM_APPROVAL_SEED, form_id.as_bytes()],
-        bump = form_approval.bump,
-        constraint = form_approval.signer == admin.key() @ FormApprovalError::UnauthorizedAdmin
+        seeds = [Config::PROGRAM_CONFIG_SEED],
+        bump = program_config.bump
     )]
-    pub form_approval: Account<'info, FormApproval>,
+    pub program_config: Account<'info, ProgramConfig>,
     
     #[account(
         seeds = [Config::ADMIN_CONFIG_SEED],
         bump = admin_config.bump,
-        constraint = admin_config.is_admin(&admin.key()) @ FormApprovalError::UnauthorizedAdmin
+        constraint = admin_config.authority == authority.key() @ FormApprovalError::UnauthorizedAdmin
     )]
     pub admin_config: Account<'info, AdminConfig>,
     
-    pub admin: Signer<'info>,
+    pub authority: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
 }
 
-/// Context for adding a new admin
+/// Context for publishing a new version of a form template
+#[cfg_attr(feature = "event-cpi", event_cpi)]
 #[derive(Accounts)]
-pub struct AddAdmin<'info> {
+#[instruction(
+    template_id: String,
+    version: u32,
+    content_hash: [u8; 32],
+    allowed_locales: Vec<String>,
+    allowed_jurisdictions: Vec<String>
+)]
+pub struct PublishTemplateVersion<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = TemplateVersion::space(template_id.len(), allowed_locales.len(), allowed_jurisdictions.len()),
+        seeds = [Config::TEMPLATE_VERSION_SEED, template_id.as_bytes(), version.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub template_version: Account<'info, TemplateVersion>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.authority == authority.key() @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for deprecating a template version
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(template_id: String, version: u32)]
+pub struct DeprecateTemplateVersion<'info> {
     #[account(
         mut,
+        seeds = [Config::TEMPLATE_VERSION_SEED, template_id.as_bytes(), version.to_le_bytes().as_ref()],
+        bump = template_version.bump
+    )]
+    pub template_version: Account<'info, TemplateVersion>,
+    
+    #[account(
         seeds = [Config::ADMIN_CONFIG_SEED],
         bump = admin_config.bump,
         constraint = admin_config.authority == authority.key() @ FormApprovalError::UnauthorizedAdmin
@@ -80,13 +136,89 @@ pub struct AddAdmin<'info> {
     pub admin_config: Account<'info, AdminConfig>,
     
     pub authority: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
 }
 
-/// Context for removing an admin
+/// Context for initializing the global event sequence
 #[derive(Accounts)]
-pub struct RemoveAdmin<'info> {
+pub struct InitializeEventSequence<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = EventSequence::space(),
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.authority == authority.key() @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for registering an integrating application
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(app_key: Pubkey, name: String)]
+pub struct RegisterApp<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = RegisteredApp::space(name.len()),
+        seeds = [Config::REGISTERED_APP_SEED, app_key.as_ref()],
+        bump
+    )]
+    pub registered_app: Account<'info, RegisteredApp>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.authority == authority.key() @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for changing a registered application
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(app_key: Pubkey)]
+pub struct UpdateRegisteredApp<'info> {
     #[account(
         mut,
+        seeds = [Config::REGISTERED_APP_SEED, app_key.as_ref()],
+        bump = registered_app.bump
+    )]
+    pub registered_app: Account<'info, RegisteredApp>,
+    
+    #[account(
         seeds = [Config::ADMIN_CONFIG_SEED],
         bump = admin_config.bump,
         constraint = admin_config.authority == authority.key() @ FormApprovalError::UnauthorizedAdmin
@@ -94,15 +226,3336 @@ pub struct RemoveAdmin<'info> {
     pub admin_config: Account<'info, AdminConfig>,
     
     pub authority: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
 }
 
-/// Context for verifying a form approval
+/// Context for a registered application recording a verification it performed
+#[cfg_attr(feature = "event-cpi", event_cpi)]
 #[derive(Accounts)]
 #[instruction(form_id: String)]
-pub struct VerifyFormApproval<'info> {
+pub struct RecordAppVerification<'info> {
     #[account(
         seeds = [Config::FORM_APPROVAL_SEED, form_id.as_bytes()],
         bump = form_approval.bump
     )]
     pub form_approval: Account<'info, FormApproval>,
+    
+    #[account(
+        mut,
+        seeds = [Config::REGISTERED_APP_SEED, app_signer.key().as_ref()],
+        bump = registered_app.bump,
+        constraint = registered_app.active @ FormApprovalError::AppInactive
+    )]
+    pub registered_app: Account<'info, RegisteredApp>,
+    
+    pub app_signer: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for creating or updating the issuer branding
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct SetBranding<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = BrandingConfig::space(),
+        seeds = [Config::BRANDING_CONFIG_SEED],
+        bump
+    )]
+    pub branding_config: Account<'info, BrandingConfig>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.authority == authority.key() @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for publishing the authorized webhook endpoint manifest
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct SetWebhookManifest<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = WebhookManifest::space(),
+        seeds = [Config::WEBHOOK_MANIFEST_SEED],
+        bump
+    )]
+    pub webhook_manifest: Account<'info, WebhookManifest>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.authority == authority.key() @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for registering the policy catalog referenced by rejections and revocations
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct SetPolicyCatalog<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = PolicyCatalog::space(),
+        seeds = [Config::POLICY_CATALOG_SEED],
+        bump
+    )]
+    pub policy_catalog: Account<'info, PolicyCatalog>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.authority == authority.key() @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for creating or replacing a category's threshold policy
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(category: String)]
+pub struct SetCategoryPolicy<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = CategoryPolicy::space(category.len()),
+        seeds = [Config::CATEGORY_POLICY_SEED, category.as_bytes()],
+        bump
+    )]
+    pub category_policy: Account<'info, CategoryPolicy>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.authority == authority.key() @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for initializing the feature gates with every feature disabled
+#[derive(Accounts)]
+pub struct InitializeFeatureGates<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = FeatureGates::space(),
+        seeds = [Config::FEATURE_GATES_SEED],
+        bump
+    )]
+    pub feature_gates: Account<'info, FeatureGates>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.authority == authority.key() @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for toggling a feature gate
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct SetFeatureGate<'info> {
+    #[account(
+        mut,
+        seeds = [Config::FEATURE_GATES_SEED],
+        bump = feature_gates.bump
+    )]
+    pub feature_gates: Account<'info, FeatureGates>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.authority == authority.key() @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    pub authority: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for signing a form submission
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String, form_hash: [u8; 32], metadata: Option<String>)]
+pub struct SignFormSubmission<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = FormApproval::space(form_id.len(), metadata.as_ref().map_or(0, |meta| meta.len())),
+        seeds = [Config::FORM_APPROVAL_SEED, form_id.as_bytes()],
+        bump
+    )]
+    pub form_approval: Account<'info, FormApproval>,
+    
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = ApprovalChain::space(),
+        seeds = [Config::APPROVAL_CHAIN_SEED],
+        bump
+    )]
+    pub approval_chain: Account<'info, ApprovalChain>,
+    
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = HashIndex::space(),
+        seeds = [Config::HASH_INDEX_SEED, form_hash.as_ref()],
+        bump
+    )]
+    pub hash_index: Account<'info, HashIndex>,
+    
+    #[account(
+        mut,
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.is_admin_with_record(&admin.key(), admin_record.as_deref()) @ FormApprovalError::UnauthorizedAdmin,
+        constraint = admin_config.has_permission_with_record(&admin.key(), admin_record.as_deref(), AdminConfig::CAN_SIGN) @ FormApprovalError::MissingPermission
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        mut,
+        seeds = [Config::ADMIN_RECORD_SEED, admin.key().as_ref()],
+        bump = admin_record.bump
+    )]
+    pub admin_record: Option<Account<'info, AdminRecord>>,
+    
+    #[account(
+        seeds = [Config::PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.is_deprecated(ProgramInstruction::SignFormSubmission) @ FormApprovalError::InstructionDeprecated,
+        constraint = !program_config.paused @ FormApprovalError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    /// Registered application submitting the signature
+    #[account(mut)]
+    pub registered_app: Option<Account<'info, RegisteredApp>>,
+    
+    /// The registered application's key, co-signing the transaction
+    pub app_signer: Option<Signer<'info>>,
+    
+    /// Template version the form was filled from
+    pub template_version: Option<Account<'info, TemplateVersion>>,
+    
+    /// Consent the signer recorded for this form, required in strict-consent mode
+    #[account(
+        seeds = [Config::CONSENT_SEED, form_id.as_bytes(), admin.key().as_ref()],
+        bump = consent_record.bump
+    )]
+    pub consent_record: Option<Account<'info, ConsentRecord>>,
+    
+    /// Feature gates, required when the request collects more than one signature
+    #[account(
+        seeds = [Config::FEATURE_GATES_SEED],
+        bump = feature_gates.bump
+    )]
+    pub feature_gates: Option<Account<'info, FeatureGates>>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for creating an organization
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(org_id: String)]
+pub struct CreateOrganization<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = Organization::space(org_id.len()),
+        seeds = [Config::ORGANIZATION_SEED, org_id.as_bytes()],
+        bump
+    )]
+    pub organization: Account<'info, Organization>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for managing an organization's admins
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct UpdateOrganization<'info> {
+    #[account(
+        mut,
+        seeds = [Config::ORGANIZATION_SEED, organization.org_id.as_bytes()],
+        bump = organization.bump,
+        constraint = organization.authority == authority.key() @ FormApprovalError::UnauthorizedAdmin,
+        constraint = !organization.is_suspended() @ FormApprovalError::OrgSuspended
+    )]
+    pub organization: Account<'info, Organization>,
+    
+    pub authority: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for the program authority suspending or reinstating an organization
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct SetOrganizationSuspension<'info> {
+    #[account(
+        mut,
+        seeds = [Config::ORGANIZATION_SEED, organization.org_id.as_bytes()],
+        bump = organization.bump
+    )]
+    pub organization: Account<'info, Organization>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.authority == authority.key() @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    pub authority: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for signing a form submission in an organization's namespace
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String, form_hash: [u8; 32], metadata: Option<String>)]
+pub struct SignOrgFormSubmission<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = FormApproval::space(form_id.len(), metadata.as_ref().map_or(0, |meta| meta.len())),
+        seeds = [Config::ORG_FORM_APPROVAL_SEED, organization.key().as_ref(), form_id.as_bytes()],
+        bump
+    )]
+    pub form_approval: Account<'info, FormApproval>,
+    
+    #[account(
+        mut,
+        seeds = [Config::ORGANIZATION_SEED, organization.org_id.as_bytes()],
+        bump = organization.bump,
+        constraint = organization.is_admin(&admin.key()) @ FormApprovalError::UnauthorizedAdmin,
+        constraint = !organization.is_suspended() @ FormApprovalError::OrgSuspended
+    )]
+    pub organization: Account<'info, Organization>,
+    
+    /// Second organization one of whose admins must also sign before the approval is in force
+    #[account(
+        seeds = [Config::ORGANIZATION_SEED, counterparty_organization.org_id.as_bytes()],
+        bump = counterparty_organization.bump,
+        constraint = counterparty_organization.key() != organization.key() @ FormApprovalError::InvalidCounterpartyOrg,
+        constraint = !counterparty_organization.is_suspended() @ FormApprovalError::OrgSuspended
+    )]
+    pub counterparty_organization: Option<Account<'info, Organization>>,
+    
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = ApprovalChain::space(),
+        seeds = [Config::APPROVAL_CHAIN_SEED],
+        bump
+    )]
+    pub approval_chain: Account<'info, ApprovalChain>,
+    
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = HashIndex::space(),
+        seeds = [Config::HASH_INDEX_SEED, form_hash.as_ref()],
+        bump
+    )]
+    pub hash_index: Account<'info, HashIndex>,
+    
+    /// Program admin set, counting the signer's activity if it is also a program admin
+    #[account(
+        mut,
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        mut,
+        seeds = [Config::ADMIN_RECORD_SEED, admin.key().as_ref()],
+        bump = admin_record.bump
+    )]
+    pub admin_record: Option<Account<'info, AdminRecord>>,
+    
+    #[account(
+        seeds = [Config::PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.is_deprecated(ProgramInstruction::SignOrgFormSubmission) @ FormApprovalError::InstructionDeprecated,
+        constraint = !program_config.paused @ FormApprovalError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    /// Registered application submitting the signature
+    #[account(mut)]
+    pub registered_app: Option<Account<'info, RegisteredApp>>,
+    
+    /// The registered application's key, co-signing the transaction
+    pub app_signer: Option<Signer<'info>>,
+    
+    /// Template version the form was filled from
+    pub template_version: Option<Account<'info, TemplateVersion>>,
+    
+    /// Consent the signer recorded for this form, required in strict-consent mode
+    #[account(
+        seeds = [Config::CONSENT_SEED, form_id.as_bytes(), admin.key().as_ref()],
+        bump = consent_record.bump
+    )]
+    pub consent_record: Option<Account<'info, ConsentRecord>>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for an admin recording their own approval of a form
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String, form_hash: [u8; 32], metadata: Option<String>)]
+pub struct SignFormAsSigner<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = FormApproval::space(form_id.len(), metadata.as_ref().map_or(0, |meta| meta.len())),
+        seeds = [Config::SIGNER_APPROVAL_SEED, form_id.as_bytes(), admin.key().as_ref()],
+        bump
+    )]
+    pub form_approval: Account<'info, FormApproval>,
+    
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = ApprovalChain::space(),
+        seeds = [Config::APPROVAL_CHAIN_SEED],
+        bump
+    )]
+    pub approval_chain: Account<'info, ApprovalChain>,
+    
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = ApprovalTally::space(),
+        seeds = [Config::APPROVAL_TALLY_SEED, form_id.as_bytes()],
+        bump
+    )]
+    pub approval_tally: Account<'info, ApprovalTally>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.is_admin_with_record(&admin.key(), admin_record.as_deref()) @ FormApprovalError::UnauthorizedAdmin,
+        constraint = admin_config.has_permission_with_record(&admin.key(), admin_record.as_deref(), AdminConfig::CAN_SIGN) @ FormApprovalError::MissingPermission
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, admin.key().as_ref()],
+        bump = admin_record.bump
+    )]
+    pub admin_record: Option<Account<'info, AdminRecord>>,
+    
+    #[account(
+        seeds = [Config::PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.is_deprecated(ProgramInstruction::SignFormAsSigner) @ FormApprovalError::InstructionDeprecated,
+        constraint = !program_config.paused @ FormApprovalError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        seeds = [Config::FEATURE_GATES_SEED],
+        bump = feature_gates.bump,
+        constraint = feature_gates.is_enabled(FeatureGate::MultiSign) @ FormApprovalError::FeatureDisabled
+    )]
+    pub feature_gates: Account<'info, FeatureGates>,
+    
+    /// Policy of the form's category; only read on the first signature
+    pub category_policy: Option<Account<'info, CategoryPolicy>>,
+    
+    /// Registered application submitting the signature
+    #[account(mut)]
+    pub registered_app: Option<Account<'info, RegisteredApp>>,
+    
+    /// The registered application's key, co-signing the transaction
+    pub app_signer: Option<Signer<'info>>,
+    
+    /// Template version the form was filled from
+    pub template_version: Option<Account<'info, TemplateVersion>>,
+    
+    /// Consent the signer recorded for this form, required in strict-consent mode
+    #[account(
+        seeds = [Config::CONSENT_SEED, form_id.as_bytes(), admin.key().as_ref()],
+        bump = consent_record.bump
+    )]
+    pub consent_record: Option<Account<'info, ConsentRecord>>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for a co-signer withdrawing their signature before finalization
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String)]
+pub struct WithdrawSignature<'info> {
+    #[account(
+        mut,
+        close = signer,
+        seeds = [Config::SIGNER_APPROVAL_SEED, form_id.as_bytes(), signer.key().as_ref()],
+        bump = form_approval.bump
+    )]
+    pub form_approval: Account<'info, FormApproval>,
+    
+    #[account(
+        mut,
+        seeds = [Config::APPROVAL_TALLY_SEED, form_id.as_bytes()],
+        bump = approval_tally.bump
+    )]
+    pub approval_tally: Account<'info, ApprovalTally>,
+    
+    #[account(
+        seeds = [Config::PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ FormApprovalError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for finalizing a form whose tally meets its policy
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String)]
+pub struct TryFinalize<'info> {
+    #[account(
+        mut,
+        seeds = [Config::APPROVAL_TALLY_SEED, form_id.as_bytes()],
+        bump = approval_tally.bump
+    )]
+    pub approval_tally: Account<'info, ApprovalTally>,
+    
+    #[account(
+        seeds = [Config::PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ FormApprovalError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for an allow-listed program creating an approval via CPI
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String, form_hash: [u8; 32], metadata: Option<String>)]
+pub struct SignFormSubmissionCpi<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = FormApproval::space(form_id.len(), metadata.as_ref().map_or(0, |meta| meta.len())),
+        seeds = [Config::FORM_APPROVAL_SEED, form_id.as_bytes()],
+        bump
+    )]
+    pub form_approval: Account<'info, FormApproval>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ApprovalChain::space(),
+        seeds = [Config::APPROVAL_CHAIN_SEED],
+        bump
+    )]
+    pub approval_chain: Account<'info, ApprovalChain>,
+    
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = HashIndex::space(),
+        seeds = [Config::HASH_INDEX_SEED, form_hash.as_ref()],
+        bump
+    )]
+    pub hash_index: Account<'info, HashIndex>,
+    
+    #[account(
+        seeds = [Config::PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.is_deprecated(ProgramInstruction::SignFormSubmissionCpi) @ FormApprovalError::InstructionDeprecated,
+        constraint = program_config.is_cpi_allowed(&caller_program.key()) @ FormApprovalError::CpiProgramNotAllowed,
+        constraint = !program_config.paused @ FormApprovalError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    /// CHECK: only used to derive the expected signer PDA; must be on the allow-list
+    #[account(executable)]
+    pub caller_program: UncheckedAccount<'info>,
+    
+    /// PDA of the calling program, signed for via `invoke_signed`
+    #[account(
+        seeds = [Config::CPI_SIGNER_SEED],
+        bump,
+        seeds::program = caller_program.key()
+    )]
+    pub caller_signer: Signer<'info>,
+    
+    /// Registered application submitting the signature
+    #[account(mut)]
+    pub registered_app: Option<Account<'info, RegisteredApp>>,
+    
+    /// The registered application's key, co-signing the transaction
+    pub app_signer: Option<Signer<'info>>,
+    
+    /// Template version the form was filled from
+    pub template_version: Option<Account<'info, TemplateVersion>>,
+    
+    /// Consent the calling program's PDA recorded for this form, required in strict-consent mode
+    #[account(
+        seeds = [Config::CONSENT_SEED, form_id.as_bytes(), caller_signer.key().as_ref()],
+        bump = consent_record.bump
+    )]
+    pub consent_record: Option<Account<'info, ConsentRecord>>,
+    
+    /// Feature gates, required when the request collects more than one signature
+    #[account(
+        seeds = [Config::FEATURE_GATES_SEED],
+        bump = feature_gates.bump
+    )]
+    pub feature_gates: Option<Account<'info, FeatureGates>>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for the authority importing an approval from a previous system
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String, form_hash: [u8; 32], signer: Pubkey, approved_at: i64, expires_at: i64, metadata: Option<String>)]
+pub struct ImportLegacyApproval<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = FormApproval::space(form_id.len(), metadata.as_ref().map_or(0, |meta| meta.len())),
+        seeds = [Config::FORM_APPROVAL_SEED, form_id.as_bytes()],
+        bump
+    )]
+    pub form_approval: Account<'info, FormApproval>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.authority == authority.key() @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [Config::PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ FormApprovalError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for co-signing a form approval awaiting its quorum
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String)]
+pub struct CoSignForm<'info> {
+    #[account(
+        mut,
+        seeds = [Config::FORM_APPROVAL_SEED, form_id.as_bytes()],
+        bump = form_approval.bump,
+        constraint = !form_approval.frozen @ FormApprovalError::FormFrozen
+    )]
+    pub form_approval: Account<'info, FormApproval>,
+    
+    /// History the change is appended to
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = FormHistory::space(),
+        seeds = [Config::FORM_HISTORY_SEED, form_approval.key().as_ref()],
+        bump
+    )]
+    pub form_history: Account<'info, FormHistory>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.is_admin_with_record(&admin.key(), admin_record.as_deref()) @ FormApprovalError::UnauthorizedAdmin,
+        constraint = admin_config.has_permission_with_record(&admin.key(), admin_record.as_deref(), AdminConfig::CAN_SIGN) @ FormApprovalError::MissingPermission
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, admin.key().as_ref()],
+        bump = admin_record.bump
+    )]
+    pub admin_record: Option<Account<'info, AdminRecord>>,
+    
+    #[account(
+        seeds = [Config::PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ FormApprovalError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        seeds = [Config::FEATURE_GATES_SEED],
+        bump = feature_gates.bump,
+        constraint = feature_gates.is_enabled(FeatureGate::MultiSign) @ FormApprovalError::FeatureDisabled
+    )]
+    pub feature_gates: Account<'info, FeatureGates>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for updating form approval with metadata
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String, metadata: String)]
+pub struct UpdateFormApproval<'info> {
+    #[account(
+        mut,
+        seeds = [Config::FORM_APPROVAL_SEED, form_id.as_bytes()],
+        bump = form_approval.bump,
+        realloc = FormApproval::space(form_id.len(), metadata.len()),
+        realloc::payer = admin,
+        realloc::zero = false,
+        constraint = !form_approval.frozen @ FormApprovalError::FormFrozen,
+        constraint = form_approval.signer == admin.key() @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub form_approval: Account<'info, FormApproval>,
+    
+    /// History the change is appended to
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = FormHistory::space(),
+        seeds = [Config::FORM_HISTORY_SEED, form_approval.key().as_ref()],
+        bump
+    )]
+    pub form_history: Account<'info, FormHistory>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.is_admin_with_record(&admin.key(), admin_record.as_deref()) @ FormApprovalError::UnauthorizedAdmin,
+        constraint = admin_config.has_permission_with_record(&admin.key(), admin_record.as_deref(), AdminConfig::CAN_UPDATE) @ FormApprovalError::MissingPermission
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, admin.key().as_ref()],
+        bump = admin_record.bump
+    )]
+    pub admin_record: Option<Account<'info, AdminRecord>>,
+    
+    #[account(
+        seeds = [Config::PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.is_deprecated(ProgramInstruction::UpdateFormApproval) @ FormApprovalError::InstructionDeprecated,
+        constraint = !program_config.paused @ FormApprovalError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for the original signer updating where the signed document is located
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String)]
+pub struct UpdateDocumentLocation<'info> {
+    #[account(
+        mut,
+        seeds = [Config::FORM_APPROVAL_SEED, form_id.as_bytes()],
+        bump = form_approval.bump,
+        constraint = !form_approval.frozen @ FormApprovalError::FormFrozen,
+        constraint = !form_approval.is_revoked() @ FormApprovalError::FormAlreadyRevoked,
+        constraint = form_approval.signer == admin.key() @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub form_approval: Account<'info, FormApproval>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.is_admin_with_record(&admin.key(), admin_record.as_deref()) @ FormApprovalError::UnauthorizedAdmin,
+        constraint = admin_config.has_permission_with_record(&admin.key(), admin_record.as_deref(), AdminConfig::CAN_UPDATE) @ FormApprovalError::MissingPermission
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, admin.key().as_ref()],
+        bump = admin_record.bump
+    )]
+    pub admin_record: Option<Account<'info, AdminRecord>>,
+    
+    #[account(
+        seeds = [Config::PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ FormApprovalError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    pub admin: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for starting a chunked document upload
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String, total_len: u32)]
+pub struct BeginDocumentUpload<'info> {
+    #[account(
+        init,
+        payer = uploader,
+        space = DocumentUpload::space(form_id.len(), total_len as usize),
+        seeds = [Config::DOCUMENT_UPLOAD_SEED, form_id.as_bytes(), uploader.key().as_ref()],
+        bump
+    )]
+    pub document_upload: Account<'info, DocumentUpload>,
+    
+    #[account(mut)]
+    pub uploader: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for appending a chunk to a document upload
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String)]
+pub struct AppendChunk<'info> {
+    #[account(
+        mut,
+        seeds = [Config::DOCUMENT_UPLOAD_SEED, form_id.as_bytes(), uploader.key().as_ref()],
+        bump = document_upload.bump
+    )]
+    pub document_upload: Account<'info, DocumentUpload>,
+    
+    pub uploader: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for hashing a completed upload and checking it against the form's approval
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String)]
+pub struct FinalizeDocument<'info> {
+    #[account(
+        mut,
+        close = uploader,
+        seeds = [Config::DOCUMENT_UPLOAD_SEED, form_id.as_bytes(), uploader.key().as_ref()],
+        bump = document_upload.bump
+    )]
+    pub document_upload: Account<'info, DocumentUpload>,
+    
+    #[account(
+        mut,
+        seeds = [Config::FORM_APPROVAL_SEED, form_id.as_bytes()],
+        bump = form_approval.bump
+    )]
+    pub form_approval: Account<'info, FormApproval>,
+    
+    #[account(
+        seeds = [Config::PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ FormApprovalError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(mut)]
+    pub uploader: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for the original signer revoking a form approval
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String)]
+pub struct RevokeFormApproval<'info> {
+    #[account(
+        mut,
+        seeds = [Config::FORM_APPROVAL_SEED, form_id.as_bytes()],
+        bump = form_approval.bump,
+        constraint = form_approval.signer == admin.key()
+            || admin_config.authority == admin.key() @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub form_approval: Account<'info, FormApproval>,
+    
+    /// History the change is appended to
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = FormHistory::space(),
+        seeds = [Config::FORM_HISTORY_SEED, form_approval.key().as_ref()],
+        bump
+    )]
+    pub form_history: Account<'info, FormHistory>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.authority == admin.key()
+            || admin_config.has_permission_with_record(&admin.key(), admin_record.as_deref(), AdminConfig::CAN_REVOKE) @ FormApprovalError::MissingPermission
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, admin.key().as_ref()],
+        bump = admin_record.bump
+    )]
+    pub admin_record: Option<Account<'info, AdminRecord>>,
+    
+    /// Policy catalog whose hash is stamped on the outcome
+    #[account(
+        seeds = [Config::POLICY_CATALOG_SEED],
+        bump = policy_catalog.bump
+    )]
+    pub policy_catalog: Option<Account<'info, PolicyCatalog>>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for the authority freezing or unfreezing a form approval
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String)]
+pub struct FreezeFormApproval<'info> {
+    #[account(
+        mut,
+        seeds = [Config::FORM_APPROVAL_SEED, form_id.as_bytes()],
+        bump = form_approval.bump
+    )]
+    pub form_approval: Account<'info, FormApproval>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.authority == authority.key() @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    pub authority: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for the original signer or the authority changing an approval's status
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String)]
+pub struct TransitionFormStatus<'info> {
+    #[account(
+        mut,
+        seeds = [Config::FORM_APPROVAL_SEED, form_id.as_bytes()],
+        bump = form_approval.bump,
+        constraint = form_approval.signer == admin.key()
+            || admin_config.authority == admin.key() @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub form_approval: Account<'info, FormApproval>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.authority == admin.key()
+            || admin_config.has_permission_with_record(&admin.key(), admin_record.as_deref(), AdminConfig::CAN_REVOKE) @ FormApprovalError::MissingPermission
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, admin.key().as_ref()],
+        bump = admin_record.bump
+    )]
+    pub admin_record: Option<Account<'info, AdminRecord>>,
+    
+    #[account(
+        seeds = [Config::PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ FormApprovalError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    pub admin: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for the original signer or the authority extending an approval's expiry
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String)]
+pub struct RenewFormApproval<'info> {
+    #[account(
+        mut,
+        seeds = [Config::FORM_APPROVAL_SEED, form_id.as_bytes()],
+        bump = form_approval.bump,
+        constraint = !form_approval.frozen @ FormApprovalError::FormFrozen,
+        constraint = form_approval.signer == admin.key()
+            || admin_config.authority == admin.key() @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub form_approval: Account<'info, FormApproval>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.authority == admin.key()
+            || admin_config.has_permission_with_record(&admin.key(), admin_record.as_deref(), AdminConfig::CAN_UPDATE) @ FormApprovalError::MissingPermission
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, admin.key().as_ref()],
+        bump = admin_record.bump
+    )]
+    pub admin_record: Option<Account<'info, AdminRecord>>,
+    
+    #[account(
+        seeds = [Config::PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ FormApprovalError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    pub admin: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for an admin recording that a form submission was declined
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String)]
+pub struct RejectFormSubmission<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = FormRejection::space(form_id.len()),
+        seeds = [Config::FORM_REJECTION_SEED, form_id.as_bytes()],
+        bump
+    )]
+    pub form_rejection: Account<'info, FormRejection>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.is_admin_with_record(&admin.key(), admin_record.as_deref()) @ FormApprovalError::UnauthorizedAdmin,
+        constraint = admin_config.has_permission_with_record(&admin.key(), admin_record.as_deref(), AdminConfig::CAN_SIGN) @ FormApprovalError::MissingPermission
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, admin.key().as_ref()],
+        bump = admin_record.bump
+    )]
+    pub admin_record: Option<Account<'info, AdminRecord>>,
+    
+    /// Policy catalog whose hash is stamped on the outcome
+    #[account(
+        seeds = [Config::POLICY_CATALOG_SEED],
+        bump = policy_catalog.bump
+    )]
+    pub policy_catalog: Option<Account<'info, PolicyCatalog>>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for a signer recording consent before signing a form
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String)]
+pub struct RecordConsent<'info> {
+    #[account(
+        init,
+        payer = signer,
+        space = ConsentRecord::space(form_id.len()),
+        seeds = [Config::CONSENT_SEED, form_id.as_bytes(), signer.key().as_ref()],
+        bump
+    )]
+    pub consent_record: Account<'info, ConsentRecord>,
+    
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for the original signer or the authority sharing an approval's metadata key
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String, grantee: Pubkey)]
+pub struct GrantAccess<'info> {
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AccessGrant::space(form_id.len()),
+        seeds = [Config::ACCESS_GRANT_SEED, form_approval.key().as_ref(), grantee.as_ref()],
+        bump
+    )]
+    pub access_grant: Account<'info, AccessGrant>,
+    
+    #[account(
+        seeds = [Config::FORM_APPROVAL_SEED, form_id.as_bytes()],
+        bump = form_approval.bump,
+        constraint = form_approval.signer == admin.key()
+            || admin_config.authority == admin.key() @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub form_approval: Account<'info, FormApproval>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for withdrawing an access grant before it expires
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String, grantee: Pubkey)]
+pub struct RevokeAccess<'info> {
+    #[account(
+        mut,
+        close = granted_by,
+        seeds = [Config::ACCESS_GRANT_SEED, form_approval.key().as_ref(), grantee.as_ref()],
+        bump = access_grant.bump,
+        has_one = granted_by
+    )]
+    pub access_grant: Account<'info, AccessGrant>,
+    
+    #[account(
+        seeds = [Config::FORM_APPROVAL_SEED, form_id.as_bytes()],
+        bump = form_approval.bump
+    )]
+    pub form_approval: Account<'info, FormApproval>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin.key() == granted_by.key()
+            || admin_config.authority == admin.key() @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(mut)]
+    pub granted_by: SystemAccount<'info>,
+    
+    pub admin: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for an admin attaching a review note to an approval
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String)]
+pub struct AddAnnotation<'info> {
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AnnotationLog::space(),
+        seeds = [Config::ANNOTATION_LOG_SEED, form_approval.key().as_ref()],
+        bump
+    )]
+    pub annotation_log: Account<'info, AnnotationLog>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = Annotation::space(form_id.len()),
+        seeds = [
+            Config::ANNOTATION_SEED,
+            form_approval.key().as_ref(),
+            &annotation_log.count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub annotation: Account<'info, Annotation>,
+    
+    #[account(
+        seeds = [Config::FORM_APPROVAL_SEED, form_id.as_bytes()],
+        bump = form_approval.bump
+    )]
+    pub form_approval: Account<'info, FormApproval>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.is_admin_with_record(&admin.key(), admin_record.as_deref()) @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, admin.key().as_ref()],
+        bump = admin_record.bump
+    )]
+    pub admin_record: Option<Account<'info, AdminRecord>>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for an admin managing their own notification preferences
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct SetNotificationPrefs<'info> {
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = NotificationPrefs::space(),
+        seeds = [Config::NOTIFICATION_PREFS_SEED, admin.key().as_ref()],
+        bump
+    )]
+    pub notification_prefs: Account<'info, NotificationPrefs>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.is_admin_with_record(&admin.key(), admin_record.as_deref()) @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, admin.key().as_ref()],
+        bump = admin_record.bump
+    )]
+    pub admin_record: Option<Account<'info, AdminRecord>>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for the reminder crank; anyone may send a reminder the admin's preferences allow
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String)]
+pub struct SendSignatureReminder<'info> {
+    #[account(
+        seeds = [Config::FORM_APPROVAL_SEED, form_id.as_bytes()],
+        bump = form_approval.bump
+    )]
+    pub form_approval: Account<'info, FormApproval>,
+    
+    #[account(
+        mut,
+        seeds = [Config::NOTIFICATION_PREFS_SEED, notification_prefs.admin.as_ref()],
+        bump = notification_prefs.bump
+    )]
+    pub notification_prefs: Account<'info, NotificationPrefs>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.is_admin_with_record(&notification_prefs.admin, admin_record.as_deref()) @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, notification_prefs.admin.as_ref()],
+        bump = admin_record.bump
+    )]
+    pub admin_record: Option<Account<'info, AdminRecord>>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for a counterparty named on a form attaching their signature
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String)]
+pub struct CounterSignForm<'info> {
+    #[account(
+        init,
+        payer = signer,
+        space = CounterSignature::space(form_id.len()),
+        seeds = [Config::COUNTER_SIGNATURE_SEED, form_id.as_bytes(), signer.key().as_ref()],
+        bump
+    )]
+    pub counter_signature: Account<'info, CounterSignature>,
+    
+    #[account(
+        mut,
+        seeds = [Config::FORM_APPROVAL_SEED, form_id.as_bytes()],
+        bump = form_approval.bump,
+        constraint = !form_approval.frozen @ FormApprovalError::FormFrozen
+    )]
+    pub form_approval: Account<'info, FormApproval>,
+    
+    #[account(
+        seeds = [Config::PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.paused @ FormApprovalError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for registering a wallet as an observer of a form
+#[derive(Accounts)]
+#[instruction(form_id: String)]
+pub struct RegisterObserver<'info> {
+    #[account(
+        init,
+        payer = observer,
+        space = FormObserver::space(form_id.len()),
+        seeds = [Config::OBSERVER_SEED, form_id.as_bytes(), observer.key().as_ref()],
+        bump
+    )]
+    pub form_observer: Account<'info, FormObserver>,
+    
+    #[account(mut)]
+    pub observer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for removing a form observer
+#[derive(Accounts)]
+#[instruction(form_id: String)]
+pub struct UnregisterObserver<'info> {
+    #[account(
+        mut,
+        close = observer,
+        seeds = [Config::OBSERVER_SEED, form_id.as_bytes(), observer.key().as_ref()],
+        bump = form_observer.bump
+    )]
+    pub form_observer: Account<'info, FormObserver>,
+    
+    #[account(mut)]
+    pub observer: Signer<'info>,
+}
+
+/// Context for registering a wallet as an observer of a form in an organization's namespace
+#[derive(Accounts)]
+#[instruction(form_id: String)]
+pub struct RegisterOrgObserver<'info> {
+    #[account(
+        init,
+        payer = observer,
+        space = FormObserver::space(form_id.len()),
+        seeds = [
+            Config::ORG_OBSERVER_SEED,
+            organization.key().as_ref(),
+            form_id.as_bytes(),
+            observer.key().as_ref()
+        ],
+        bump
+    )]
+    pub form_observer: Account<'info, FormObserver>,
+    
+    #[account(
+        seeds = [Config::ORGANIZATION_SEED, organization.org_id.as_bytes()],
+        bump = organization.bump
+    )]
+    pub organization: Account<'info, Organization>,
+    
+    #[account(mut)]
+    pub observer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for removing an observer of a form in an organization's namespace
+#[derive(Accounts)]
+#[instruction(form_id: String)]
+pub struct UnregisterOrgObserver<'info> {
+    #[account(
+        mut,
+        close = observer,
+        seeds = [
+            Config::ORG_OBSERVER_SEED,
+            form_observer.organization.as_ref(),
+            form_id.as_bytes(),
+            observer.key().as_ref()
+        ],
+        bump = form_observer.bump
+    )]
+    pub form_observer: Account<'info, FormObserver>,
+    
+    #[account(mut)]
+    pub observer: Signer<'info>,
+}
+
+/// Context for closing a form approval and refunding its rent
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String)]
+pub struct CloseFormApproval<'info> {
+    #[account(
+        mut,
+        close = recipient,
+        seeds = [Config::FORM_APPROVAL_SEED, form_id.as_bytes()],
+        bump = form_approval.bump,
+        constraint = form_approval.signer == admin.key()
+            || admin_config.authority == admin.key() @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub form_approval: Account<'info, FormApproval>,
+    
+    /// Retention receipt for the approval, required when archiving before close is enforced
+    #[account(
+        seeds = [Config::ARCHIVE_RECORD_SEED, form_approval.key().as_ref()],
+        bump = archive_record.bump
+    )]
+    pub archive_record: Option<Account<'info, ArchiveRecord>>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [Config::PROGRAM_CONFIG_SEED],
+        bump = program_config.bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    /// CHECK: only receives the refunded rent
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+    
+    pub admin: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for recording that an approval was exported to retention storage
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String)]
+pub struct ArchiveFormApproval<'info> {
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = ArchiveRecord::space(form_id.len()),
+        seeds = [Config::ARCHIVE_RECORD_SEED, form_approval.key().as_ref()],
+        bump
+    )]
+    pub archive_record: Account<'info, ArchiveRecord>,
+    
+    #[account(
+        seeds = [Config::FORM_APPROVAL_SEED, form_id.as_bytes()],
+        bump = form_approval.bump
+    )]
+    pub form_approval: Account<'info, FormApproval>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.is_admin_with_record(&admin.key(), admin_record.as_deref()) @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, admin.key().as_ref()],
+        bump = admin_record.bump
+    )]
+    pub admin_record: Option<Account<'info, AdminRecord>>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for recording a batch of approvals exported in an Arweave bundle
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(bundle_id: [u8; 32])]
+pub struct ArchiveApproval<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = ArchiveBundle::space(),
+        seeds = [Config::ARCHIVE_BUNDLE_SEED, bundle_id.as_ref()],
+        bump
+    )]
+    pub archive_bundle: Account<'info, ArchiveBundle>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.is_admin_with_record(&admin.key(), admin_record.as_deref()) @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, admin.key().as_ref()],
+        bump = admin_record.bump
+    )]
+    pub admin_record: Option<Account<'info, AdminRecord>>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for an admin attesting that an approval was mirrored elsewhere
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String, location: MirrorLocation, remote_tx_hash: [u8; 32])]
+pub struct RecordMirrorAttestation<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = MirrorAttestation::space(form_id.len()),
+        seeds = [Config::MIRROR_ATTESTATION_SEED, form_approval.key().as_ref(), remote_tx_hash.as_ref()],
+        bump
+    )]
+    pub mirror_attestation: Account<'info, MirrorAttestation>,
+    
+    #[account(
+        seeds = [Config::FORM_APPROVAL_SEED, form_id.as_bytes()],
+        bump = form_approval.bump
+    )]
+    pub form_approval: Account<'info, FormApproval>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.is_admin_with_record(&admin.key(), admin_record.as_deref()) @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, admin.key().as_ref()],
+        bump = admin_record.bump
+    )]
+    pub admin_record: Option<Account<'info, AdminRecord>>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for an admin withdrawing a mirror attestation whose copy is gone
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String, remote_tx_hash: [u8; 32])]
+pub struct RemoveMirrorAttestation<'info> {
+    #[account(
+        mut,
+        close = admin,
+        seeds = [Config::MIRROR_ATTESTATION_SEED, form_approval.key().as_ref(), remote_tx_hash.as_ref()],
+        bump = mirror_attestation.bump
+    )]
+    pub mirror_attestation: Account<'info, MirrorAttestation>,
+    
+    #[account(
+        seeds = [Config::FORM_APPROVAL_SEED, form_id.as_bytes()],
+        bump = form_approval.bump
+    )]
+    pub form_approval: Account<'info, FormApproval>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.is_admin_with_record(&admin.key(), admin_record.as_deref()) @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, admin.key().as_ref()],
+        bump = admin_record.bump
+    )]
+    pub admin_record: Option<Account<'info, AdminRecord>>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for indexing an approval under an external reference
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String, key_hash: [u8; 32])]
+pub struct AddSearchKey<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = SearchKey::space(form_id.len()),
+        seeds = [Config::SEARCH_KEY_SEED, key_hash.as_ref()],
+        bump
+    )]
+    pub search_key: Account<'info, SearchKey>,
+    
+    #[account(
+        seeds = [Config::FORM_APPROVAL_SEED, form_id.as_bytes()],
+        bump = form_approval.bump
+    )]
+    pub form_approval: Account<'info, FormApproval>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.is_admin_with_record(&admin.key(), admin_record.as_deref()) @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, admin.key().as_ref()],
+        bump = admin_record.bump
+    )]
+    pub admin_record: Option<Account<'info, AdminRecord>>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for removing a search key
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(key_hash: [u8; 32])]
+pub struct RemoveSearchKey<'info> {
+    #[account(
+        mut,
+        close = created_by,
+        seeds = [Config::SEARCH_KEY_SEED, key_hash.as_ref()],
+        bump = search_key.bump
+    )]
+    pub search_key: Account<'info, SearchKey>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.is_admin_with_record(&admin.key(), admin_record.as_deref()) @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, admin.key().as_ref()],
+        bump = admin_record.bump
+    )]
+    pub admin_record: Option<Account<'info, AdminRecord>>,
+    
+    /// CHECK: receives the index rent; must be the admin that created it
+    #[account(mut, address = search_key.created_by)]
+    pub created_by: UncheckedAccount<'info>,
+    
+    pub admin: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for signing a corrected document under a revoked form ID
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String)]
+pub struct ReapproveFormSubmission<'info> {
+    #[account(
+        mut,
+        seeds = [Config::FORM_APPROVAL_SEED, form_id.as_bytes()],
+        bump = form_approval.bump
+    )]
+    pub form_approval: Account<'info, FormApproval>,
+    
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = ApprovalChain::space(),
+        seeds = [Config::APPROVAL_CHAIN_SEED],
+        bump
+    )]
+    pub approval_chain: Account<'info, ApprovalChain>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.is_admin_with_record(&admin.key(), admin_record.as_deref()) @ FormApprovalError::UnauthorizedAdmin,
+        constraint = admin_config.has_permission_with_record(&admin.key(), admin_record.as_deref(), AdminConfig::CAN_SIGN) @ FormApprovalError::MissingPermission
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, admin.key().as_ref()],
+        bump = admin_record.bump
+    )]
+    pub admin_record: Option<Account<'info, AdminRecord>>,
+    
+    #[account(
+        seeds = [Config::PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.is_deprecated(ProgramInstruction::ReapproveFormSubmission) @ FormApprovalError::InstructionDeprecated,
+        constraint = !program_config.paused @ FormApprovalError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for upgrading a legacy form approval to the current layout
+#[derive(Accounts)]
+#[instruction(form_id: String)]
+pub struct MigrateFormApproval<'info> {
+    /// CHECK: legacy accounts do not deserialize as `FormApproval`; the layout is validated in the handler
+    #[account(
+        mut,
+        seeds = [Config::FORM_APPROVAL_SEED, form_id.as_bytes()],
+        bump,
+        owner = crate::ID
+    )]
+    pub form_approval: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for recording the next approval checkpoint
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct CreateCheckpoint<'info> {
+    #[account(
+        mut,
+        seeds = [Config::PROGRAM_CONFIG_SEED],
+        bump = program_config.bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = Checkpoint::space(),
+        seeds = [Config::CHECKPOINT_SEED, &program_config.checkpoint_count.to_le_bytes()],
+        bump
+    )]
+    pub checkpoint: Account<'info, Checkpoint>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.is_admin_with_record(&admin.key(), admin_record.as_deref()) @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, admin.key().as_ref()],
+        bump = admin_record.bump
+    )]
+    pub admin_record: Option<Account<'info, AdminRecord>>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for co-signing a checkpoint root
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(checkpoint_id: u64)]
+pub struct AttestCheckpoint<'info> {
+    #[account(
+        mut,
+        seeds = [Config::CHECKPOINT_SEED, &checkpoint_id.to_le_bytes()],
+        bump = checkpoint.bump
+    )]
+    pub checkpoint: Account<'info, Checkpoint>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.is_admin_with_record(&admin.key(), admin_record.as_deref()) @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, admin.key().as_ref()],
+        bump = admin_record.bump
+    )]
+    pub admin_record: Option<Account<'info, AdminRecord>>,
+    
+    pub admin: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for adding new admins, followed by the record address of each as a remaining account
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct AddAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.can_manage_admins_with_record(&authority.key(), authority_record.as_deref()) @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, authority.key().as_ref()],
+        bump = authority_record.bump
+    )]
+    pub authority_record: Option<Account<'info, AdminRecord>>,
+    
+    #[account(
+        seeds = [Config::PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.is_deprecated(ProgramInstruction::AddAdmin) @ FormApprovalError::InstructionDeprecated,
+        constraint = program_config.admin_change_delay == 0 @ FormApprovalError::AdminChangeTimelocked
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    pub authority: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for registering an admin as its own record account
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(admin: Pubkey)]
+pub struct AddAdminRecord<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = AdminRecord::space(),
+        seeds = [Config::ADMIN_RECORD_SEED, admin.as_ref()],
+        bump
+    )]
+    pub admin_record: Account<'info, AdminRecord>,
+    
+    #[account(
+        mut,
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.can_manage_admins_with_record(&authority.key(), authority_record.as_deref()) @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, authority.key().as_ref()],
+        bump = authority_record.bump
+    )]
+    pub authority_record: Option<Account<'info, AdminRecord>>,
+    
+    #[account(
+        seeds = [Config::PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = program_config.admin_change_delay == 0 @ FormApprovalError::AdminChangeTimelocked
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for the authority changing a registered admin's permissions or expiry
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(admin: Pubkey)]
+pub struct UpdateAdminRecord<'info> {
+    #[account(
+        mut,
+        seeds = [Config::ADMIN_RECORD_SEED, admin.as_ref()],
+        bump = admin_record.bump
+    )]
+    pub admin_record: Account<'info, AdminRecord>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = !admin_config.is_renounced() @ FormApprovalError::AuthorityRenounced,
+        constraint = admin_config.authority == authority.key() @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    pub authority: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for removing a registered admin and reclaiming its record rent
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(admin: Pubkey)]
+pub struct RemoveAdminRecord<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [Config::ADMIN_RECORD_SEED, admin.as_ref()],
+        bump = admin_record.bump
+    )]
+    pub admin_record: Account<'info, AdminRecord>,
+    
+    #[account(
+        mut,
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.can_manage_admins_with_record(&authority.key(), authority_record.as_deref()) @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, authority.key().as_ref()],
+        bump = authority_record.bump
+    )]
+    pub authority_record: Option<Account<'info, AdminRecord>>,
+    
+    #[account(
+        seeds = [Config::PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = program_config.admin_change_delay == 0 @ FormApprovalError::AdminChangeTimelocked
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for inviting a key to become an admin
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(invitee: Pubkey)]
+pub struct InviteAdmin<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = AdminInvitation::space(),
+        seeds = [Config::ADMIN_INVITATION_SEED, invitee.as_ref()],
+        bump
+    )]
+    pub admin_invitation: Account<'info, AdminInvitation>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.can_manage_admins_with_record(&authority.key(), authority_record.as_deref()) @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    /// CHECK: record address of the key being added, which must not hold an `AdminRecord`
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, invitee.as_ref()],
+        bump,
+        constraint = new_admin_record.data_is_empty() @ FormApprovalError::AdminAlreadyExists
+    )]
+    pub new_admin_record: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, authority.key().as_ref()],
+        bump = authority_record.bump
+    )]
+    pub authority_record: Option<Account<'info, AdminRecord>>,
+    
+    #[account(
+        seeds = [Config::PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.is_deprecated(ProgramInstruction::InviteAdmin) @ FormApprovalError::InstructionDeprecated,
+        constraint = program_config.admin_change_delay == 0 @ FormApprovalError::AdminChangeTimelocked
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for the invitee accepting an admin invitation
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct AcceptAdminInvitation<'info> {
+    #[account(
+        mut,
+        close = invited_by,
+        seeds = [Config::ADMIN_INVITATION_SEED, invitee.key().as_ref()],
+        bump = admin_invitation.bump
+    )]
+    pub admin_invitation: Account<'info, AdminInvitation>,
+    
+    #[account(
+        mut,
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    /// CHECK: record address of the key being added, which must not hold an `AdminRecord`
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, invitee.key().as_ref()],
+        bump,
+        constraint = new_admin_record.data_is_empty() @ FormApprovalError::AdminAlreadyExists
+    )]
+    pub new_admin_record: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [Config::PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = program_config.admin_change_delay == 0 @ FormApprovalError::AdminChangeTimelocked
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    /// CHECK: receives the invitation rent; must be the inviting authority
+    #[account(mut, address = admin_invitation.invited_by)]
+    pub invited_by: UncheckedAccount<'info>,
+    
+    pub invitee: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for the authority withdrawing a pending admin invitation
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(invitee: Pubkey)]
+pub struct CancelAdminInvitation<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [Config::ADMIN_INVITATION_SEED, invitee.as_ref()],
+        bump = admin_invitation.bump
+    )]
+    pub admin_invitation: Account<'info, AdminInvitation>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.can_manage_admins_with_record(&authority.key(), authority_record.as_deref()) @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, authority.key().as_ref()],
+        bump = authority_record.bump
+    )]
+    pub authority_record: Option<Account<'info, AdminRecord>>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for scheduling the removal of an admin
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(admin: Pubkey)]
+pub struct ScheduleAdminRemoval<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = AdminRemovalSchedule::space(),
+        seeds = [Config::ADMIN_REMOVAL_SEED, admin.as_ref()],
+        bump
+    )]
+    pub admin_removal: Account<'info, AdminRemovalSchedule>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.can_manage_admins_with_record(&authority.key(), authority_record.as_deref()) @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, authority.key().as_ref()],
+        bump = authority_record.bump
+    )]
+    pub authority_record: Option<Account<'info, AdminRecord>>,
+    
+    #[account(
+        seeds = [Config::PROGRAM_CONFIG_SEED],
+        bump = program_config.bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for cancelling a scheduled admin removal
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(admin: Pubkey)]
+pub struct CancelAdminRemoval<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [Config::ADMIN_REMOVAL_SEED, admin.as_ref()],
+        bump = admin_removal.bump
+    )]
+    pub admin_removal: Account<'info, AdminRemovalSchedule>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.can_manage_admins_with_record(&authority.key(), authority_record.as_deref()) @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, authority.key().as_ref()],
+        bump = authority_record.bump
+    )]
+    pub authority_record: Option<Account<'info, AdminRecord>>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for executing a scheduled admin removal once it is effective
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(admin: Pubkey)]
+pub struct ExecuteAdminRemoval<'info> {
+    #[account(
+        mut,
+        close = scheduled_by,
+        seeds = [Config::ADMIN_REMOVAL_SEED, admin.as_ref()],
+        bump = admin_removal.bump
+    )]
+    pub admin_removal: Account<'info, AdminRemovalSchedule>,
+    
+    #[account(
+        mut,
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [Config::PROGRAM_CONFIG_SEED],
+        bump = program_config.bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    /// CHECK: receives the schedule rent; must be the scheduling authority
+    #[account(mut, address = admin_removal.scheduled_by)]
+    pub scheduled_by: UncheckedAccount<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for changing an admin's signature weight
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct SetAdminWeight<'info> {
+    #[account(
+        mut,
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.can_manage_admins_with_record(&authority.key(), authority_record.as_deref()) @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, authority.key().as_ref()],
+        bump = authority_record.bump
+    )]
+    pub authority_record: Option<Account<'info, AdminRecord>>,
+    
+    pub authority: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for queueing a timelocked admin change
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct QueueAdminChange<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = PendingAdminChange::space(),
+        seeds = [Config::ADMIN_CHANGE_SEED, program_config.admin_change_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub pending_admin_change: Account<'info, PendingAdminChange>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.can_manage_admins_with_record(&authority.key(), authority_record.as_deref()) @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    /// CHECK: record address of the admin the change targets, checked by the handler
+    pub admin_record: Option<UncheckedAccount<'info>>,
+    
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, authority.key().as_ref()],
+        bump = authority_record.bump
+    )]
+    pub authority_record: Option<Account<'info, AdminRecord>>,
+    
+    #[account(
+        mut,
+        seeds = [Config::PROGRAM_CONFIG_SEED],
+        bump = program_config.bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for applying a queued admin change once its timelock passed
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct FinalizeAdminChange<'info> {
+    #[account(
+        mut,
+        close = queued_by,
+        seeds = [Config::ADMIN_CHANGE_SEED, id.to_le_bytes().as_ref()],
+        bump = pending_admin_change.bump
+    )]
+    pub pending_admin_change: Account<'info, PendingAdminChange>,
+    
+    #[account(
+        mut,
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    /// CHECK: record address of the admin the change targets, checked by the handler
+    #[account(mut)]
+    pub admin_record: Option<UncheckedAccount<'info>>,
+    
+    #[account(
+        mut,
+        seeds = [Config::PROGRAM_CONFIG_SEED],
+        bump = program_config.bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    /// CHECK: receives the change rent; must be the key that queued it
+    #[account(mut, address = pending_admin_change.queued_by)]
+    pub queued_by: UncheckedAccount<'info>,
+    
+    /// Pays the rent of a record an `AddRecord` change creates
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,    
+    pub system_program: Option<Program<'info, System>>,
+}
+
+/// Context for any admin or the authority cancelling a queued admin change
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct CancelAdminChange<'info> {
+    #[account(
+        mut,
+        close = queued_by,
+        seeds = [Config::ADMIN_CHANGE_SEED, id.to_le_bytes().as_ref()],
+        bump = pending_admin_change.bump
+    )]
+    pub pending_admin_change: Account<'info, PendingAdminChange>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.is_admin_with_record(&admin.key(), admin_record.as_deref())
+            || admin_config.authority == admin.key() @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, admin.key().as_ref()],
+        bump = admin_record.bump
+    )]
+    pub admin_record: Option<Account<'info, AdminRecord>>,
+    
+    /// CHECK: receives the change rent; must be the key that queued it
+    #[account(mut, address = pending_admin_change.queued_by)]
+    pub queued_by: UncheckedAccount<'info>,
+    
+    pub admin: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for the authority changing an admin's permissions or expiry
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct SetAdminPermissions<'info> {
+    #[account(
+        mut,
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.authority == authority.key() @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    pub authority: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for the authority proposing its successor
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct ProposeAuthorityTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.authority == authority.key() @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    pub authority: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for the authority permanently giving up its role
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct RenounceAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.authority == authority.key() @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    pub authority: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for the proposed authority taking over
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct AcceptAuthorityTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.pending_authority == new_authority.key() @ FormApprovalError::NotPendingAuthority
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    pub new_authority: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for removing an admin
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct RemoveAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.can_manage_admins_with_record(&authority.key(), authority_record.as_deref()) @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, authority.key().as_ref()],
+        bump = authority_record.bump
+    )]
+    pub authority_record: Option<Account<'info, AdminRecord>>,
+    
+    #[account(
+        seeds = [Config::PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.is_deprecated(ProgramInstruction::RemoveAdmin) @ FormApprovalError::InstructionDeprecated,
+        constraint = program_config.admin_change_delay == 0 @ FormApprovalError::AdminChangeTimelocked
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    pub authority: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for a key registering itself as an admin with an import proof
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct RegisterAdminWithProof<'info> {
+    #[account(
+        mut,
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    /// CHECK: record address of the key being added, which must not hold an `AdminRecord`
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, candidate.key().as_ref()],
+        bump,
+        constraint = new_admin_record.data_is_empty() @ FormApprovalError::AdminAlreadyExists
+    )]
+    pub new_admin_record: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [Config::PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = program_config.admin_change_delay == 0 @ FormApprovalError::AdminChangeTimelocked
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    pub candidate: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for replacing one admin with another in a single step
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(old_admin: Pubkey, new_admin: Pubkey)]
+pub struct ReplaceAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.can_manage_admins_with_record(&authority.key(), authority_record.as_deref()) @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    /// CHECK: record address of the key being added, which must not hold an `AdminRecord`
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, new_admin.as_ref()],
+        bump,
+        constraint = new_admin_record.data_is_empty() @ FormApprovalError::AdminAlreadyExists
+    )]
+    pub new_admin_record: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [Config::ADMIN_RECORD_SEED, authority.key().as_ref()],
+        bump = authority_record.bump
+    )]
+    pub authority_record: Option<Account<'info, AdminRecord>>,
+    
+    #[account(
+        seeds = [Config::PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.is_deprecated(ProgramInstruction::ReplaceAdmin) @ FormApprovalError::InstructionDeprecated,
+        constraint = program_config.admin_change_delay == 0 @ FormApprovalError::AdminChangeTimelocked
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    pub authority: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for verifying a form approval
+#[derive(Accounts)]
+#[instruction(form_id: String)]
+pub struct VerifyFormApproval<'info> {
+    #[account(
+        seeds = [Config::FORM_APPROVAL_SEED, form_id.as_bytes()],
+        bump = form_approval.bump
+    )]
+    pub form_approval: Account<'info, FormApproval>,
+}
+
+/// Context for revoking a form approval in an organization's namespace
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String)]
+pub struct RevokeOrgFormApproval<'info> {
+    #[account(
+        mut,
+        seeds = [Config::ORG_FORM_APPROVAL_SEED, organization.key().as_ref(), form_id.as_bytes()],
+        bump = form_approval.bump,
+        constraint = form_approval.signer == admin.key()
+            || organization.authority == admin.key() @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub form_approval: Account<'info, FormApproval>,
+    
+    /// History the change is appended to
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = FormHistory::space(),
+        seeds = [Config::FORM_HISTORY_SEED, form_approval.key().as_ref()],
+        bump
+    )]
+    pub form_history: Account<'info, FormHistory>,
+    
+    #[account(
+        mut,
+        seeds = [Config::ORGANIZATION_SEED, organization.org_id.as_bytes()],
+        bump = organization.bump,
+        constraint = !organization.is_suspended() @ FormApprovalError::OrgSuspended
+    )]
+    pub organization: Account<'info, Organization>,
+    
+    /// Policy catalog whose hash is stamped on the outcome
+    #[account(
+        seeds = [Config::POLICY_CATALOG_SEED],
+        bump = policy_catalog.bump
+    )]
+    pub policy_catalog: Option<Account<'info, PolicyCatalog>>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for a counter-party organization admin signing a cross-organization approval
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String)]
+pub struct CountersignOrgFormApproval<'info> {
+    #[account(
+        mut,
+        seeds = [Config::ORG_FORM_APPROVAL_SEED, organization.key().as_ref(), form_id.as_bytes()],
+        bump = form_approval.bump,
+        constraint = form_approval.counterparty_org == counterparty_organization.key() @ FormApprovalError::NotCounterpartyOrg
+    )]
+    pub form_approval: Account<'info, FormApproval>,
+    
+    #[account(
+        seeds = [Config::ORGANIZATION_SEED, organization.org_id.as_bytes()],
+        bump = organization.bump,
+        constraint = !organization.is_suspended() @ FormApprovalError::OrgSuspended
+    )]
+    pub organization: Account<'info, Organization>,
+    
+    #[account(
+        seeds = [Config::ORGANIZATION_SEED, counterparty_organization.org_id.as_bytes()],
+        bump = counterparty_organization.bump,
+        constraint = counterparty_organization.is_admin(&admin.key()) @ FormApprovalError::UnauthorizedAdmin,
+        constraint = !counterparty_organization.is_suspended() @ FormApprovalError::OrgSuspended
+    )]
+    pub counterparty_organization: Account<'info, Organization>,
+    
+    #[account(
+        seeds = [Config::PROGRAM_CONFIG_SEED],
+        bump = program_config.bump,
+        constraint = !program_config.is_deprecated(ProgramInstruction::CountersignOrgFormApproval) @ FormApprovalError::InstructionDeprecated,
+        constraint = !program_config.paused @ FormApprovalError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    pub admin: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for verifying a form approval in an organization's namespace
+#[derive(Accounts)]
+#[instruction(form_id: String)]
+pub struct VerifyOrgFormApproval<'info> {
+    #[account(
+        seeds = [Config::ORG_FORM_APPROVAL_SEED, organization.key().as_ref(), form_id.as_bytes()],
+        bump = form_approval.bump
+    )]
+    pub form_approval: Account<'info, FormApproval>,
+    
+    #[account(
+        seeds = [Config::ORGANIZATION_SEED, organization.org_id.as_bytes()],
+        bump = organization.bump
+    )]
+    pub organization: Account<'info, Organization>,
+}
+
+/// Context for verifying a form approval and recording the verification
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String)]
+pub struct VerifyAndRecord<'info> {
+    #[account(
+        seeds = [Config::FORM_APPROVAL_SEED, form_id.as_bytes()],
+        bump = form_approval.bump
+    )]
+    pub form_approval: Account<'info, FormApproval>,
+    
+    #[account(
+        init_if_needed,
+        payer = verifier,
+        space = VerificationStats::space(),
+        seeds = [Config::VERIFICATION_STATS_SEED, form_approval.key().as_ref()],
+        bump
+    )]
+    pub verification_stats: Account<'info, VerificationStats>,
+    
+    #[account(
+        init_if_needed,
+        payer = verifier,
+        space = VerifierRecord::space(),
+        seeds = [Config::VERIFIER_RECORD_SEED, form_approval.key().as_ref(), verifier.key().as_ref()],
+        bump
+    )]
+    pub verifier_record: Account<'info, VerifierRecord>,
+    
+    #[account(
+        seeds = [Config::PROGRAM_CONFIG_SEED],
+        bump = program_config.bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(mut)]
+    pub verifier: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for reporting a signing attempt by an unauthorized key
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(attempted_by: Pubkey)]
+pub struct ReportUnauthorizedAttempt<'info> {
+    #[account(
+        init_if_needed,
+        payer = reporter,
+        space = AbuseCounters::space(),
+        seeds = [Config::ABUSE_COUNTERS_SEED],
+        bump
+    )]
+    pub abuse_counters: Account<'info, AbuseCounters>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = !admin_config.is_admin(&attempted_by) @ FormApprovalError::ReportedKeyIsAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for the invariant health check; a tally's signer approvals are passed as remaining accounts
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String)]
+pub struct AssertInvariants<'info> {
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [Config::PROGRAM_CONFIG_SEED],
+        bump = program_config.bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+    
+    #[account(
+        seeds = [Config::APPROVAL_TALLY_SEED, form_id.as_bytes()],
+        bump = approval_tally.bump
+    )]
+    pub approval_tally: Option<Account<'info, ApprovalTally>>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+/// Context for aggregating approvals of a form; the approvals are passed as remaining accounts
+#[derive(Accounts)]
+pub struct VerifyFormSignatures<'info> {
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+}
+
+/// Context for depositing SOL, or SPL tokens when the token accounts are passed, against a form
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String)]
+pub struct CreateEscrow<'info> {
+    #[account(
+        init,
+        payer = depositor,
+        space = Escrow::space(form_id.len()),
+        seeds = [Config::ESCROW_SEED, form_id.as_bytes(), depositor.key().as_ref()],
+        bump
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+    
+    /// Mint of the deposited tokens
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    /// Depositor's token account the tokens are taken from
+    #[account(mut)]
+    pub depositor_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Token account owned by the escrow that holds the tokens until settlement
+    #[account(
+        init,
+        payer = depositor,
+        seeds = [Config::ESCROW_VAULT_SEED, escrow.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = escrow,
+        token::token_program = token_program
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for settling an escrow; anyone may crank it once the outcome is known
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+#[instruction(form_id: String)]
+pub struct SettleEscrow<'info> {
+    #[account(
+        mut,
+        close = depositor,
+        seeds = [Config::ESCROW_SEED, form_id.as_bytes(), escrow.depositor.as_ref()],
+        bump = escrow.bump,
+        has_one = depositor,
+        has_one = counterparty
+    )]
+    pub escrow: Account<'info, Escrow>,
+    
+    /// Approval of the form, if one exists
+    #[account(
+        seeds = [Config::FORM_APPROVAL_SEED, form_id.as_bytes()],
+        bump = form_approval.bump
+    )]
+    pub form_approval: Option<Account<'info, FormApproval>>,
+    
+    /// Rejection record of the form, if its submission was declined
+    #[account(
+        seeds = [Config::FORM_REJECTION_SEED, form_id.as_bytes()],
+        bump = form_rejection.bump
+    )]
+    pub form_rejection: Option<Account<'info, FormRejection>>,
+    
+    #[account(mut)]
+    pub depositor: SystemAccount<'info>,
+    
+    #[account(mut)]
+    pub counterparty: SystemAccount<'info>,
+    
+    /// Mint of the escrowed tokens
+    #[account(address = escrow.mint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+    
+    #[account(
+        mut,
+        seeds = [Config::ESCROW_VAULT_SEED, escrow.key().as_ref()],
+        bump
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Token account of the party being paid: the counterparty on release, the depositor on refund
+    #[account(mut, token::mint = escrow.mint)]
+    pub recipient_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    
+    /// Global event counter, advanced by every event the instruction emits
+    #[account(
+        mut,
+        seeds = [Config::EVENT_SEQUENCE_SEED],
+        bump = event_sequence.bump
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
 }
@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::{get_return_data, invoke};
+use anchor_lang::{Discriminator, InstructionData};
+
+use crate::config::{Config, FormApprovalError};
+use crate::state::FormApproval;
+
+/// Instruction discriminators shared by conforming document-signing programs
+pub mod discriminators {
+    use super::*;
+    
+    /// `verify_form_approval(form_id, expected_hash) -> bool`
+    pub const VERIFY_FORM_APPROVAL: &[u8] = crate::instruction::VerifyFormApproval::DISCRIMINATOR;
+    
+    /// `get_form_approval_details(form_id)`
+    pub const GET_FORM_APPROVAL_DETAILS: &[u8] =
+        crate::instruction::GetFormApprovalDetails::DISCRIMINATOR;
+    
+    /// Account discriminator of approval records
+    pub const FORM_APPROVAL_ACCOUNT: &[u8] = FormApproval::DISCRIMINATOR;
+}
+
+/// Program-agnostic view of an approval record
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ApprovalRecord {
+    pub form_id: String,
+    pub form_hash: [u8; 32],
+    pub signer: Pubkey,
+    pub approved_at: i64,
+    pub approved_slot: u64,
+}
+
+impl From<&FormApproval> for ApprovalRecord {
+    fn from(approval: &FormApproval) -> Self {
+        Self {
+            form_id: approval.form_id.clone(),
+            form_hash: approval.form_hash,
+            signer: approval.signer,
+            approved_at: approval.approved_at,
+            approved_slot: approval.approved_slot,
+        }
+    }
+}
+
+/// Interface every document-signing program exposes to verifiers
+///
+/// Verification code written against this trait works unchanged for
+/// alternative or future signing programs that implement it.
+pub trait DocumentSigningProgram {
+    /// Program ID of the deployment
+    fn program_id(&self) -> Pubkey;
+    
+    /// Address of the approval record for a form ID
+    fn approval_address(&self, form_id: &str) -> Pubkey;
+    
+    /// Decode approval account data into the shared record layout
+    fn decode_approval(&self, data: &[u8]) -> Result<ApprovalRecord>;
+    
+    /// Build the read-only instruction verifying an approval against a hash
+    fn verify_instruction(&self, form_id: &str, expected_hash: [u8; 32]) -> Instruction {
+        Instruction {
+            program_id: self.program_id(),
+            accounts: vec![AccountMeta::new_readonly(self.approval_address(form_id), false)],
+            data: crate::instruction::VerifyFormApproval {
+                _form_id: form_id.to_string(),
+                expected_hash,
+            }
+            .data(),
+        }
+    }
+}
+
+/// This program's implementation of the shared interface
+pub struct SignDocumentContract {
+    pub program_id: Pubkey,
+}
+
+impl Default for SignDocumentContract {
+    fn default() -> Self {
+        Self { program_id: crate::ID }
+    }
+}
+
+impl DocumentSigningProgram for SignDocumentContract {
+    fn program_id(&self) -> Pubkey {
+        self.program_id
+    }
+    
+    fn approval_address(&self, form_id: &str) -> Pubkey {
+        Pubkey::find_program_address(
+            &[Config::FORM_APPROVAL_SEED, form_id.as_bytes()],
+            &self.program_id,
+        )
+        .0
+    }
+    
+    fn decode_approval(&self, data: &[u8]) -> Result<ApprovalRecord> {
+        let approval = FormApproval::try_deserialize(&mut &data[..])?;
+        Ok(ApprovalRecord::from(&approval))
+    }
+}
+
+/// Verify an approval through CPI into any conforming program
+pub fn verify_via_cpi<'info>(
+    program: &impl DocumentSigningProgram,
+    program_account: &AccountInfo<'info>,
+    approval_account: &AccountInfo<'info>,
+    form_id: &str,
+    expected_hash: [u8; 32],
+) -> Result<bool> {
+    require_keys_eq!(program_account.key(), program.program_id());
+    
+    let instruction = program.verify_instruction(form_id, expected_hash);
+    invoke(&instruction, &[approval_account.clone(), program_account.clone()])?;
+    
+    let (returning_program, data) =
+        get_return_data().ok_or(FormApprovalError::MissingReturnData)?;
+    require_keys_eq!(returning_program, program.program_id());
+    
+    bool::try_from_slice(&data).map_err(|_| FormApprovalError::MissingReturnData.into())
+}
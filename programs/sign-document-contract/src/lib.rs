@@ -1,15 +1,57 @@
+
+// Anchor's generated IDL instructions still call the deprecated `AccountInfo::realloc`.
+// `#[program]` emits them at the crate root, so the allow can't be narrower than this;
+// the program module and the other hand-written modules opt back into the lint.
+#![allow(deprecated)]
+
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+use anchor_spl::token_interface;
 
+#[warn(deprecated)]
 pub mod config;
+#[warn(deprecated)]
 pub mod state;
+#[warn(deprecated)]
 pub mod instructions;
+#[warn(deprecated)]
+pub mod interface;
+#[warn(deprecated)]
+pub mod merkle;
+#[cfg(feature = "localnet")]
+#[warn(deprecated)]
+pub mod localnet;
 
 use config::*;
 use instructions::*;
+#[cfg(feature = "localnet")]
+use localnet::*;
+use state::{
+    AdminChange, AdminConfig, AdminRecord, ApprovalChain, ApprovalStatus, ApprovalTally, ArchiveRecord, ConsentRecord,
+    EventSequence, FeatureGate, FeatureGates, FormAction, FormApproval, FormApprovalDetails, FormApprovalV1, FormHistory,
+    FormObserver, HashAlgorithm, HashIndex, HistoryEntry, InvariantBaseline, MirrorLocation, Organization,
+    PolicyCatalog, ProgramConfig, ProgramInstruction, ReasonCode, RegisteredApp, SemVer, SignOptions,
+    SigningProgress, TallyStatus, TemplateVersion, ThresholdPolicy,
+};
 
 declare_id!("7xMFfY7wEggjbVTQvtLYXcnAsNBFxiBDSx6ohtuxSYXt");
 
+/// Emit an event from a handler: through a self-CPI carrying the event as
+/// instruction data with the `event-cpi` feature, where it survives log
+/// truncation, and to the program log otherwise
+macro_rules! emit_event {
+    ($ctx:ident, $event:expr) => {{
+        let event = $event;
+        #[cfg(feature = "event-cpi")]
+        emit_cpi_event(&$ctx.accounts.event_authority, $ctx.bumps.event_authority, &event)?;
+        #[cfg(not(feature = "event-cpi"))]
+        emit!(event);
+    }};
+}
+
 #[program]
+#[warn(deprecated)]
 pub mod sign_document_contract {
     use super::*;
 
@@ -22,146 +64,4341 @@ pub mod sign_document_contract {
         admin_config.admins[0] = ctx.accounts.authority.key();
         admin_config.admin_count = 1;
         admin_config.bump = ctx.bumps.admin_config;
+        admin_config.weights = [0; 10];
+        admin_config.weights[0] = 1;
+        admin_config.permissions = [0; 10];
+        admin_config.permissions[0] = AdminConfig::ALL_PERMISSIONS;
+        admin_config.pending_authority = Pubkey::default();
+        admin_config.expires_at = [0; 10];
+        admin_config.record_count = 0;
+        admin_config.signatures_count = [0; 10];
+        admin_config.last_signed_at = [0; 10];
         
         msg!("Admin config initialized with authority: {}", ctx.accounts.authority.key());
         Ok(())
     }
 
-    /// Sign a form submission with blockchain approval
-    pub fn sign_form_submission(
-        ctx: Context<SignFormSubmission>,
-        form_id: String,
-        form_hash: [u8; 32],
-        metadata: Option<String>,
+    /// Initialize the program configuration, recording the deployed version
+    pub fn initialize_program_config(ctx: Context<InitializeProgramConfig>) -> Result<()> {
+        let program_config = &mut ctx.accounts.program_config;
+        
+        program_config.program_version = Config::PROGRAM_VERSION;
+        program_config.min_client_version = SemVer::default();
+        program_config.deprecated_instructions = 0;
+        program_config.checkpoint_count = 0;
+        program_config.last_checkpoint_end = 0;
+        program_config.checkpoint_quorum = 1;
+        program_config.threshold_policy = ThresholdPolicy::WeightedSum { required: 1 };
+        program_config.cpi_allow_list = Vec::new();
+        program_config.reapproval_window = 0;
+        program_config.require_app_signature = false;
+        program_config.config_sequence = 0;
+        program_config.max_signers_per_form = Config::MAX_ADMINS as u8;
+        program_config.admin_import_root = [0u8; 32];
+        program_config.strict_consent = false;
+        program_config.require_archive_before_close = false;
+        program_config.max_clock_skew = Config::DEFAULT_MAX_CLOCK_SKEW;
+        program_config.admin_change_delay = 0;
+        program_config.admin_change_count = 0;
+        program_config.invitation_ttl = Config::DEFAULT_INVITATION_TTL;
+        program_config.verifier_rate_limit = 0;
+        program_config.paused = false;
+        program_config.reject_duplicate_hashes = false;
+        program_config.bump = ctx.bumps.program_config;
+        
+        msg!("Program config initialized at version {}", Config::PROGRAM_VERSION);
+        Ok(())
+    }
+
+    /// Initialize the counter numbering every event, which emitting instructions require
+    pub fn initialize_event_sequence(ctx: Context<InitializeEventSequence>) -> Result<()> {
+        let event_sequence = &mut ctx.accounts.event_sequence;
+        event_sequence.last = 0;
+        event_sequence.bump = ctx.bumps.event_sequence;
+        
+        msg!("Event sequence initialized");
+        Ok(())
+    }
+
+    /// Set the oldest client version allowed to sign forms
+    pub fn set_min_client_version(
+        ctx: Context<UpdateProgramConfig>,
+        min_client_version: SemVer,
+    ) -> Result<()> {
+        let prior_hash = ctx.accounts.program_config.config_hash()?;
+        let program_config = &mut ctx.accounts.program_config;
+        
+        // Refresh the recorded version in case the program was upgraded
+        program_config.program_version = Config::PROGRAM_VERSION;
+        program_config.min_client_version = min_client_version;
+        
+        msg!("Minimum client version set to {}", min_client_version);
+        emit_event!(ctx, config_change(ctx.accounts, "min_client_version", prior_hash)?);
+        Ok(())
+    }
+
+    /// Retire an instruction so it fails with `InstructionDeprecated`, or restore it
+    pub fn set_instruction_deprecated(
+        ctx: Context<UpdateProgramConfig>,
+        instruction: ProgramInstruction,
+        deprecated: bool,
     ) -> Result<()> {
-        // Validate inputs
+        let prior_hash = ctx.accounts.program_config.config_hash()?;
+        ctx.accounts.program_config.set_deprecated(instruction, deprecated);
+        
+        msg!("Instruction {:?} deprecated: {}", instruction, deprecated);
+        emit_event!(ctx, config_change(ctx.accounts, "deprecated_instructions", prior_hash)?);
+        Ok(())
+    }
+
+    /// Set how many admins must attest each new checkpoint
+    pub fn set_checkpoint_quorum(ctx: Context<UpdateProgramConfig>, quorum: u8) -> Result<()> {
+        let prior_hash = ctx.accounts.program_config.config_hash()?;
         require!(
-            form_id.len() <= Config::MAX_FORM_ID_LENGTH,
-            FormApprovalError::FormIdTooLong
+            quorum >= 1 && quorum <= ctx.accounts.admin_config.admin_count,
+            FormApprovalError::InvalidQuorum
         );
         
-        if let Some(ref meta) = metadata {
-            require!(
-                meta.len() <= Config::MAX_METADATA_LENGTH,
-                FormApprovalError::MetadataTooLong
-            );
-        }
+        ctx.accounts.program_config.checkpoint_quorum = quorum;
         
+        msg!("Checkpoint quorum set to {}", quorum);
+        emit_event!(ctx, config_change(ctx.accounts, "checkpoint_quorum", prior_hash)?);
+        Ok(())
+    }
+
+    /// Set the default rule for when a form's per-signer approvals are enough
+    pub fn set_threshold_policy(ctx: Context<UpdateProgramConfig>, policy: ThresholdPolicy) -> Result<()> {
+        let prior_hash = ctx.accounts.program_config.config_hash()?;
+        policy.validate(&ctx.accounts.admin_config)?;
         require!(
-            form_hash != [0u8; 32],
-            FormApprovalError::InvalidFormHash
+            policy.fits_signer_limit(ctx.accounts.program_config.max_signers_per_form),
+            FormApprovalError::PolicyExceedsSignerLimit
         );
+        
+        ctx.accounts.program_config.threshold_policy = policy;
+        
+        msg!("Threshold policy set to {:?}", policy);
+        emit_event!(ctx, config_change(ctx.accounts, "threshold_policy", prior_hash)?);
+        Ok(())
+    }
 
-        let form_approval = &mut ctx.accounts.form_approval;
-        let clock = Clock::get()?;
+    /// Override the threshold policy for one form category
+    pub fn set_category_policy(
+        ctx: Context<SetCategoryPolicy>,
+        category: String,
+        policy: ThresholdPolicy,
+    ) -> Result<()> {
+        require!(
+            category.len() <= Config::MAX_CATEGORY_LENGTH,
+            FormApprovalError::CategoryTooLong
+        );
+        policy.validate(&ctx.accounts.admin_config)?;
         
-        // Initialize the form approval account
-        form_approval.form_id = form_id.clone();
-        form_approval.form_hash = form_hash;
-        form_approval.signer = ctx.accounts.admin.key();
-        form_approval.approved_at = clock.unix_timestamp;
-        form_approval.metadata = metadata.unwrap_or_default();
-        form_approval.bump = ctx.bumps.form_approval;
+        let category_policy = &mut ctx.accounts.category_policy;
+        category_policy.category = category.clone();
+        category_policy.policy = policy;
+        category_policy.bump = ctx.bumps.category_policy;
         
-        msg!(
-            "Form {} approved by admin {} at timestamp {}",
-            form_id,
-            ctx.accounts.admin.key(),
-            clock.unix_timestamp
+        emit_event!(ctx, CategoryPolicySet {
+            category: category.clone(),
+            policy,
+            set_by: ctx.accounts.authority.key(),
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Category {} policy set to {:?}", category, policy);
+        Ok(())
+    }
+
+    /// Set the maximum number of signers a single form can collect
+    pub fn set_max_signers_per_form(ctx: Context<UpdateProgramConfig>, max_signers: u8) -> Result<()> {
+        let prior_hash = ctx.accounts.program_config.config_hash()?;
+        require!(
+            max_signers >= 1 && max_signers as usize <= Config::MAX_ADMINS,
+            FormApprovalError::InvalidSignerLimit
+        );
+        require!(
+            ctx.accounts.program_config.threshold_policy.fits_signer_limit(max_signers),
+            FormApprovalError::PolicyExceedsSignerLimit
         );
         
+        ctx.accounts.program_config.max_signers_per_form = max_signers;
+        
+        msg!("Max signers per form set to {}", max_signers);
+        emit_event!(ctx, config_change(ctx.accounts, "max_signers_per_form", prior_hash)?);
         Ok(())
     }
 
-    /// Update metadata for an existing form approval
-    pub fn update_form_approval(
-        ctx: Context<UpdateFormApproval>,
-        _form_id: String,
-        metadata: String,
+    /// Set how far client-supplied signing timestamps may drift from the chain clock
+    pub fn set_max_clock_skew(ctx: Context<UpdateProgramConfig>, seconds: i64) -> Result<()> {
+        let prior_hash = ctx.accounts.program_config.config_hash()?;
+        require!(seconds >= 0, FormApprovalError::InvalidClockSkew);
+        
+        ctx.accounts.program_config.max_clock_skew = seconds;
+        
+        msg!("Max clock skew set to {} seconds", seconds);
+        emit_event!(ctx, config_change(ctx.accounts, "max_clock_skew", prior_hash)?);
+        Ok(())
+    }
+
+    /// Raise the delay queued admin changes wait for; lowering it must itself be queued
+    pub fn set_admin_change_delay(ctx: Context<UpdateProgramConfig>, delay: i64) -> Result<()> {
+        let prior_hash = ctx.accounts.program_config.config_hash()?;
+        require!(
+            (0..=Config::MAX_ADMIN_CHANGE_DELAY).contains(&delay),
+            FormApprovalError::InvalidAdminChangeDelay
+        );
+        require!(
+            delay >= ctx.accounts.program_config.admin_change_delay,
+            FormApprovalError::AdminChangeTimelocked
+        );
+        
+        ctx.accounts.program_config.admin_change_delay = delay;
+        
+        msg!("Admin change delay set to {} seconds", delay);
+        emit_event!(ctx, config_change(ctx.accounts, "admin_change_delay", prior_hash)?);
+        Ok(())
+    }
+
+    /// Set how long admin invitations stay open (0 never expires)
+    pub fn set_invitation_ttl(ctx: Context<UpdateProgramConfig>, seconds: i64) -> Result<()> {
+        let prior_hash = ctx.accounts.program_config.config_hash()?;
+        require!(seconds >= 0, FormApprovalError::InvalidInvitationTtl);
+        
+        ctx.accounts.program_config.invitation_ttl = seconds;
+        
+        msg!("Admin invitation lifetime set to {} seconds", seconds);
+        emit_event!(ctx, config_change(ctx.accounts, "invitation_ttl", prior_hash)?);
+        Ok(())
+    }
+
+    /// Cap recorded verifications per verifier and approval in each hour (0 is unlimited)
+    pub fn set_verifier_rate_limit(ctx: Context<UpdateProgramConfig>, limit: u32) -> Result<()> {
+        let prior_hash = ctx.accounts.program_config.config_hash()?;
+        ctx.accounts.program_config.verifier_rate_limit = limit;
+        
+        msg!("Verifier rate limit set to {} per window", limit);
+        emit_event!(ctx, config_change(ctx.accounts, "verifier_rate_limit", prior_hash)?);
+        Ok(())
+    }
+
+    /// Suspend or resume signing and updating approvals, e.g. during incident response
+    pub fn set_pause(ctx: Context<UpdateProgramConfig>, paused: bool) -> Result<()> {
+        let prior_hash = ctx.accounts.program_config.config_hash()?;
+        ctx.accounts.program_config.paused = paused;
+        
+        msg!("Program {}", if paused { "paused" } else { "resumed" });
+        emit_event!(ctx, config_change(ctx.accounts, "paused", prior_hash)?);
+        Ok(())
+    }
+
+    /// Refuse signing a hash already anchored under another form ID, unless the signer overrides
+    pub fn set_reject_duplicate_hashes(ctx: Context<UpdateProgramConfig>, reject: bool) -> Result<()> {
+        let prior_hash = ctx.accounts.program_config.config_hash()?;
+        ctx.accounts.program_config.reject_duplicate_hashes = reject;
+        
+        msg!("Duplicate form hashes rejected: {}", reject);
+        emit_event!(ctx, config_change(ctx.accounts, "reject_duplicate_hashes", prior_hash)?);
+        Ok(())
+    }
+
+    /// Commit the Merkle root of keys allowed to self-register as admins (zero disables)
+    pub fn set_admin_import_root(ctx: Context<UpdateProgramConfig>, root: [u8; 32]) -> Result<()> {
+        let prior_hash = ctx.accounts.program_config.config_hash()?;
+        require!(
+            root == [0u8; 32] || ctx.accounts.program_config.admin_change_delay == 0,
+            FormApprovalError::AdminChangeTimelocked
+        );
+        ctx.accounts.program_config.admin_import_root = root;
+        
+        msg!("Admin import root set to {:?}", root);
+        emit_event!(ctx, config_change(ctx.accounts, "admin_import_root", prior_hash)?);
+        Ok(())
+    }
+
+    /// Require signers to record consent before their signature is accepted
+    pub fn set_strict_consent(ctx: Context<UpdateProgramConfig>, enabled: bool) -> Result<()> {
+        let prior_hash = ctx.accounts.program_config.config_hash()?;
+        ctx.accounts.program_config.strict_consent = enabled;
+        
+        msg!("Strict consent mode: {}", enabled);
+        emit_event!(ctx, config_change(ctx.accounts, "strict_consent", prior_hash)?);
+        Ok(())
+    }
+
+    /// Set the issuer presentation data referenced by issued certificates
+    pub fn set_branding(
+        ctx: Context<SetBranding>,
+        logo_uri_hash: [u8; 32],
+        issuer_display_name: String,
+        support_contact_hash: [u8; 32],
     ) -> Result<()> {
         require!(
-            metadata.len() <= Config::MAX_METADATA_LENGTH,
-            FormApprovalError::MetadataTooLong
+            issuer_display_name.len() <= Config::MAX_ISSUER_NAME_LENGTH,
+            FormApprovalError::IssuerNameTooLong
         );
         
-        let form_approval = &mut ctx.accounts.form_approval;
-        form_approval.metadata = metadata;
+        let branding_config = &mut ctx.accounts.branding_config;
+        branding_config.logo_uri_hash = logo_uri_hash;
+        branding_config.issuer_display_name = issuer_display_name;
+        branding_config.support_contact_hash = support_contact_hash;
+        branding_config.updated_by = ctx.accounts.authority.key();
+        branding_config.updated_at = current_timestamp(ctx.remaining_accounts)?;
+        branding_config.bump = ctx.bumps.branding_config;
         
-        msg!("Form approval metadata updated by admin: {}", ctx.accounts.admin.key());
+        emit_event!(ctx, BrandingUpdated {
+            logo_uri_hash,
+            issuer_display_name: branding_config.issuer_display_name.clone(),
+            support_contact_hash,
+            updated_by: branding_config.updated_by,
+            updated_at: branding_config.updated_at,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Branding set for issuer {}", branding_config.issuer_display_name);
         Ok(())
     }
 
-    /// Add a new admin to the system
-    pub fn add_admin(ctx: Context<AddAdmin>, new_admin: Pubkey) -> Result<()> {
-        let admin_config = &mut ctx.accounts.admin_config;
-        admin_config.add_admin(new_admin)?;
+    /// Publish the hash of a new webhook endpoint manifest, bumping its version
+    pub fn set_webhook_manifest(ctx: Context<SetWebhookManifest>, manifest_hash: [u8; 32]) -> Result<()> {
+        let webhook_manifest = &mut ctx.accounts.webhook_manifest;
+        let previous_hash = webhook_manifest.manifest_hash;
+        webhook_manifest.manifest_hash = manifest_hash;
+        webhook_manifest.version += 1;
+        webhook_manifest.updated_by = ctx.accounts.authority.key();
+        webhook_manifest.updated_at = current_timestamp(ctx.remaining_accounts)?;
+        webhook_manifest.bump = ctx.bumps.webhook_manifest;
         
-        msg!("New admin added: {}", new_admin);
+        emit_event!(ctx, WebhookManifestUpdated {
+            version: webhook_manifest.version,
+            previous_hash,
+            manifest_hash,
+            updated_by: webhook_manifest.updated_by,
+            updated_at: webhook_manifest.updated_at,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Webhook manifest updated to version {}", webhook_manifest.version);
         Ok(())
     }
 
-    /// Remove an admin from the system
-    pub fn remove_admin(ctx: Context<RemoveAdmin>, admin_to_remove: Pubkey) -> Result<()> {
-        let admin_config = &mut ctx.accounts.admin_config;
-        admin_config.remove_admin(&admin_to_remove)?;
+    /// Register the hash of a new policy catalog, bumping its version
+    pub fn set_policy_catalog(ctx: Context<SetPolicyCatalog>, catalog_hash: [u8; 32]) -> Result<()> {
+        let policy_catalog = &mut ctx.accounts.policy_catalog;
+        let previous_hash = policy_catalog.catalog_hash;
+        policy_catalog.catalog_hash = catalog_hash;
+        policy_catalog.version += 1;
+        policy_catalog.updated_by = ctx.accounts.authority.key();
+        policy_catalog.updated_at = current_timestamp(ctx.remaining_accounts)?;
+        policy_catalog.bump = ctx.bumps.policy_catalog;
         
-        msg!("Admin removed: {}", admin_to_remove);
+        emit_event!(ctx, PolicyCatalogUpdated {
+            version: policy_catalog.version,
+            previous_hash,
+            catalog_hash,
+            updated_by: policy_catalog.updated_by,
+            updated_at: policy_catalog.updated_at,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Policy catalog updated to version {}", policy_catalog.version);
         Ok(())
     }
 
-    /// Verify a form approval (read-only function)
-    pub fn verify_form_approval(
-        ctx: Context<VerifyFormApproval>,
-        _form_id: String,
-        expected_hash: [u8; 32],
-    ) -> Result<bool> {
-        let form_approval = &ctx.accounts.form_approval;
-        let is_valid = form_approval.form_hash == expected_hash;
+    /// Require approvals to be archived before they can be closed
+    pub fn set_require_archive_before_close(
+        ctx: Context<UpdateProgramConfig>,
+        required: bool,
+    ) -> Result<()> {
+        let prior_hash = ctx.accounts.program_config.config_hash()?;
+        ctx.accounts.program_config.require_archive_before_close = required;
         
-        msg!(
-            "Form verification result: {} (expected: {:?}, actual: {:?})",
-            is_valid,
-            expected_hash,
-            form_approval.form_hash
+        msg!("Archive before close required: {}", required);
+        emit_event!(ctx, config_change(ctx.accounts, "require_archive_before_close", prior_hash)?);
+        Ok(())
+    }
+
+    /// Set how long after a revocation a form can be re-approved (0 disables)
+    pub fn set_reapproval_window(ctx: Context<UpdateProgramConfig>, seconds: i64) -> Result<()> {
+        let prior_hash = ctx.accounts.program_config.config_hash()?;
+        require!(seconds >= 0, FormApprovalError::InvalidReapprovalWindow);
+        
+        ctx.accounts.program_config.reapproval_window = seconds;
+        
+        msg!("Re-approval window set to {} seconds", seconds);
+        emit_event!(ctx, config_change(ctx.accounts, "reapproval_window", prior_hash)?);
+        Ok(())
+    }
+
+    /// Require every signature to be co-signed by a registered application
+    pub fn set_require_app_signature(ctx: Context<UpdateProgramConfig>, required: bool) -> Result<()> {
+        let prior_hash = ctx.accounts.program_config.config_hash()?;
+        ctx.accounts.program_config.require_app_signature = required;
+        
+        msg!("Registered app signatures required: {}", required);
+        emit_event!(ctx, config_change(ctx.accounts, "require_app_signature", prior_hash)?);
+        Ok(())
+    }
+
+    /// Publish a new version of a form template
+    pub fn publish_template_version(
+        ctx: Context<PublishTemplateVersion>,
+        template_id: String,
+        version: u32,
+        content_hash: [u8; 32],
+        allowed_locales: Vec<String>,
+        allowed_jurisdictions: Vec<String>,
+    ) -> Result<()> {
+        require!(
+            template_id.len() <= Config::MAX_TEMPLATE_ID_LENGTH,
+            FormApprovalError::TemplateIdTooLong
+        );
+        require!(content_hash != [0u8; 32], FormApprovalError::InvalidFormHash);
+        require!(
+            allowed_locales.len() <= Config::MAX_TEMPLATE_LOCALES,
+            FormApprovalError::TooManyLocales
+        );
+        require!(
+            allowed_locales.iter().all(|locale| state::is_valid_locale(locale)),
+            FormApprovalError::InvalidLocale
+        );
+        require!(
+            allowed_jurisdictions.len() <= Config::MAX_TEMPLATE_JURISDICTIONS,
+            FormApprovalError::TooManyJurisdictions
+        );
+        require!(
+            allowed_jurisdictions
+                .iter()
+                .all(|jurisdiction| state::is_valid_jurisdiction(jurisdiction)),
+            FormApprovalError::InvalidJurisdiction
         );
         
-        Ok(is_valid)
+        let template_version = &mut ctx.accounts.template_version;
+        template_version.template_id = template_id.clone();
+        template_version.version = version;
+        template_version.content_hash = content_hash;
+        template_version.published_by = ctx.accounts.authority.key();
+        template_version.published_at = current_timestamp(ctx.remaining_accounts)?;
+        template_version.deprecated_at = 0;
+        template_version.allowed_locales = allowed_locales;
+        template_version.allowed_jurisdictions = allowed_jurisdictions;
+        template_version.bump = ctx.bumps.template_version;
+        
+        emit_event!(ctx, TemplateVersionPublished {
+            template_id: template_id.clone(),
+            version,
+            content_hash,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Template {} version {} published", template_id, version);
+        Ok(())
     }
 
-    /// Get form approval details (read-only function)
-    pub fn get_form_approval_details(
-        ctx: Context<VerifyFormApproval>,
-        _form_id: String,
-    ) -> Result<(String, [u8; 32], Pubkey, i64, String)> {
-        let form_approval = &ctx.accounts.form_approval;
+    /// Refuse new signings against a template version; existing approvals keep their pinned reference
+    pub fn deprecate_template_version(
+        ctx: Context<DeprecateTemplateVersion>,
+        template_id: String,
+        version: u32,
+    ) -> Result<()> {
+        let template_version = &mut ctx.accounts.template_version;
+        require!(
+            !template_version.is_deprecated(),
+            FormApprovalError::TemplateVersionDeprecated
+        );
+        template_version.deprecated_at = current_timestamp(ctx.remaining_accounts)?;
         
-        Ok((
-            form_approval.form_id.clone(),
-            form_approval.form_hash,
-            form_approval.signer,
-            form_approval.approved_at,
-            form_approval.metadata.clone(),
-        ))
+        emit_event!(ctx, TemplateVersionDeprecated {
+            template_id: template_id.clone(),
+            version,
+            deprecated_at: template_version.deprecated_at,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Template {} version {} deprecated", template_id, version);
+        Ok(())
     }
-}
 
-/// Events emitted by the contract
-#[event]
-pub struct FormApproved {
-    pub form_id: String,
-    pub form_hash: [u8; 32],
-    pub signer: Pubkey,
-    pub approved_at: i64,
-}
+    /// Register an integrating application by the key it co-signs with
+    pub fn register_app(ctx: Context<RegisterApp>, app_key: Pubkey, name: String) -> Result<()> {
+        require!(
+            name.len() <= Config::MAX_APP_NAME_LENGTH,
+            FormApprovalError::AppNameTooLong
+        );
+        
+        let registered_app = &mut ctx.accounts.registered_app;
+        registered_app.app_key = app_key;
+        registered_app.name = name;
+        registered_app.registered_by = ctx.accounts.authority.key();
+        registered_app.registered_at = current_timestamp(ctx.remaining_accounts)?;
+        registered_app.active = true;
+        registered_app.bump = ctx.bumps.registered_app;
+        
+        emit_event!(ctx, AppRegistered {
+            app_key,
+            name: registered_app.name.clone(),
+            registered_by: registered_app.registered_by,
+            registered_at: registered_app.registered_at,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Registered app {} ({})", registered_app.name, app_key);
+        Ok(())
+    }
 
-#[event]
-pub struct AdminAdded {
-    pub admin: Pubkey,
-    pub authority: Pubkey,
-}
+    /// Enable or disable a registered application
+    pub fn set_app_active(
+        ctx: Context<UpdateRegisteredApp>,
+        app_key: Pubkey,
+        active: bool,
+    ) -> Result<()> {
+        ctx.accounts.registered_app.active = active;
+        
+        emit_event!(ctx, AppActiveChanged {
+            app_key,
+            active,
+            authority: ctx.accounts.authority.key(),
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("App {} active: {}", app_key, active);
+        Ok(())
+    }
 
-#[event]
-pub struct AdminRemoved {
-    pub admin: Pubkey,
-    pub authority: Pubkey,
+    /// Allow an external program to create approvals via CPI
+    pub fn allow_cpi_program(ctx: Context<UpdateProgramConfig>, program: Pubkey) -> Result<()> {
+        let prior_hash = ctx.accounts.program_config.config_hash()?;
+        let program_config = &mut ctx.accounts.program_config;
+        require!(
+            !program_config.is_cpi_allowed(&program),
+            FormApprovalError::CpiProgramAlreadyAllowed
+        );
+        require!(
+            program_config.cpi_allow_list.len() < Config::MAX_CPI_PROGRAMS,
+            FormApprovalError::MaxCpiProgramsReached
+        );
+        
+        program_config.cpi_allow_list.push(program);
+        
+        msg!("Program {} allowed to sign via CPI", program);
+        emit_event!(ctx, config_change(ctx.accounts, "cpi_allow_list", prior_hash)?);
+        Ok(())
+    }
+
+    /// Remove an external program from the CPI allow-list
+    pub fn disallow_cpi_program(ctx: Context<UpdateProgramConfig>, program: Pubkey) -> Result<()> {
+        let prior_hash = ctx.accounts.program_config.config_hash()?;
+        let program_config = &mut ctx.accounts.program_config;
+        require!(
+            program_config.is_cpi_allowed(&program),
+            FormApprovalError::CpiProgramNotAllowed
+        );
+        
+        program_config.cpi_allow_list.retain(|allowed| *allowed != program);
+        
+        msg!("Program {} removed from the CPI allow-list", program);
+        emit_event!(ctx, config_change(ctx.accounts, "cpi_allow_list", prior_hash)?);
+        Ok(())
+    }
+
+    /// Initialize the feature gates with every feature disabled
+    pub fn initialize_feature_gates(ctx: Context<InitializeFeatureGates>) -> Result<()> {
+        let feature_gates = &mut ctx.accounts.feature_gates;
+        
+        feature_gates.multi_sign_enabled = false;
+        feature_gates.bump = ctx.bumps.feature_gates;
+        
+        msg!("Feature gates initialized");
+        Ok(())
+    }
+
+    /// Enable or disable a feature gate
+    pub fn set_feature_gate(
+        ctx: Context<SetFeatureGate>,
+        feature: FeatureGate,
+        enabled: bool,
+    ) -> Result<()> {
+        ctx.accounts.feature_gates.set(feature, enabled);
+        
+        emit_event!(ctx, FeatureGateChanged {
+            feature,
+            enabled,
+            changed_by: ctx.accounts.authority.key(),
+            changed_at: current_timestamp(ctx.remaining_accounts)?,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Feature {:?} set to {}", feature, enabled);
+        Ok(())
+    }
+
+    /// Sign a form submission with blockchain approval
+    pub fn sign_form_submission(
+        ctx: Context<SignFormSubmission>,
+        form_id: String,
+        form_hash: [u8; 32],
+        metadata: Option<String>,
+        options: Option<SignOptions>,
+    ) -> Result<()> {
+        let accounts = &mut *ctx.accounts;
+        let events = record_submission(
+            SubmissionAccounts {
+                form_approval: &mut accounts.form_approval,
+                form_approval_bump: ctx.bumps.form_approval,
+                approval_chain: &mut accounts.approval_chain,
+                approval_chain_bump: ctx.bumps.approval_chain,
+                hash_index: &mut accounts.hash_index,
+                hash_index_bump: ctx.bumps.hash_index,
+                admin_config: Some(&mut accounts.admin_config),
+                admin_record: accounts.admin_record.as_deref_mut(),
+                program_config: &accounts.program_config,
+                registered_app: accounts.registered_app.as_deref_mut(),
+                app_signer: accounts.app_signer.as_ref().map(|signer| signer.key()),
+                template_version: accounts.template_version.as_ref(),
+                consent_record: accounts.consent_record.as_deref(),
+                feature_gates: accounts.feature_gates.as_deref(),
+                admin: accounts.admin.key(),
+                organization: Pubkey::default(),
+                event_sequence: &mut accounts.event_sequence,
+            },
+            ctx.remaining_accounts,
+            &form_id,
+            form_hash,
+            metadata,
+            options.unwrap_or_default(),
+        )?;
+        let approved_at = events.approved.approved_at;
+        
+        if let Some(event) = events.duplicate {
+            emit_event!(ctx, event);
+        }
+        emit_event!(ctx, events.approved);
+        for event in events.notifications {
+            emit_event!(ctx, event);
+        }
+        
+        msg!(
+            "Form {} approved by admin {} at timestamp {}",
+            form_id,
+            ctx.accounts.admin.key(),
+            approved_at
+        );
+        
+        Ok(())
+    }
+
+    /// Create an organization with its own form ID namespace, managed by the signer
+    pub fn create_organization(ctx: Context<CreateOrganization>, org_id: String) -> Result<()> {
+        require!(Organization::is_valid_org_id(&org_id), FormApprovalError::InvalidOrgId);
+        
+        let organization = &mut ctx.accounts.organization;
+        organization.org_id = org_id;
+        organization.authority = ctx.accounts.authority.key();
+        organization.created_at = current_timestamp(ctx.remaining_accounts)?;
+        organization.bump = ctx.bumps.organization;
+        organization.admins = Vec::new();
+        organization.total_forms_signed = 0;
+        organization.total_revoked = 0;
+        organization.last_signed_at = 0;
+        organization.suspended_at = 0;
+        organization.suspension_reason_hash = [0u8; 32];
+        
+        emit_event!(ctx, OrganizationCreated {
+            organization: organization.key(),
+            org_id: organization.org_id.clone(),
+            authority: organization.authority,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Organization {} created by {}", organization.org_id, organization.authority);
+        Ok(())
+    }
+
+    /// Let `new_admin` sign the organization's forms
+    pub fn add_org_admin(ctx: Context<UpdateOrganization>, new_admin: Pubkey) -> Result<()> {
+        let organization = &mut ctx.accounts.organization;
+        organization.add_admin(new_admin)?;
+        
+        emit_event!(ctx, OrgAdminAdded {
+            organization: organization.key(),
+            admin: new_admin,
+            authority: ctx.accounts.authority.key(),
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Admin {} added to organization {}", new_admin, organization.org_id);
+        Ok(())
+    }
+
+    /// Stop `admin` from signing the organization's forms
+    pub fn remove_org_admin(ctx: Context<UpdateOrganization>, admin: Pubkey) -> Result<()> {
+        let organization = &mut ctx.accounts.organization;
+        organization.remove_admin(&admin)?;
+        
+        emit_event!(ctx, OrgAdminRemoved {
+            organization: organization.key(),
+            admin,
+            authority: ctx.accounts.authority.key(),
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Admin {} removed from organization {}", admin, organization.org_id);
+        Ok(())
+    }
+
+    /// Suspend an organization, so its signing and update instructions fail until it is reinstated
+    pub fn suspend_organization(ctx: Context<SetOrganizationSuspension>, reason_hash: [u8; 32]) -> Result<()> {
+        let organization = &mut ctx.accounts.organization;
+        require!(!organization.is_suspended(), FormApprovalError::OrgSuspended);
+        organization.suspended_at = current_timestamp(ctx.remaining_accounts)?;
+        organization.suspension_reason_hash = reason_hash;
+        
+        emit_event!(ctx, OrganizationSuspended {
+            organization: organization.key(),
+            reason_hash,
+            suspended_by: ctx.accounts.authority.key(),
+            suspended_at: organization.suspended_at,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Organization {} suspended", organization.org_id);
+        Ok(())
+    }
+
+    /// Lift an organization's suspension, keeping the reason hash on record
+    pub fn reinstate_organization(ctx: Context<SetOrganizationSuspension>) -> Result<()> {
+        let organization = &mut ctx.accounts.organization;
+        require!(organization.is_suspended(), FormApprovalError::OrgNotSuspended);
+        organization.suspended_at = 0;
+        
+        emit_event!(ctx, OrganizationReinstated {
+            organization: organization.key(),
+            reinstated_by: ctx.accounts.authority.key(),
+            reinstated_at: current_timestamp(ctx.remaining_accounts)?,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Organization {} reinstated", organization.org_id);
+        Ok(())
+    }
+
+    /// Sign a form submission under an organization, so its form ID only has to be unique within the organization
+    pub fn sign_org_form_submission(
+        ctx: Context<SignOrgFormSubmission>,
+        form_id: String,
+        form_hash: [u8; 32],
+        metadata: Option<String>,
+        options: Option<SignOptions>,
+    ) -> Result<()> {
+        let accounts = &mut *ctx.accounts;
+        let events = record_submission(
+            SubmissionAccounts {
+                form_approval: &mut accounts.form_approval,
+                form_approval_bump: ctx.bumps.form_approval,
+                approval_chain: &mut accounts.approval_chain,
+                approval_chain_bump: ctx.bumps.approval_chain,
+                hash_index: &mut accounts.hash_index,
+                hash_index_bump: ctx.bumps.hash_index,
+                admin_config: Some(&mut accounts.admin_config),
+                admin_record: accounts.admin_record.as_deref_mut(),
+                program_config: &accounts.program_config,
+                registered_app: accounts.registered_app.as_deref_mut(),
+                app_signer: accounts.app_signer.as_ref().map(|signer| signer.key()),
+                template_version: accounts.template_version.as_ref(),
+                consent_record: accounts.consent_record.as_deref(),
+                feature_gates: None,
+                admin: accounts.admin.key(),
+                organization: accounts.organization.key(),
+                event_sequence: &mut accounts.event_sequence,
+            },
+            ctx.remaining_accounts,
+            &form_id,
+            form_hash,
+            metadata,
+            options.unwrap_or_default(),
+        )?;
+        let approved_at = events.approved.approved_at;
+        ctx.accounts.organization.record_signing(approved_at);
+        
+        // A cross-organization approval stays pending until the counter-party signs
+        if let Some(counterparty_organization) = &ctx.accounts.counterparty_organization {
+            ctx.accounts.form_approval.counterparty_org = counterparty_organization.key();
+            ctx.accounts.form_approval.status = ApprovalStatus::Pending;
+        }
+        
+        if let Some(event) = events.duplicate {
+            emit_event!(ctx, event);
+        }
+        emit_event!(ctx, events.approved);
+        for event in events.notifications {
+            emit_event!(ctx, event);
+        }
+        
+        msg!(
+            "Form {} approved in organization {} by {} at timestamp {}",
+            form_id,
+            ctx.accounts.organization.org_id,
+            ctx.accounts.admin.key(),
+            approved_at
+        );
+        
+        Ok(())
+    }
+
+    /// Sign a cross-organization approval for its counter-party organization, putting it in force
+    pub fn countersign_org_form_approval(ctx: Context<CountersignOrgFormApproval>, form_id: String) -> Result<()> {
+        let form_approval = &mut ctx.accounts.form_approval;
+        require!(!form_approval.is_revoked(), FormApprovalError::FormAlreadyRevoked);
+        require!(
+            form_approval.awaits_counterparty_org(),
+            FormApprovalError::CounterpartyOrgAlreadySigned
+        );
+        form_approval.transition(ApprovalStatus::Approved)?;
+        form_approval.counterparty_org_signer = ctx.accounts.admin.key();
+        
+        emit_event!(ctx, OrgCounterpartySigned {
+            form_id: form_id.clone(),
+            organization: form_approval.organization,
+            counterparty_org: form_approval.counterparty_org,
+            signer: form_approval.counterparty_org_signer,
+            signed_at: current_timestamp(ctx.remaining_accounts)?,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!(
+            "Form {} of organization {} countersigned by {} for organization {}",
+            form_id,
+            ctx.accounts.organization.org_id,
+            ctx.accounts.admin.key(),
+            ctx.accounts.counterparty_organization.org_id
+        );
+        Ok(())
+    }
+
+    /// Revoke a form approval in an organization's namespace, counting the revocation on the organization
+    pub fn revoke_org_form_approval(
+        ctx: Context<RevokeOrgFormApproval>,
+        form_id: String,
+        reason: String,
+    ) -> Result<()> {
+        require!(
+            reason.len() <= Config::MAX_REVOCATION_REASON_LENGTH,
+            FormApprovalError::RevocationReasonTooLong
+        );
+        
+        let form_approval = &mut ctx.accounts.form_approval;
+        require!(!form_approval.is_revoked(), FormApprovalError::FormAlreadyRevoked);
+        form_approval.transition(ApprovalStatus::Revoked)?;
+        
+        form_approval.revoked_at = current_timestamp(ctx.remaining_accounts)?;
+        form_approval.revocation_reason = reason.clone();
+        form_approval.revoked_by = ctx.accounts.admin.key();
+        form_approval.revocation_catalog_hash = PolicyCatalog::stamp(ctx.accounts.policy_catalog.as_deref());
+        ctx.accounts.organization.total_revoked += 1;
+        emit_event!(ctx, append_history(
+            &mut ctx.accounts.form_history,
+            form_approval.key(),
+            HistoryEntry {
+                action: FormAction::Revoked,
+                actor: form_approval.revoked_by,
+                at: form_approval.revoked_at,
+                summary_hash: anchor_lang::solana_program::hash::hash(reason.as_bytes()).to_bytes(),
+            },
+            ctx.bumps.form_history,
+            &mut ctx.accounts.event_sequence,
+        ));
+        
+        emit_event!(ctx, FormRevoked {
+            form_id: form_id.clone(),
+            signer: form_approval.signer,
+            revoked_at: form_approval.revoked_at,
+            reason,
+            revoked_by: form_approval.revoked_by,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        for event in observer_notifications(
+            ctx.remaining_accounts,
+            &form_id,
+            ctx.accounts.form_approval.organization,
+            FormAction::Revoked,
+            &mut ctx.accounts.event_sequence,
+        )? {
+            emit_event!(ctx, event);
+        }
+        
+        msg!(
+            "Form {} revoked in organization {} by {}",
+            form_id,
+            ctx.accounts.organization.org_id,
+            ctx.accounts.admin.key()
+        );
+        Ok(())
+    }
+
+    /// Record an admin's own approval of a form, alongside other signers' approvals
+    pub fn sign_form_as_signer(
+        ctx: Context<SignFormAsSigner>,
+        form_id: String,
+        form_hash: [u8; 32],
+        metadata: Option<String>,
+        options: Option<SignOptions>,
+    ) -> Result<()> {
+        let options = options.unwrap_or_default();
+        ctx.accounts.program_config.check_client_version(options.client_version)?;
+        ConsentRecord::check_required(
+            ctx.accounts.consent_record.as_deref(),
+            ctx.accounts.program_config.strict_consent,
+        )?;
+        FormApproval::validate_submission(&form_id, &form_hash, metadata.as_deref())?;
+        let reason_code = options.approval_reason()?;
+        let timestamp_token_hash = options.timestamp_token_hash()?;
+        let app_id = RegisteredApp::resolve(
+            ctx.accounts.registered_app.as_deref_mut(),
+            ctx.accounts.app_signer.as_ref().map(|signer| signer.key()),
+            ctx.accounts.program_config.require_app_signature,
+        )?;
+        let locale = options.locale()?;
+        let jurisdiction = options.jurisdiction()?;
+        let compliance = options.compliance()?;
+        // Per-signer approvals collect their quorum through the tally instead
+        require!(
+            options.required_signatures.unwrap_or(1) == 1,
+            FormApprovalError::InvalidRequiredSignatures
+        );
+        let template_version = TemplateVersion::pin(
+            ctx.accounts.template_version.as_ref(),
+            &locale,
+            &jurisdiction,
+        )?;
+        
+        let form_approval = &mut ctx.accounts.form_approval;
+        let clock = Clock {
+            unix_timestamp: current_timestamp(ctx.remaining_accounts)?,
+            ..Clock::get()?
+        };
+        
+        form_approval.record(
+            form_id.clone(),
+            form_hash,
+            ctx.accounts.admin.key(),
+            metadata.unwrap_or_default(),
+            ctx.bumps.form_approval,
+            &clock,
+        );
+        form_approval.reason_code = reason_code;
+        form_approval.timestamp_token_hash = timestamp_token_hash;
+        form_approval.app_id = app_id;
+        form_approval.template_version = template_version;
+        form_approval.locale = locale;
+        form_approval.jurisdiction = jurisdiction;
+        (form_approval.document_uri, form_approval.content_type) = options.document_location()?;
+        (form_approval.hash_algorithm, form_approval.form_hash_tail) = options.digest()?;
+        form_approval.page_count = options.page_count(form_approval.hash_algorithm)?;
+        form_approval.compliance = compliance;
+        form_approval.client_signed_at =
+            options.client_signed_at(clock.unix_timestamp, ctx.accounts.program_config.max_clock_skew)?;
+        form_approval.expires_at = options.expires_at(clock.unix_timestamp)?;
+        form_approval.counterparties = options.counterparties()?;
+        form_approval.required_signers = options.required_signers()?;
+        form_approval.mark_signed(&ctx.accounts.admin.key());
+        
+        // The first signature fixes the hash and policy for the form
+        let max_signers = ctx.accounts.program_config.max_signers_per_form;
+        let tally = &mut ctx.accounts.approval_tally;
+        if tally.signature_count == 0 {
+            tally.form_hash = form_hash;
+            tally.policy = match &ctx.accounts.category_policy {
+                Some(category_policy) => category_policy.policy,
+                None => ctx.accounts.program_config.threshold_policy,
+            };
+            require!(
+                tally.policy.fits_signer_limit(max_signers),
+                FormApprovalError::PolicyExceedsSignerLimit
+            );
+            tally.status = TallyStatus::Pending;
+            tally.bump = ctx.bumps.approval_tally;
+        }
+        require!(
+            tally.signature_count < max_signers as u32,
+            FormApprovalError::SignerLimitReached
+        );
+        let signer = ctx.accounts.admin.key();
+        let weight = ctx.accounts.admin_config.weight_of_with_record(&signer, ctx.accounts.admin_record.as_deref());
+        tally.record_signature(signer, form_hash, weight)?;
+        if tally.policy.is_met(tally) {
+            msg!("Form {} meets its threshold policy and can be finalized", form_id);
+        }
+        
+        let approval_address = form_approval.key();
+        let approval_chain = &mut ctx.accounts.approval_chain;
+        approval_chain.bump = ctx.bumps.approval_chain;
+        approval_chain.link(form_approval, &approval_address);
+        
+        emit_event!(ctx, FormApproved {
+            form_id: form_id.clone(),
+            form_hash,
+            signer: ctx.accounts.admin.key(),
+            approved_at: clock.unix_timestamp,
+            reason_code,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        for event in observer_notifications(
+            ctx.remaining_accounts,
+            &form_id,
+            ctx.accounts.form_approval.organization,
+            FormAction::Approved,
+            &mut ctx.accounts.event_sequence,
+        )? {
+            emit_event!(ctx, event);
+        }
+        
+        msg!("Form {} signed by {}", form_id, ctx.accounts.admin.key());
+        Ok(())
+    }
+
+    /// Withdraw a co-signer's signature before the form is finalized
+    pub fn withdraw_signature(ctx: Context<WithdrawSignature>, form_id: String) -> Result<()> {
+        let signer = ctx.accounts.signer.key();
+        let tally = &mut ctx.accounts.approval_tally;
+        tally.withdraw_signature(&signer)?;
+        
+        emit_event!(ctx, SignatureWithdrawn {
+            form_id: form_id.clone(),
+            signer,
+            signature_count: tally.signature_count,
+            weighted_sum: tally.weighted_sum,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        for event in observer_notifications(
+            ctx.remaining_accounts,
+            &form_id,
+            ctx.accounts.form_approval.organization,
+            FormAction::SignatureWithdrawn,
+            &mut ctx.accounts.event_sequence,
+        )? {
+            emit_event!(ctx, event);
+        }
+        
+        msg!("Signer {} withdrew from form {}", signer, form_id);
+        Ok(())
+    }
+
+    /// Finalize a form whose tally meets its policy; permissionless so a crank can do it
+    pub fn try_finalize(ctx: Context<TryFinalize>, form_id: String) -> Result<()> {
+        let tally = &mut ctx.accounts.approval_tally;
+        require!(
+            tally.status == TallyStatus::Pending,
+            FormApprovalError::TallyAlreadyFinalized
+        );
+        require!(
+            tally.try_finalize(current_timestamp(ctx.remaining_accounts)?),
+            FormApprovalError::ThresholdNotMet
+        );
+        
+        emit_event!(ctx, approval_finalized(&form_id, tally, &mut ctx.accounts.event_sequence));
+        
+        msg!("Form {} finalized", form_id);
+        Ok(())
+    }
+
+    /// Create an approval on behalf of an allow-listed program calling via CPI
+    ///
+    /// Goes through the same checks as `sign_form_submission`, with the calling
+    /// program's PDA as the signer of record.
+    pub fn sign_form_submission_cpi(
+        ctx: Context<SignFormSubmissionCpi>,
+        form_id: String,
+        form_hash: [u8; 32],
+        metadata: Option<String>,
+        options: Option<SignOptions>,
+    ) -> Result<()> {
+        let accounts = &mut *ctx.accounts;
+        let events = record_submission(
+            SubmissionAccounts {
+                form_approval: &mut accounts.form_approval,
+                form_approval_bump: ctx.bumps.form_approval,
+                approval_chain: &mut accounts.approval_chain,
+                approval_chain_bump: ctx.bumps.approval_chain,
+                hash_index: &mut accounts.hash_index,
+                hash_index_bump: ctx.bumps.hash_index,
+                admin_config: None,
+                admin_record: None,
+                program_config: &accounts.program_config,
+                registered_app: accounts.registered_app.as_deref_mut(),
+                app_signer: accounts.app_signer.as_ref().map(|signer| signer.key()),
+                template_version: accounts.template_version.as_ref(),
+                consent_record: accounts.consent_record.as_deref(),
+                feature_gates: accounts.feature_gates.as_deref(),
+                admin: accounts.caller_signer.key(),
+                organization: Pubkey::default(),
+                event_sequence: &mut accounts.event_sequence,
+            },
+            ctx.remaining_accounts,
+            &form_id,
+            form_hash,
+            metadata,
+            options.unwrap_or_default(),
+        )?;
+        ctx.accounts.form_approval.cpi_program = ctx.accounts.caller_program.key();
+        
+        if let Some(event) = events.duplicate {
+            emit_event!(ctx, event);
+        }
+        emit_event!(ctx, events.approved);
+        for event in events.notifications {
+            emit_event!(ctx, event);
+        }
+        
+        msg!(
+            "Form {} approved via CPI by program {} (signer {})",
+            form_id,
+            ctx.accounts.form_approval.cpi_program,
+            ctx.accounts.form_approval.signer
+        );
+        Ok(())
+    }
+
+    /// Import an approval from a previous system, keeping its original signer and timestamps
+    pub fn import_legacy_approval(
+        ctx: Context<ImportLegacyApproval>,
+        form_id: String,
+        form_hash: [u8; 32],
+        signer: Pubkey,
+        approved_at: i64,
+        expires_at: i64,
+        metadata: Option<String>,
+    ) -> Result<()> {
+        FormApproval::validate_submission(&form_id, &form_hash, metadata.as_deref())?;
+        let clock = Clock {
+            unix_timestamp: current_timestamp(ctx.remaining_accounts)?,
+            ..Clock::get()?
+        };
+        require!(
+            approved_at > 0 && approved_at <= clock.unix_timestamp,
+            FormApprovalError::InvalidImportTimestamp
+        );
+        require!(
+            expires_at == 0 || expires_at > approved_at,
+            FormApprovalError::InvalidImportTimestamp
+        );
+        
+        let form_approval = &mut ctx.accounts.form_approval;
+        form_approval.record(
+            form_id.clone(),
+            form_hash,
+            signer,
+            metadata.unwrap_or_default(),
+            ctx.bumps.form_approval,
+            &clock,
+        );
+        // Historical values replace the current clock; the original slot is unknown
+        form_approval.approved_at = approved_at;
+        form_approval.approved_slot = 0;
+        form_approval.expires_at = expires_at;
+        form_approval.imported = true;
+        
+        emit_event!(ctx, LegacyApprovalImported {
+            form_id: form_id.clone(),
+            form_hash,
+            signer,
+            approved_at,
+            imported_by: ctx.accounts.authority.key(),
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Form {} imported with original approval time {}", form_id, approved_at);
+        Ok(())
+    }
+
+    /// Add an admin's co-signature to a form approval awaiting its quorum
+    pub fn co_sign_form(ctx: Context<CoSignForm>, form_id: String) -> Result<()> {
+        let co_signer = ctx.accounts.admin.key();
+        let form_approval = &mut ctx.accounts.form_approval;
+        let now = current_timestamp(ctx.remaining_accounts)?;
+        form_approval.check_signing_window(now)?;
+        check_dependencies(ctx.remaining_accounts, &form_approval.dependencies, now)?;
+        form_approval.co_sign(co_signer)?;
+        emit_event!(ctx, append_history(
+            &mut ctx.accounts.form_history,
+            form_approval.key(),
+            HistoryEntry {
+                action: FormAction::CoSigned,
+                actor: co_signer,
+                at: now,
+                summary_hash: form_approval.form_hash,
+            },
+            ctx.bumps.form_history,
+            &mut ctx.accounts.event_sequence,
+        ));
+        
+        emit_event!(ctx, FormCoSigned {
+            form_id: form_id.clone(),
+            co_signer,
+            signature_count: form_approval.signature_count() as u8,
+            required_signatures: form_approval.required_signatures,
+            approved: form_approval.status == ApprovalStatus::Approved,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        for event in observer_notifications(
+            ctx.remaining_accounts,
+            &form_id,
+            form_approval.organization,
+            FormAction::CoSigned,
+            &mut ctx.accounts.event_sequence,
+        )? {
+            emit_event!(ctx, event);
+        }
+        msg!(
+            "Form {} co-signed by {} ({} of {})",
+            form_id,
+            co_signer,
+            form_approval.signature_count(),
+            form_approval.required_signatures
+        );
+        Ok(())
+    }
+
+    /// Attach a named counterparty's own signature to a form approval
+    pub fn counter_sign_form(
+        ctx: Context<CounterSignForm>,
+        form_id: String,
+        form_hash: [u8; 32],
+    ) -> Result<()> {
+        let form_approval = &mut ctx.accounts.form_approval;
+        let signer = ctx.accounts.signer.key();
+        require!(!form_approval.is_revoked(), FormApprovalError::FormAlreadyRevoked);
+        let now = current_timestamp(ctx.remaining_accounts)?;
+        form_approval.check_signing_window(now)?;
+        check_dependencies(ctx.remaining_accounts, &form_approval.dependencies, now)?;
+        require!(
+            form_approval.counterparties.contains(&signer) || form_approval.required_signers.contains(&signer),
+            FormApprovalError::NotACounterparty
+        );
+        require!(
+            form_approval.form_hash == form_hash,
+            FormApprovalError::CounterSignatureHashMismatch
+        );
+        
+        let counter_signature = &mut ctx.accounts.counter_signature;
+        counter_signature.form_id = form_id.clone();
+        counter_signature.signer = signer;
+        counter_signature.form_hash = form_hash;
+        counter_signature.signed_at = current_timestamp(ctx.remaining_accounts)?;
+        counter_signature.bump = ctx.bumps.counter_signature;
+        form_approval.mark_signed(&signer);
+        
+        emit_event!(ctx, FormCounterSigned {
+            form_id: form_id.clone(),
+            signer,
+            form_hash,
+            signed_at: counter_signature.signed_at,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        for event in observer_notifications(
+            ctx.remaining_accounts,
+            &form_id,
+            ctx.accounts.form_approval.organization,
+            FormAction::CounterSigned,
+            &mut ctx.accounts.event_sequence,
+        )? {
+            emit_event!(ctx, event);
+        }
+        
+        msg!("Form {} counter-signed by {}", form_id, signer);
+        Ok(())
+    }
+
+    /// Share the key to an approval's encrypted metadata with a grantee for a limited period
+    pub fn grant_access(
+        ctx: Context<GrantAccess>,
+        form_id: String,
+        grantee: Pubkey,
+        wrapped_key: Vec<u8>,
+        expires_at: i64,
+    ) -> Result<()> {
+        require!(
+            !wrapped_key.is_empty() && wrapped_key.len() <= Config::MAX_WRAPPED_KEY_LENGTH,
+            FormApprovalError::InvalidWrappedKey
+        );
+        let now = current_timestamp(ctx.remaining_accounts)?;
+        require!(expires_at > now, FormApprovalError::InvalidExpiry);
+        
+        let access_grant = &mut ctx.accounts.access_grant;
+        access_grant.approval = ctx.accounts.form_approval.key();
+        access_grant.form_id = form_id.clone();
+        access_grant.grantee = grantee;
+        access_grant.granted_by = ctx.accounts.admin.key();
+        access_grant.wrapped_key = wrapped_key;
+        access_grant.granted_at = now;
+        access_grant.expires_at = expires_at;
+        access_grant.bump = ctx.bumps.access_grant;
+        
+        emit_event!(ctx, AccessGranted {
+            form_id: form_id.clone(),
+            grantee,
+            granted_by: access_grant.granted_by,
+            expires_at,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Access to form {} granted to {} until {}", form_id, grantee, expires_at);
+        Ok(())
+    }
+
+    /// Withdraw an access grant, returning its rent to the key that issued it
+    pub fn revoke_access(ctx: Context<RevokeAccess>, form_id: String, grantee: Pubkey) -> Result<()> {
+        emit_event!(ctx, AccessRevoked {
+            form_id: form_id.clone(),
+            grantee,
+            revoked_by: ctx.accounts.admin.key(),
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Access to form {} revoked for {}", form_id, grantee);
+        Ok(())
+    }
+
+    /// Attach a review note to an approval without modifying the approval
+    pub fn add_annotation(
+        ctx: Context<AddAnnotation>,
+        form_id: String,
+        note_hash: [u8; 32],
+        page_ref: u32,
+    ) -> Result<()> {
+        require!(note_hash != [0u8; 32], FormApprovalError::InvalidNoteHash);
+        
+        let approval = ctx.accounts.form_approval.key();
+        let annotation_log = &mut ctx.accounts.annotation_log;
+        require!(
+            annotation_log.count < Config::MAX_ANNOTATIONS_PER_APPROVAL,
+            FormApprovalError::AnnotationLimitReached
+        );
+        annotation_log.approval = approval;
+        annotation_log.bump = ctx.bumps.annotation_log;
+        
+        let annotation = &mut ctx.accounts.annotation;
+        annotation.approval = approval;
+        annotation.form_id = form_id.clone();
+        annotation.index = annotation_log.count;
+        annotation.author = ctx.accounts.admin.key();
+        annotation.note_hash = note_hash;
+        annotation.page_ref = page_ref;
+        annotation.created_at = current_timestamp(ctx.remaining_accounts)?;
+        annotation.bump = ctx.bumps.annotation;
+        annotation_log.count += 1;
+        
+        emit_event!(ctx, AnnotationAdded {
+            form_id,
+            approval,
+            index: annotation.index,
+            author: annotation.author,
+            note_hash,
+            page_ref,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        Ok(())
+    }
+
+    /// Set how often the calling admin is reminded of pending signatures
+    pub fn set_notification_prefs(
+        ctx: Context<SetNotificationPrefs>,
+        reminder_interval: i64,
+        escalation_opt_in: bool,
+        webhook_hash: [u8; 32],
+    ) -> Result<()> {
+        require!(
+            reminder_interval == 0 || reminder_interval >= Config::MIN_REMINDER_INTERVAL,
+            FormApprovalError::InvalidReminderInterval
+        );
+        
+        let notification_prefs = &mut ctx.accounts.notification_prefs;
+        notification_prefs.admin = ctx.accounts.admin.key();
+        notification_prefs.reminder_interval = reminder_interval;
+        notification_prefs.escalation_opt_in = escalation_opt_in;
+        notification_prefs.webhook_hash = webhook_hash;
+        notification_prefs.bump = ctx.bumps.notification_prefs;
+        
+        emit_event!(ctx, NotificationPrefsUpdated {
+            admin: notification_prefs.admin,
+            reminder_interval,
+            escalation_opt_in,
+            webhook_hash,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Notification preferences updated for admin {}", notification_prefs.admin);
+        Ok(())
+    }
+
+    /// Remind an admin who has not yet co-signed a pending approval, as their preferences allow
+    pub fn send_signature_reminder(ctx: Context<SendSignatureReminder>, form_id: String) -> Result<()> {
+        let now = current_timestamp(ctx.remaining_accounts)?;
+        let form_approval = &ctx.accounts.form_approval;
+        let notification_prefs = &mut ctx.accounts.notification_prefs;
+        let admin = notification_prefs.admin;
+        require!(
+            form_approval.status == ApprovalStatus::Pending,
+            FormApprovalError::FormNotPending
+        );
+        require!(
+            admin != form_approval.signer && !form_approval.co_signers.contains(&admin),
+            FormApprovalError::AlreadyCoSigned
+        );
+        require!(notification_prefs.reminder_due(now), FormApprovalError::ReminderNotDue);
+        
+        notification_prefs.last_reminded_at = now;
+        let escalated = notification_prefs.escalation_opt_in
+            && now.saturating_sub(form_approval.approved_at) >= Config::ESCALATION_DELAY;
+        
+        emit_event!(ctx, SignatureReminder {
+            form_id: form_id.clone(),
+            admin,
+            webhook_hash: notification_prefs.webhook_hash,
+            escalated,
+            reminded_at: now,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Reminded admin {} to co-sign form {}", admin, form_id);
+        Ok(())
+    }
+
+    /// Update metadata for an existing form approval
+    pub fn update_form_approval(
+        ctx: Context<UpdateFormApproval>,
+        form_id: String,
+        metadata: String,
+    ) -> Result<()> {
+        require!(
+            metadata.len() <= Config::MAX_METADATA_LENGTH,
+            FormApprovalError::MetadataTooLong
+        );
+        
+        let form_approval = &mut ctx.accounts.form_approval;
+        form_approval.metadata = metadata.clone();
+        let now = current_timestamp(ctx.remaining_accounts)?;
+        emit_event!(ctx, append_history(
+            &mut ctx.accounts.form_history,
+            form_approval.key(),
+            HistoryEntry {
+                action: FormAction::Updated,
+                actor: ctx.accounts.admin.key(),
+                at: now,
+                summary_hash: anchor_lang::solana_program::hash::hash(metadata.as_bytes()).to_bytes(),
+            },
+            ctx.bumps.form_history,
+            &mut ctx.accounts.event_sequence,
+        ));
+        
+        emit_event!(ctx, FormUpdated {
+            form_id: form_id.clone(),
+            metadata,
+            updated_by: ctx.accounts.admin.key(),
+            updated_at: now,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        for event in observer_notifications(
+            ctx.remaining_accounts,
+            &form_id,
+            ctx.accounts.form_approval.organization,
+            FormAction::Updated,
+            &mut ctx.accounts.event_sequence,
+        )? {
+            emit_event!(ctx, event);
+        }
+        
+        msg!("Form approval metadata updated by admin: {}", ctx.accounts.admin.key());
+        Ok(())
+    }
+
+    /// Start uploading a document of `total_len` bytes for on-chain hashing
+    pub fn begin_document_upload(
+        ctx: Context<BeginDocumentUpload>,
+        form_id: String,
+        total_len: u32,
+    ) -> Result<()> {
+        require!(
+            total_len > 0 && total_len as usize <= Config::MAX_DOCUMENT_UPLOAD_SIZE,
+            FormApprovalError::InvalidUploadSize
+        );
+        
+        let document_upload = &mut ctx.accounts.document_upload;
+        document_upload.form_id = form_id.clone();
+        document_upload.uploader = ctx.accounts.uploader.key();
+        document_upload.total_len = total_len;
+        document_upload.content = Vec::new();
+        document_upload.started_at = current_timestamp(ctx.remaining_accounts)?;
+        document_upload.bump = ctx.bumps.document_upload;
+        
+        emit_event!(ctx, DocumentUploadStarted {
+            form_id,
+            uploader: document_upload.uploader,
+            total_len,
+            started_at: document_upload.started_at,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        Ok(())
+    }
+
+    /// Append the next chunk of a document upload
+    pub fn append_chunk(
+        ctx: Context<AppendChunk>,
+        form_id: String,
+        offset: u32,
+        chunk: Vec<u8>,
+    ) -> Result<()> {
+        ctx.accounts.document_upload.append(offset, &chunk)?;
+        
+        emit_event!(ctx, DocumentChunkAppended {
+            form_id,
+            uploader: ctx.accounts.uploader.key(),
+            offset,
+            len: chunk.len() as u32,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        Ok(())
+    }
+
+    /// Hash a completed upload on-chain and mark the approval's content verified if it matches
+    pub fn finalize_document(ctx: Context<FinalizeDocument>, form_id: String) -> Result<()> {
+        let document_upload = &ctx.accounts.document_upload;
+        let content_hash = document_upload.content_hash()?;
+        
+        let form_approval = &mut ctx.accounts.form_approval;
+        require!(
+            form_approval.hash_algorithm == HashAlgorithm::Sha256 && form_approval.form_hash == content_hash,
+            FormApprovalError::DocumentHashMismatch
+        );
+        form_approval.content_verified = true;
+        
+        emit_event!(ctx, DocumentContentVerified {
+            form_id,
+            content_hash,
+            content_len: document_upload.total_len,
+            verified_by: ctx.accounts.uploader.key(),
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        Ok(())
+    }
+
+    /// Point an approval at the current location of its signed document
+    pub fn update_document_location(
+        ctx: Context<UpdateDocumentLocation>,
+        form_id: String,
+        document_uri: String,
+        content_type: String,
+    ) -> Result<()> {
+        FormApproval::validate_document_location(&document_uri, &content_type)?;
+        
+        let form_approval = &mut ctx.accounts.form_approval;
+        let previous_uri = std::mem::replace(&mut form_approval.document_uri, document_uri);
+        form_approval.content_type = content_type;
+        
+        emit_event!(ctx, DocumentLocationUpdated {
+            form_id,
+            previous_uri,
+            document_uri: form_approval.document_uri.clone(),
+            content_type: form_approval.content_type.clone(),
+            updated_by: ctx.accounts.admin.key(),
+            updated_at: current_timestamp(ctx.remaining_accounts)?,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        Ok(())
+    }
+
+    /// Block updates and further signatures on a form approval, e.g. during a dispute, without revoking it
+    pub fn freeze_form_approval(ctx: Context<FreezeFormApproval>, form_id: String) -> Result<()> {
+        ctx.accounts.form_approval.frozen = true;
+        
+        emit_event!(ctx, FormApprovalFrozen {
+            form_id: form_id.clone(),
+            authority: ctx.accounts.authority.key(),
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Form {} frozen", form_id);
+        Ok(())
+    }
+
+    /// Lift a freeze, allowing updates and further signatures again
+    pub fn unfreeze_form_approval(ctx: Context<FreezeFormApproval>, form_id: String) -> Result<()> {
+        ctx.accounts.form_approval.frozen = false;
+        
+        emit_event!(ctx, FormApprovalUnfrozen {
+            form_id: form_id.clone(),
+            authority: ctx.accounts.authority.key(),
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Form {} unfrozen", form_id);
+        Ok(())
+    }
+
+    /// Revoke a form approval without deleting it
+    pub fn revoke_form_approval(
+        ctx: Context<RevokeFormApproval>,
+        form_id: String,
+        reason: String,
+    ) -> Result<()> {
+        require!(
+            reason.len() <= Config::MAX_REVOCATION_REASON_LENGTH,
+            FormApprovalError::RevocationReasonTooLong
+        );
+        
+        let form_approval = &mut ctx.accounts.form_approval;
+        require!(!form_approval.is_revoked(), FormApprovalError::FormAlreadyRevoked);
+        form_approval.transition(ApprovalStatus::Revoked)?;
+        
+        form_approval.revoked_at = current_timestamp(ctx.remaining_accounts)?;
+        form_approval.revocation_reason = reason.clone();
+        form_approval.revoked_by = ctx.accounts.admin.key();
+        form_approval.revocation_catalog_hash = PolicyCatalog::stamp(ctx.accounts.policy_catalog.as_deref());
+        emit_event!(ctx, append_history(
+            &mut ctx.accounts.form_history,
+            form_approval.key(),
+            HistoryEntry {
+                action: FormAction::Revoked,
+                actor: form_approval.revoked_by,
+                at: form_approval.revoked_at,
+                summary_hash: anchor_lang::solana_program::hash::hash(reason.as_bytes()).to_bytes(),
+            },
+            ctx.bumps.form_history,
+            &mut ctx.accounts.event_sequence,
+        ));
+        
+        emit_event!(ctx, FormRevoked {
+            form_id: form_id.clone(),
+            signer: form_approval.signer,
+            revoked_at: form_approval.revoked_at,
+            reason,
+            revoked_by: form_approval.revoked_by,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        for event in observer_notifications(
+            ctx.remaining_accounts,
+            &form_id,
+            ctx.accounts.form_approval.organization,
+            FormAction::Revoked,
+            &mut ctx.accounts.event_sequence,
+        )? {
+            emit_event!(ctx, event);
+        }
+        
+        msg!("Form {} revoked by {}", form_id, ctx.accounts.admin.key());
+        Ok(())
+    }
+
+    /// Move a form approval to `Rejected` or `Expired`, following the approval lifecycle
+    pub fn transition_form_status(
+        ctx: Context<TransitionFormStatus>,
+        form_id: String,
+        status: ApprovalStatus,
+    ) -> Result<()> {
+        // Other statuses carry state of their own and have dedicated instructions
+        require!(
+            matches!(status, ApprovalStatus::Rejected | ApprovalStatus::Expired),
+            FormApprovalError::InvalidStatusTransition
+        );
+        
+        let form_approval = &mut ctx.accounts.form_approval;
+        let previous = form_approval.status;
+        form_approval.transition(status)?;
+        
+        emit_event!(ctx, FormStatusChanged {
+            form_id: form_id.clone(),
+            previous,
+            status,
+            changed_by: ctx.accounts.admin.key(),
+            changed_at: current_timestamp(ctx.remaining_accounts)?,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        for event in observer_notifications(
+            ctx.remaining_accounts,
+            &form_id,
+            ctx.accounts.form_approval.organization,
+            FormAction::StatusChanged,
+            &mut ctx.accounts.event_sequence,
+        )? {
+            emit_event!(ctx, event);
+        }
+        
+        msg!("Form {} moved from {:?} to {:?}", form_id, previous, status);
+        Ok(())
+    }
+
+    /// Push back the expiry of a form approval that has not lapsed yet
+    pub fn renew_form_approval(
+        ctx: Context<RenewFormApproval>,
+        form_id: String,
+        expires_at: i64,
+    ) -> Result<()> {
+        let now = current_timestamp(ctx.remaining_accounts)?;
+        let form_approval = &mut ctx.accounts.form_approval;
+        require!(form_approval.expires_at != 0, FormApprovalError::InvalidExpiry);
+        require!(!form_approval.is_expired(now), FormApprovalError::ApprovalExpired);
+        require!(expires_at > form_approval.expires_at, FormApprovalError::InvalidExpiry);
+        
+        let previous_expires_at = form_approval.expires_at;
+        form_approval.expires_at = expires_at;
+        
+        emit_event!(ctx, FormRenewed {
+            form_id: form_id.clone(),
+            previous_expires_at,
+            expires_at,
+            renewed_by: ctx.accounts.admin.key(),
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Form {} renewed until {}", form_id, expires_at);
+        Ok(())
+    }
+
+    /// Record on-chain that an admin reviewed and declined a form submission
+    pub fn reject_form_submission(
+        ctx: Context<RejectFormSubmission>,
+        form_id: String,
+        form_hash: [u8; 32],
+        reason_code: ReasonCode,
+        reason: String,
+    ) -> Result<()> {
+        FormApproval::validate_submission(&form_id, &form_hash, None)?;
+        require!(!reason_code.is_approval(), FormApprovalError::InvalidRejectionCode);
+        require!(
+            reason.len() <= Config::MAX_REJECTION_REASON_LENGTH,
+            FormApprovalError::RejectionReasonTooLong
+        );
+        
+        let form_rejection = &mut ctx.accounts.form_rejection;
+        form_rejection.form_id = form_id.clone();
+        form_rejection.form_hash = form_hash;
+        form_rejection.rejected_by = ctx.accounts.admin.key();
+        form_rejection.reason_code = reason_code;
+        form_rejection.reason = reason.clone();
+        form_rejection.rejected_at = current_timestamp(ctx.remaining_accounts)?;
+        form_rejection.bump = ctx.bumps.form_rejection;
+        form_rejection.policy_catalog_hash = PolicyCatalog::stamp(ctx.accounts.policy_catalog.as_deref());
+        
+        emit_event!(ctx, FormRejected {
+            form_id: form_id.clone(),
+            form_hash,
+            rejected_by: form_rejection.rejected_by,
+            reason_code,
+            reason,
+            rejected_at: form_rejection.rejected_at,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Form {} rejected by {}", form_id, form_rejection.rejected_by);
+        Ok(())
+    }
+
+    /// Sign a corrected document under a revoked form ID, within the re-approval window
+    pub fn reapprove_form_submission(
+        ctx: Context<ReapproveFormSubmission>,
+        form_id: String,
+        form_hash: [u8; 32],
+    ) -> Result<()> {
+        FormApproval::validate_submission(&form_id, &form_hash, None)?;
+        
+        let form_approval = &mut ctx.accounts.form_approval;
+        let clock = Clock {
+            unix_timestamp: current_timestamp(ctx.remaining_accounts)?,
+            ..Clock::get()?
+        };
+        let now = current_timestamp(ctx.remaining_accounts)?;
+        let window = ctx.accounts.program_config.reapproval_window;
+        require!(form_approval.is_revoked(), FormApprovalError::FormNotRevoked);
+        require!(
+            window > 0 && now <= form_approval.revoked_at.saturating_add(window),
+            FormApprovalError::ReapprovalWindowClosed
+        );
+        
+        form_approval.form_hash = form_hash;
+        form_approval.signer = ctx.accounts.admin.key();
+        form_approval.approved_at = clock.unix_timestamp;
+        form_approval.approved_slot = clock.slot;
+        form_approval.program_version = Config::PROGRAM_VERSION;
+        form_approval.revoked_at = 0;
+        form_approval.revocation_reason = String::new();
+        form_approval.revoked_by = Pubkey::default();
+        form_approval.revocation_catalog_hash = [0u8; 32];
+        let required_signatures = form_approval.required_signatures;
+        form_approval.require_signatures(required_signatures);
+        form_approval.revision += 1;
+        
+        // Re-approval changes the approval's leaf, so it is linked again at the chain's end
+        let approval_address = form_approval.key();
+        let approval_chain = &mut ctx.accounts.approval_chain;
+        approval_chain.bump = ctx.bumps.approval_chain;
+        approval_chain.link(form_approval, &approval_address);
+        
+        emit_event!(ctx, FormReapproved {
+            form_id: form_id.clone(),
+            form_hash,
+            signer: form_approval.signer,
+            revision: form_approval.revision,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        for event in observer_notifications(
+            ctx.remaining_accounts,
+            &form_id,
+            form_approval.organization,
+            FormAction::Reapproved,
+            &mut ctx.accounts.event_sequence,
+        )? {
+            emit_event!(ctx, event);
+        }
+        
+        msg!("Form {} re-approved as revision {}", form_id, form_approval.revision);
+        Ok(())
+    }
+
+    /// Upgrade a legacy form approval in place (permissionless and idempotent)
+    pub fn migrate_form_approval_v1(
+        ctx: Context<MigrateFormApproval>,
+        form_id: String,
+    ) -> Result<()> {
+        let account_info = ctx.accounts.form_approval.to_account_info();
+        
+        let approval: FormApproval = {
+            let data = account_info.try_borrow_data()?;
+            require!(
+                data.len() >= 8 && data[..8] == *FormApproval::DISCRIMINATOR,
+                FormApprovalError::InvalidFormApprovalAccount
+            );
+            
+            // Accounts that already deserialize have nothing to migrate
+            if FormApproval::try_deserialize(&mut &data[..]).is_ok() {
+                msg!("Form approval {} is already up to date", form_id);
+                return Ok(());
+            }
+            
+            FormApprovalV1::deserialize(&mut &data[8..])
+                .map_err(|_| FormApprovalError::InvalidFormApprovalAccount)?
+                .into()
+        };
+        
+        let new_space = FormApproval::space(approval.form_id.len(), approval.metadata.len());
+        let rent_due = Rent::get()?
+            .minimum_balance(new_space)
+            .saturating_sub(account_info.lamports());
+        
+        if rent_due > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: account_info.clone(),
+                    },
+                ),
+                rent_due,
+            )?;
+        }
+        
+        account_info.resize(new_space)?;
+        approval.try_serialize(&mut &mut account_info.try_borrow_mut_data()?[..])?;
+        
+        msg!("Form approval {} migrated to the current layout", form_id);
+        Ok(())
+    }
+
+    /// Record a Merkle root over all approvals created in a period
+    pub fn create_checkpoint(
+        ctx: Context<CreateCheckpoint>,
+        period_start: i64,
+        period_end: i64,
+        merkle_root: [u8; 32],
+        approval_count: u32,
+    ) -> Result<()> {
+        let clock = Clock {
+            unix_timestamp: current_timestamp(ctx.remaining_accounts)?,
+            ..Clock::get()?
+        };
+        let program_config = &mut ctx.accounts.program_config;
+        
+        // Periods must be closed, in the past and contiguous with the previous checkpoint
+        require!(
+            period_start < period_end
+                && period_end <= clock.unix_timestamp
+                && period_start >= program_config.last_checkpoint_end,
+            FormApprovalError::InvalidCheckpointPeriod
+        );
+        require!(
+            (merkle_root == [0u8; 32]) == (approval_count == 0),
+            FormApprovalError::InvalidMerkleRoot
+        );
+        
+        let checkpoint = &mut ctx.accounts.checkpoint;
+        checkpoint.checkpoint_id = program_config.checkpoint_count;
+        checkpoint.period_start = period_start;
+        checkpoint.period_end = period_end;
+        checkpoint.merkle_root = merkle_root;
+        checkpoint.approval_count = approval_count;
+        checkpoint.created_by = ctx.accounts.admin.key();
+        checkpoint.created_at = clock.unix_timestamp;
+        checkpoint.created_slot = clock.slot;
+        checkpoint.required_attestations = program_config.checkpoint_quorum;
+        checkpoint.attesters = Vec::new();
+        checkpoint.finalized_at = 0;
+        checkpoint.bump = ctx.bumps.checkpoint;
+        
+        // The submitting admin is the first attester
+        let finalized = checkpoint.attest(ctx.accounts.admin.key(), clock.unix_timestamp)?;
+        
+        program_config.checkpoint_count += 1;
+        program_config.last_checkpoint_end = period_end;
+        
+        emit_event!(ctx, CheckpointCreated {
+            checkpoint_id: checkpoint.checkpoint_id,
+            period_start,
+            period_end,
+            merkle_root,
+            approval_count,
+            created_by: checkpoint.created_by,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!(
+            "Checkpoint {} recorded for period {}..{} with {} approvals",
+            checkpoint.checkpoint_id,
+            period_start,
+            period_end,
+            approval_count
+        );
+        
+        if finalized {
+            emit_event!(ctx, CheckpointFinalized {
+                checkpoint_id: checkpoint.checkpoint_id,
+                merkle_root,
+                attesters: checkpoint.attesters.clone(),
+                event_sequence: ctx.accounts.event_sequence.advance(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Co-sign a checkpoint root as an admin, finalizing it once the quorum is met
+    pub fn attest_checkpoint(ctx: Context<AttestCheckpoint>, checkpoint_id: u64) -> Result<()> {
+        let now = current_timestamp(ctx.remaining_accounts)?;
+        let checkpoint = &mut ctx.accounts.checkpoint;
+        let admin = ctx.accounts.admin.key();
+        
+        let finalized = checkpoint.attest(admin, now)?;
+        
+        emit_event!(ctx, CheckpointAttested {
+            checkpoint_id,
+            admin,
+            attestation_count: checkpoint.attesters.len() as u8,
+            required_attestations: checkpoint.required_attestations,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        if finalized {
+            emit_event!(ctx, CheckpointFinalized {
+                checkpoint_id,
+                merkle_root: checkpoint.merkle_root,
+                attesters: checkpoint.attesters.clone(),
+                event_sequence: ctx.accounts.event_sequence.advance(),
+            });
+            msg!("Checkpoint {} finalized", checkpoint_id);
+        }
+        Ok(())
+    }
+
+    /// Add a new admin to the system
+    pub fn add_admin(ctx: Context<AddAdmin>, new_admin: Pubkey) -> Result<()> {
+        require_no_admin_record(ctx.remaining_accounts.first(), &new_admin)?;
+        let admin_config = &mut ctx.accounts.admin_config;
+        admin_config.add_admin(new_admin)?;
+        
+        emit_event!(ctx, AdminAdded {
+            admin: new_admin,
+            authority: ctx.accounts.authority.key(),
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("New admin added: {}", new_admin);
+        Ok(())
+    }
+
+    /// Add several admins in one transaction; fails as a whole if any of them cannot be added
+    pub fn add_admins(ctx: Context<AddAdmin>, new_admins: Vec<Pubkey>) -> Result<()> {
+        require!(
+            !new_admins.is_empty() && new_admins.len() <= Config::MAX_ADMINS,
+            FormApprovalError::InvalidAdminBatch
+        );
+        
+        let admin_config = &mut ctx.accounts.admin_config;
+        for (i, new_admin) in new_admins.iter().enumerate() {
+            require_no_admin_record(ctx.remaining_accounts.get(i), new_admin)?;
+            admin_config.add_admin(*new_admin)?;
+            emit_event!(ctx, AdminAdded {
+                admin: *new_admin,
+                authority: ctx.accounts.authority.key(),
+                event_sequence: ctx.accounts.event_sequence.advance(),
+            });
+        }
+        
+        msg!("{} admins added", new_admins.len());
+        Ok(())
+    }
+
+    /// Register an admin as its own `AdminRecord` account, so the admin set can grow past `MAX_ADMINS`
+    pub fn add_admin_record(ctx: Context<AddAdminRecord>, admin: Pubkey) -> Result<()> {
+        require!(
+            ctx.accounts.admin_config.slot_of(&admin).is_none(),
+            FormApprovalError::AdminAlreadyExists
+        );
+        
+        let admin_record = &mut ctx.accounts.admin_record;
+        admin_record.admin = admin;
+        admin_record.permissions = AdminConfig::DEFAULT_PERMISSIONS;
+        admin_record.expires_at = 0;
+        admin_record.added_by = ctx.accounts.authority.key();
+        admin_record.added_at = current_timestamp(ctx.remaining_accounts)?;
+        admin_record.bump = ctx.bumps.admin_record;
+        admin_record.signatures_count = 0;
+        admin_record.last_signed_at = 0;
+        ctx.accounts.admin_config.record_count += 1;
+        
+        emit_event!(ctx, AdminAdded {
+            admin,
+            authority: ctx.accounts.authority.key(),
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Admin {} registered as record", admin);
+        Ok(())
+    }
+
+    /// Replace a registered admin's permission flags and expiry (0 never expires)
+    pub fn update_admin_record(
+        ctx: Context<UpdateAdminRecord>,
+        admin: Pubkey,
+        permissions: u8,
+        expires_at: i64,
+    ) -> Result<()> {
+        require!(
+            permissions & !AdminConfig::ALL_PERMISSIONS == 0,
+            FormApprovalError::InvalidPermissions
+        );
+        require!(
+            expires_at == 0 || expires_at > current_timestamp(ctx.remaining_accounts)?,
+            FormApprovalError::InvalidAdminExpiry
+        );
+        
+        let admin_record = &mut ctx.accounts.admin_record;
+        admin_record.permissions = permissions;
+        admin_record.expires_at = expires_at;
+        
+        emit_event!(ctx, AdminPermissionsChanged {
+            admin,
+            permissions,
+            authority: ctx.accounts.authority.key(),
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        emit_event!(ctx, AdminExpirySet {
+            admin,
+            expires_at,
+            authority: ctx.accounts.authority.key(),
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Admin record {} permissions set to {:#06b}, expiring at {}", admin, permissions, expires_at);
+        Ok(())
+    }
+
+    /// Remove a registered admin, returning its record rent to the caller
+    pub fn remove_admin_record(ctx: Context<RemoveAdminRecord>, admin: Pubkey) -> Result<()> {
+        ctx.accounts.admin_config.record_count -= 1;
+        
+        emit_event!(ctx, AdminRemoved {
+            admin,
+            authority: ctx.accounts.authority.key(),
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Admin record {} removed", admin);
+        Ok(())
+    }
+
+    /// Invite a key to become an admin; it is only added once it accepts
+    pub fn invite_admin(ctx: Context<InviteAdmin>, invitee: Pubkey) -> Result<()> {
+        require!(
+            !ctx.accounts.admin_config.is_admin(&invitee),
+            FormApprovalError::AdminAlreadyExists
+        );
+        
+        let admin_invitation = &mut ctx.accounts.admin_invitation;
+        admin_invitation.invitee = invitee;
+        admin_invitation.invited_by = ctx.accounts.authority.key();
+        admin_invitation.invited_at = current_timestamp(ctx.remaining_accounts)?;
+        admin_invitation.bump = ctx.bumps.admin_invitation;
+        admin_invitation.expires_at = match ctx.accounts.program_config.invitation_ttl {
+            0 => 0,
+            ttl => admin_invitation.invited_at + ttl,
+        };
+        
+        emit_event!(ctx, AdminInvited {
+            invitee,
+            invited_by: admin_invitation.invited_by,
+            expires_at: admin_invitation.expires_at,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Admin invitation sent to {}", invitee);
+        Ok(())
+    }
+
+    /// Accept an admin invitation, proving control of the invited key
+    pub fn accept_admin_invitation(ctx: Context<AcceptAdminInvitation>) -> Result<()> {
+        require!(
+            ctx.accounts.admin_invitation.is_open(current_timestamp(ctx.remaining_accounts)?),
+            FormApprovalError::InvitationExpired
+        );
+        
+        let invitee = ctx.accounts.invitee.key();
+        ctx.accounts.admin_config.add_admin(invitee)?;
+        
+        emit_event!(ctx, AdminAdded {
+            admin: invitee,
+            authority: ctx.accounts.admin_invitation.invited_by,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Admin invitation accepted by {}", invitee);
+        Ok(())
+    }
+
+    /// Withdraw a pending admin invitation
+    pub fn cancel_admin_invitation(ctx: Context<CancelAdminInvitation>, invitee: Pubkey) -> Result<()> {
+        emit_event!(ctx, AdminInvitationCancelled {
+            invitee,
+            cancelled_by: ctx.accounts.authority.key(),
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Admin invitation for {} cancelled", invitee);
+        Ok(())
+    }
+
+    /// Schedule an admin's removal, leaving them active until `effective_at`
+    pub fn schedule_admin_removal(
+        ctx: Context<ScheduleAdminRemoval>,
+        admin: Pubkey,
+        effective_at: i64,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.admin_config.is_admin(&admin),
+            FormApprovalError::AdminNotFound
+        );
+        
+        let now = current_timestamp(ctx.remaining_accounts)?;
+        require!(
+            effective_at > now && effective_at >= now + ctx.accounts.program_config.admin_change_delay,
+            FormApprovalError::InvalidRemovalTime
+        );
+        
+        let admin_removal = &mut ctx.accounts.admin_removal;
+        admin_removal.admin = admin;
+        admin_removal.scheduled_by = ctx.accounts.authority.key();
+        admin_removal.scheduled_at = now;
+        admin_removal.effective_at = effective_at;
+        admin_removal.bump = ctx.bumps.admin_removal;
+        
+        emit_event!(ctx, AdminRemovalScheduled {
+            admin,
+            effective_at,
+            scheduled_by: admin_removal.scheduled_by,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Admin {} scheduled for removal at {}", admin, effective_at);
+        Ok(())
+    }
+
+    /// Cancel a scheduled admin removal before it is executed
+    pub fn cancel_admin_removal(ctx: Context<CancelAdminRemoval>, admin: Pubkey) -> Result<()> {
+        emit_event!(ctx, AdminRemovalCancelled {
+            admin,
+            cancelled_by: ctx.accounts.authority.key(),
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Scheduled removal of admin {} cancelled", admin);
+        Ok(())
+    }
+
+    /// Remove an admin whose scheduled removal is now effective (callable by anyone)
+    pub fn execute_admin_removal(ctx: Context<ExecuteAdminRemoval>, admin: Pubkey) -> Result<()> {
+        require!(
+            current_timestamp(ctx.remaining_accounts)? >= ctx.accounts.admin_removal.effective_at,
+            FormApprovalError::RemovalNotYetEffective
+        );
+        
+        ctx.accounts.admin_config.remove_admin(&admin)?;
+        ctx.accounts.program_config.require_quorum_reachable(&ctx.accounts.admin_config)?;
+        
+        emit_event!(ctx, AdminRemoved {
+            admin,
+            authority: ctx.accounts.admin_removal.scheduled_by,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Admin removed: {}", admin);
+        Ok(())
+    }
+
+    /// Queue an admin addition, removal or delay change behind the configured delay
+    pub fn queue_admin_change(ctx: Context<QueueAdminChange>, change: AdminChange) -> Result<()> {
+        match change {
+            AdminChange::Add { admin } => {
+                require!(
+                    !ctx.accounts.admin_config.is_admin(&admin),
+                    FormApprovalError::AdminAlreadyExists
+                );
+                require_no_admin_record(ctx.accounts.admin_record.as_deref(), &admin)?;
+            }
+            AdminChange::Remove { admin } => require!(
+                ctx.accounts.admin_config.is_admin(&admin),
+                FormApprovalError::AdminNotFound
+            ),
+            AdminChange::AddRecord { admin } => {
+                require!(
+                    ctx.accounts.admin_config.slot_of(&admin).is_none(),
+                    FormApprovalError::AdminAlreadyExists
+                );
+                require_no_admin_record(ctx.accounts.admin_record.as_deref(), &admin)?;
+            }
+            AdminChange::RemoveRecord { admin } => require!(
+                !admin_record_account(ctx.accounts.admin_record.as_deref(), &admin)?.data_is_empty(),
+                FormApprovalError::AdminNotFound
+            ),
+            AdminChange::SetDelay { delay } => require!(
+                (0..=Config::MAX_ADMIN_CHANGE_DELAY).contains(&delay),
+                FormApprovalError::InvalidAdminChangeDelay
+            ),
+        }
+        
+        let now = current_timestamp(ctx.remaining_accounts)?;
+        let program_config = &mut ctx.accounts.program_config;
+        let pending_admin_change = &mut ctx.accounts.pending_admin_change;
+        pending_admin_change.id = program_config.admin_change_count;
+        pending_admin_change.change = change;
+        pending_admin_change.queued_by = ctx.accounts.authority.key();
+        pending_admin_change.queued_at = now;
+        pending_admin_change.effective_at = now + program_config.admin_change_delay;
+        pending_admin_change.bump = ctx.bumps.pending_admin_change;
+        program_config.admin_change_count += 1;
+        
+        emit_event!(ctx, AdminChangeQueued {
+            id: pending_admin_change.id,
+            change,
+            queued_by: pending_admin_change.queued_by,
+            effective_at: pending_admin_change.effective_at,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!(
+            "Admin change {} queued until {}",
+            pending_admin_change.id,
+            pending_admin_change.effective_at
+        );
+        Ok(())
+    }
+
+    /// Apply a queued admin change once its delay has passed (callable by anyone)
+    pub fn finalize_admin_change(ctx: Context<FinalizeAdminChange>, id: u64) -> Result<()> {
+        let pending_admin_change = &ctx.accounts.pending_admin_change;
+        require!(
+            current_timestamp(ctx.remaining_accounts)? >= pending_admin_change.effective_at,
+            FormApprovalError::AdminChangeNotReady
+        );
+        
+        let change = pending_admin_change.change;
+        match change {
+            AdminChange::Add { admin } => {
+                require_no_admin_record(ctx.accounts.admin_record.as_deref(), &admin)?;
+                ctx.accounts.admin_config.add_admin(admin)?
+            }
+            AdminChange::Remove { admin } => {
+                ctx.accounts.admin_config.remove_admin(&admin)?;
+                ctx.accounts.program_config.require_quorum_reachable(&ctx.accounts.admin_config)?;
+            }
+            AdminChange::SetDelay { delay } => ctx.accounts.program_config.admin_change_delay = delay,
+            AdminChange::AddRecord { admin } => {
+                require!(
+                    ctx.accounts.admin_config.slot_of(&admin).is_none(),
+                    FormApprovalError::AdminAlreadyExists
+                );
+                require_no_admin_record(ctx.accounts.admin_record.as_deref(), &admin)?;
+                
+                let (Some(payer), Some(system_program)) = (&ctx.accounts.payer, &ctx.accounts.system_program) else {
+                    return err!(FormApprovalError::AdminRecordPayerRequired);
+                };
+                let record_info = admin_record_account(ctx.accounts.admin_record.as_deref(), &admin)?;
+                let (_, bump) = Pubkey::find_program_address(&[Config::ADMIN_RECORD_SEED, admin.as_ref()], &crate::ID);
+                create_pda(
+                    record_info,
+                    payer,
+                    system_program,
+                    AdminRecord::space(),
+                    &[Config::ADMIN_RECORD_SEED, admin.as_ref(), &[bump]],
+                )?;
+                
+                let admin_record = AdminRecord {
+                    admin,
+                    permissions: AdminConfig::DEFAULT_PERMISSIONS,
+                    expires_at: 0,
+                    added_by: pending_admin_change.queued_by,
+                    added_at: current_timestamp(ctx.remaining_accounts)?,
+                    bump,
+                    signatures_count: 0,
+                    last_signed_at: 0,
+                };
+                admin_record.try_serialize(&mut &mut record_info.try_borrow_mut_data()?[..])?;
+                ctx.accounts.admin_config.record_count += 1;
+            }
+            AdminChange::RemoveRecord { admin } => {
+                let record_info = admin_record_account(ctx.accounts.admin_record.as_deref(), &admin)?;
+                require!(
+                    *record_info.owner == crate::ID
+                        && AdminRecord::try_deserialize(&mut &record_info.try_borrow_data()?[..]).is_ok(),
+                    FormApprovalError::AdminNotFound
+                );
+                
+                let rent = record_info.lamports();
+                record_info.sub_lamports(rent)?;
+                ctx.accounts.queued_by.add_lamports(rent)?;
+                record_info.assign(&system_program::ID);
+                record_info.resize(0)?;
+                ctx.accounts.admin_config.record_count -= 1;
+            }
+        }
+        
+        emit_event!(ctx, AdminChangeFinalized {
+            id,
+            change,
+            queued_by: pending_admin_change.queued_by,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Admin change {} finalized", id);
+        Ok(())
+    }
+
+    /// Discard a queued admin change before it is finalized
+    pub fn cancel_admin_change(ctx: Context<CancelAdminChange>, id: u64) -> Result<()> {
+        emit_event!(ctx, AdminChangeCancelled {
+            id,
+            change: ctx.accounts.pending_admin_change.change,
+            cancelled_by: ctx.accounts.admin.key(),
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Admin change {} cancelled by {}", id, ctx.accounts.admin.key());
+        Ok(())
+    }
+
+    /// Change an admin's signature weight
+    pub fn set_admin_weight(ctx: Context<SetAdminWeight>, admin: Pubkey, weight: u16) -> Result<()> {
+        ctx.accounts.admin_config.set_weight(&admin, weight)?;
+        
+        emit_event!(ctx, AdminWeightChanged {
+            admin,
+            weight,
+            authority: ctx.accounts.authority.key(),
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Admin {} weight set to {}", admin, weight);
+        Ok(())
+    }
+
+    /// Replace the permission flags of an admin
+    pub fn set_admin_permissions(
+        ctx: Context<SetAdminPermissions>,
+        admin: Pubkey,
+        permissions: u8,
+    ) -> Result<()> {
+        ctx.accounts.admin_config.set_permissions(&admin, permissions)?;
+        
+        emit_event!(ctx, AdminPermissionsChanged {
+            admin,
+            permissions,
+            authority: ctx.accounts.authority.key(),
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Admin {} permissions set to {:#06b}", admin, permissions);
+        Ok(())
+    }
+
+    /// Grant an admin time-boxed rights that lapse at `expires_at` (0 never expires)
+    pub fn set_admin_expiry(
+        ctx: Context<SetAdminPermissions>,
+        admin: Pubkey,
+        expires_at: i64,
+    ) -> Result<()> {
+        ctx.accounts.admin_config.set_expiry(&admin, expires_at)?;
+        
+        emit_event!(ctx, AdminExpirySet {
+            admin,
+            expires_at,
+            authority: ctx.accounts.authority.key(),
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Admin {} expires at {}", admin, expires_at);
+        Ok(())
+    }
+
+    /// Propose a new authority, which takes over only once it accepts; the default key cancels a proposal
+    pub fn propose_authority_transfer(
+        ctx: Context<ProposeAuthorityTransfer>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.admin_config.pending_authority = new_authority;
+        
+        emit_event!(ctx, AuthorityTransferProposed {
+            authority: ctx.accounts.authority.key(),
+            pending_authority: new_authority,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Authority transfer proposed to {}", new_authority);
+        Ok(())
+    }
+
+    /// Accept a proposed authority transfer, proving control of the new key
+    pub fn accept_authority_transfer(ctx: Context<AcceptAuthorityTransfer>) -> Result<()> {
+        let admin_config = &mut ctx.accounts.admin_config;
+        let previous_authority = admin_config.authority;
+        admin_config.authority = ctx.accounts.new_authority.key();
+        admin_config.pending_authority = Pubkey::default();
+        
+        emit_event!(ctx, AuthorityTransferred {
+            previous_authority,
+            new_authority: admin_config.authority,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Authority transferred to {}", admin_config.authority);
+        Ok(())
+    }
+
+    /// Permanently give up the authority, freezing the admin set and every authority-only setting
+    pub fn renounce_authority(ctx: Context<RenounceAuthority>) -> Result<()> {
+        let admin_config = &mut ctx.accounts.admin_config;
+        admin_config.authority = AdminConfig::RENOUNCED_AUTHORITY;
+        admin_config.pending_authority = Pubkey::default();
+        
+        emit_event!(ctx, AuthorityRenounced {
+            previous_authority: ctx.accounts.authority.key(),
+            renounced_at: current_timestamp(ctx.remaining_accounts)?,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Authority renounced by {}", ctx.accounts.authority.key());
+        Ok(())
+    }
+
+    /// Remove an admin from the system
+    pub fn remove_admin(ctx: Context<RemoveAdmin>, admin_to_remove: Pubkey) -> Result<()> {
+        let admin_config = &mut ctx.accounts.admin_config;
+        admin_config.remove_admin(&admin_to_remove)?;
+        ctx.accounts.program_config.require_quorum_reachable(admin_config)?;
+        
+        emit_event!(ctx, AdminRemoved {
+            admin: admin_to_remove,
+            authority: ctx.accounts.authority.key(),
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Admin removed: {}", admin_to_remove);
+        Ok(())
+    }
+
+    /// Remove several admins in one transaction; fails as a whole if any of them cannot be removed
+    pub fn remove_admins(ctx: Context<RemoveAdmin>, admins_to_remove: Vec<Pubkey>) -> Result<()> {
+        require!(
+            !admins_to_remove.is_empty() && admins_to_remove.len() <= Config::MAX_ADMINS,
+            FormApprovalError::InvalidAdminBatch
+        );
+        
+        let admin_config = &mut ctx.accounts.admin_config;
+        for admin in &admins_to_remove {
+            admin_config.remove_admin(admin)?;
+            emit_event!(ctx, AdminRemoved {
+                admin: *admin,
+                authority: ctx.accounts.authority.key(),
+                event_sequence: ctx.accounts.event_sequence.advance(),
+            });
+        }
+        
+        ctx.accounts.program_config.require_quorum_reachable(admin_config)?;
+        
+        msg!("{} admins removed", admins_to_remove.len());
+        Ok(())
+    }
+
+    /// Register the signing key as an admin by proving it is in the import root
+    pub fn register_admin_with_proof(
+        ctx: Context<RegisterAdminWithProof>,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        let root = ctx.accounts.program_config.admin_import_root;
+        require!(root != [0u8; 32], FormApprovalError::AdminImportDisabled);
+        
+        let candidate = ctx.accounts.candidate.key();
+        require!(
+            merkle::verify_proof(&merkle::admin_leaf(&candidate), &proof, &root),
+            FormApprovalError::InvalidAdminProof
+        );
+        
+        let admin_config = &mut ctx.accounts.admin_config;
+        admin_config.add_admin(candidate)?;
+        
+        emit_event!(ctx, AdminAdded {
+            admin: candidate,
+            authority: admin_config.authority,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Admin {} registered from the import root", candidate);
+        Ok(())
+    }
+
+    /// Replace an admin with a new key atomically, keeping the admin count unchanged
+    pub fn replace_admin(ctx: Context<ReplaceAdmin>, old_admin: Pubkey, new_admin: Pubkey) -> Result<()> {
+        let admin_config = &mut ctx.accounts.admin_config;
+        admin_config.replace_admin(&old_admin, new_admin)?;
+        
+        // The default policy may name the outgoing admin as its required signer
+        ctx.accounts.program_config.threshold_policy.validate(admin_config)?;
+        
+        emit_event!(ctx, AdminReplaced {
+            old_admin,
+            new_admin,
+            authority: ctx.accounts.authority.key(),
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Admin {} replaced by {}", old_admin, new_admin);
+        Ok(())
+    }
+
+    /// Record that the signer accepted the consent disclosure for a form
+    pub fn record_consent(
+        ctx: Context<RecordConsent>,
+        form_id: String,
+        disclosure_hash: [u8; 32],
+    ) -> Result<()> {
+        require!(
+            form_id.len() <= Config::MAX_FORM_ID_LENGTH,
+            FormApprovalError::FormIdTooLong
+        );
+        require!(disclosure_hash != [0u8; 32], FormApprovalError::InvalidConsentDisclosure);
+        
+        let consent_record = &mut ctx.accounts.consent_record;
+        consent_record.signer = ctx.accounts.signer.key();
+        consent_record.form_id = form_id.clone();
+        consent_record.disclosure_hash = disclosure_hash;
+        consent_record.consented_at = current_timestamp(ctx.remaining_accounts)?;
+        consent_record.bump = ctx.bumps.consent_record;
+        
+        emit_event!(ctx, ConsentRecorded {
+            form_id: form_id.clone(),
+            signer: consent_record.signer,
+            disclosure_hash,
+            consented_at: consent_record.consented_at,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Consent for form {} recorded by {}", form_id, consent_record.signer);
+        Ok(())
+    }
+
+    /// Register the signing wallet as an observer of a form
+    pub fn register_observer(ctx: Context<RegisterObserver>, form_id: String) -> Result<()> {
+        require!(
+            form_id.len() <= Config::MAX_FORM_ID_LENGTH,
+            FormApprovalError::FormIdTooLong
+        );
+        
+        let form_observer = &mut ctx.accounts.form_observer;
+        form_observer.observer = ctx.accounts.observer.key();
+        form_observer.form_id = form_id.clone();
+        form_observer.registered_at = current_timestamp(ctx.remaining_accounts)?;
+        form_observer.bump = ctx.bumps.form_observer;
+        form_observer.organization = Pubkey::default();
+        
+        msg!("{} is observing form {}", form_observer.observer, form_id);
+        Ok(())
+    }
+
+    /// Stop observing a form, returning the observer rent
+    pub fn unregister_observer(ctx: Context<UnregisterObserver>, form_id: String) -> Result<()> {
+        msg!("{} stopped observing form {}", ctx.accounts.observer.key(), form_id);
+        Ok(())
+    }
+
+    /// Register the signing wallet as an observer of a form in an organization's namespace
+    pub fn register_org_observer(ctx: Context<RegisterOrgObserver>, form_id: String) -> Result<()> {
+        require!(
+            form_id.len() <= Config::MAX_FORM_ID_LENGTH,
+            FormApprovalError::FormIdTooLong
+        );
+        
+        let form_observer = &mut ctx.accounts.form_observer;
+        form_observer.observer = ctx.accounts.observer.key();
+        form_observer.form_id = form_id.clone();
+        form_observer.registered_at = current_timestamp(ctx.remaining_accounts)?;
+        form_observer.bump = ctx.bumps.form_observer;
+        form_observer.organization = ctx.accounts.organization.key();
+        
+        msg!(
+            "{} is observing form {} of organization {}",
+            form_observer.observer,
+            form_id,
+            ctx.accounts.organization.org_id
+        );
+        Ok(())
+    }
+
+    /// Stop observing a form in an organization's namespace, returning the observer rent
+    pub fn unregister_org_observer(ctx: Context<UnregisterOrgObserver>, form_id: String) -> Result<()> {
+        msg!("{} stopped observing form {}", ctx.accounts.observer.key(), form_id);
+        Ok(())
+    }
+
+    /// Close a form approval and refund its rent to `recipient`
+    pub fn close_form_approval(ctx: Context<CloseFormApproval>, form_id: String) -> Result<()> {
+        ArchiveRecord::check_before_close(
+            ctx.accounts.archive_record.as_deref(),
+            &ctx.accounts.form_approval.to_account_info().try_borrow_data()?,
+            ctx.accounts.program_config.require_archive_before_close,
+        )?;
+        
+        emit_event!(ctx, FormApprovalClosed {
+            form_id: form_id.clone(),
+            approval: ctx.accounts.form_approval.key(),
+            closed_by: ctx.accounts.admin.key(),
+            recipient: ctx.accounts.recipient.key(),
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Form approval {} closed by {}", form_id, ctx.accounts.admin.key());
+        Ok(())
+    }
+
+    /// Record that a form approval was exported to off-chain retention storage
+    pub fn archive_form_approval(
+        ctx: Context<ArchiveFormApproval>,
+        form_id: String,
+        archive_uri: String,
+    ) -> Result<()> {
+        require!(
+            archive_uri.len() <= Config::MAX_ARCHIVE_URI_LENGTH,
+            FormApprovalError::ArchiveUriTooLong
+        );
+        
+        let approval_info = ctx.accounts.form_approval.to_account_info();
+        let approval_hash =
+            anchor_lang::solana_program::hash::hash(&approval_info.try_borrow_data()?).to_bytes();
+        
+        let archive_record = &mut ctx.accounts.archive_record;
+        archive_record.approval = approval_info.key();
+        archive_record.form_id = form_id.clone();
+        archive_record.approval_hash = approval_hash;
+        archive_record.archive_uri = archive_uri.clone();
+        archive_record.archived_by = ctx.accounts.admin.key();
+        archive_record.archived_at = current_timestamp(ctx.remaining_accounts)?;
+        archive_record.bump = ctx.bumps.archive_record;
+        
+        emit_event!(ctx, ApprovalArchived {
+            approval: archive_record.approval,
+            form_id,
+            approval_hash,
+            archive_uri,
+            archived_at: archive_record.archived_at,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        Ok(())
+    }
+
+    /// Record an Arweave bundle holding the approval accounts passed as writable remaining accounts,
+    /// flagging each of them as archived
+    pub fn archive_approval(
+        ctx: Context<ArchiveApproval>,
+        bundle_id: [u8; 32],
+        archiver: Pubkey,
+        archiver_signature: [u8; 64],
+    ) -> Result<()> {
+        let batch = ctx.remaining_accounts;
+        require!(
+            !batch.is_empty() && batch.len() <= Config::MAX_ARCHIVE_BATCH,
+            FormApprovalError::InvalidArchiveBatch
+        );
+        
+        let mut approvals: Vec<Pubkey> = Vec::with_capacity(batch.len());
+        for account_info in batch {
+            require_keys_eq!(
+                *account_info.owner,
+                crate::ID,
+                FormApprovalError::InvalidFormApprovalAccount
+            );
+            require!(
+                account_info.is_writable && !approvals.contains(account_info.key),
+                FormApprovalError::InvalidArchiveBatch
+            );
+            let mut data = account_info.try_borrow_mut_data()?;
+            let mut approval = FormApproval::try_deserialize(&mut &data[..])
+                .map_err(|_| error!(FormApprovalError::InvalidFormApprovalAccount))?;
+            approval.archived = true;
+            approval.try_serialize(&mut &mut data[..])?;
+            approvals.push(*account_info.key);
+        }
+        
+        let archive_bundle = &mut ctx.accounts.archive_bundle;
+        archive_bundle.bundle_id = bundle_id;
+        archive_bundle.archiver = archiver;
+        archive_bundle.archiver_signature = archiver_signature;
+        archive_bundle.approvals = approvals;
+        archive_bundle.archived_by = ctx.accounts.admin.key();
+        archive_bundle.archived_at = Clock::get()?.unix_timestamp;
+        archive_bundle.bump = ctx.bumps.archive_bundle;
+        
+        emit_event!(ctx, ApprovalsBundled {
+            bundle_id,
+            archiver,
+            approvals: archive_bundle.approvals.clone(),
+            archived_at: archive_bundle.archived_at,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("{} approvals archived in bundle", archive_bundle.approvals.len());
+        Ok(())
+    }
+
+    /// Attest that a form's approval was mirrored to another cluster or chain
+    pub fn record_mirror_attestation(
+        ctx: Context<RecordMirrorAttestation>,
+        form_id: String,
+        location: MirrorLocation,
+        remote_tx_hash: [u8; 32],
+        attester: Pubkey,
+        attester_signature: [u8; 64],
+    ) -> Result<()> {
+        require!(remote_tx_hash != [0u8; 32], FormApprovalError::InvalidRemoteReference);
+        
+        let mirror_attestation = &mut ctx.accounts.mirror_attestation;
+        mirror_attestation.approval = ctx.accounts.form_approval.key();
+        mirror_attestation.form_id = form_id.clone();
+        mirror_attestation.location = location;
+        mirror_attestation.remote_tx_hash = remote_tx_hash;
+        mirror_attestation.attester = attester;
+        mirror_attestation.attester_signature = attester_signature;
+        mirror_attestation.recorded_by = ctx.accounts.admin.key();
+        mirror_attestation.recorded_at = current_timestamp(ctx.remaining_accounts)?;
+        mirror_attestation.bump = ctx.bumps.mirror_attestation;
+        
+        emit_event!(ctx, MirrorAttested {
+            form_id: form_id.clone(),
+            approval: mirror_attestation.approval,
+            location,
+            remote_tx_hash,
+            attester,
+            recorded_at: mirror_attestation.recorded_at,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Form {} mirrored to {:?}", form_id, location);
+        Ok(())
+    }
+
+    /// Withdraw a mirror attestation, e.g. once the remote copy is gone
+    pub fn remove_mirror_attestation(
+        ctx: Context<RemoveMirrorAttestation>,
+        form_id: String,
+        remote_tx_hash: [u8; 32],
+    ) -> Result<()> {
+        emit_event!(ctx, MirrorAttestationRemoved {
+            form_id: form_id.clone(),
+            location: ctx.accounts.mirror_attestation.location,
+            remote_tx_hash,
+            removed_by: ctx.accounts.admin.key(),
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Mirror attestation removed for form {}", form_id);
+        Ok(())
+    }
+
+    /// Index a form approval under the hash of an external reference
+    pub fn add_search_key(ctx: Context<AddSearchKey>, form_id: String, key_hash: [u8; 32]) -> Result<()> {
+        require!(key_hash != [0u8; 32], FormApprovalError::InvalidSearchKey);
+        
+        let search_key = &mut ctx.accounts.search_key;
+        search_key.key_hash = key_hash;
+        search_key.approval = ctx.accounts.form_approval.key();
+        search_key.form_id = form_id.clone();
+        search_key.created_by = ctx.accounts.admin.key();
+        search_key.created_at = current_timestamp(ctx.remaining_accounts)?;
+        search_key.bump = ctx.bumps.search_key;
+        
+        emit_event!(ctx, SearchKeyAdded {
+            key_hash,
+            form_id: form_id.clone(),
+            approval: search_key.approval,
+            created_by: search_key.created_by,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Search key added for form {}", form_id);
+        Ok(())
+    }
+
+    /// Remove a search key, returning its rent to the admin that created it
+    pub fn remove_search_key(ctx: Context<RemoveSearchKey>, key_hash: [u8; 32]) -> Result<()> {
+        emit_event!(ctx, SearchKeyRemoved {
+            key_hash,
+            form_id: ctx.accounts.search_key.form_id.clone(),
+            removed_by: ctx.accounts.admin.key(),
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Search key for form {} removed", ctx.accounts.search_key.form_id);
+        Ok(())
+    }
+
+    /// Deposit funds that the form's approval releases to the counterparty
+    pub fn create_escrow(
+        ctx: Context<CreateEscrow>,
+        form_id: String,
+        form_hash: [u8; 32],
+        counterparty: Pubkey,
+        amount: u64,
+        refund_after: i64,
+    ) -> Result<()> {
+        FormApproval::validate_submission(&form_id, &form_hash, None)?;
+        require!(amount > 0, FormApprovalError::InvalidEscrowAmount);
+        let now = current_timestamp(ctx.remaining_accounts)?;
+        require!(refund_after > now, FormApprovalError::InvalidExpiry);
+        
+        let accounts = &ctx.accounts;
+        let mint = match (
+            &accounts.mint,
+            &accounts.depositor_token_account,
+            &accounts.vault,
+            &accounts.token_program,
+        ) {
+            (Some(mint), Some(depositor_token_account), Some(vault), Some(token_program)) => {
+                token_interface::transfer_checked(
+                    CpiContext::new(
+                        token_program.to_account_info(),
+                        token_interface::TransferChecked {
+                            from: depositor_token_account.to_account_info(),
+                            mint: mint.to_account_info(),
+                            to: vault.to_account_info(),
+                            authority: accounts.depositor.to_account_info(),
+                        },
+                    ),
+                    amount,
+                    mint.decimals,
+                )?;
+                mint.key()
+            }
+            (None, None, None, None) => {
+                system_program::transfer(
+                    CpiContext::new(
+                        accounts.system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: accounts.depositor.to_account_info(),
+                            to: accounts.escrow.to_account_info(),
+                        },
+                    ),
+                    amount,
+                )?;
+                Pubkey::default()
+            }
+            _ => return err!(FormApprovalError::IncompleteTokenAccounts),
+        };
+        
+        let escrow = &mut ctx.accounts.escrow;
+        escrow.form_id = form_id.clone();
+        escrow.form_hash = form_hash;
+        escrow.depositor = ctx.accounts.depositor.key();
+        escrow.counterparty = counterparty;
+        escrow.mint = mint;
+        escrow.amount = amount;
+        escrow.refund_after = refund_after;
+        escrow.created_at = now;
+        escrow.bump = ctx.bumps.escrow;
+        
+        emit_event!(ctx, EscrowCreated {
+            form_id,
+            escrow: escrow.key(),
+            depositor: escrow.depositor,
+            counterparty,
+            mint,
+            amount,
+            refund_after,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        Ok(())
+    }
+
+    /// Release escrowed funds to the counterparty once the form is approved, or refund them
+    pub fn settle_escrow(ctx: Context<SettleEscrow>, form_id: String) -> Result<()> {
+        let now = current_timestamp(ctx.remaining_accounts)?;
+        let accounts = &ctx.accounts;
+        let escrow = &accounts.escrow;
+        let released = escrow.releases(
+            accounts.form_approval.as_deref(),
+            accounts.form_rejection.is_some(),
+            now,
+        )?;
+        let recipient = if released { escrow.counterparty } else { escrow.depositor };
+        
+        if escrow.is_native() {
+            let recipient_info = if released {
+                accounts.counterparty.to_account_info()
+            } else {
+                accounts.depositor.to_account_info()
+            };
+            escrow.to_account_info().sub_lamports(escrow.amount)?;
+            recipient_info.add_lamports(escrow.amount)?;
+        } else {
+            let (Some(mint), Some(vault), Some(recipient_token_account), Some(token_program)) = (
+                &accounts.mint,
+                &accounts.vault,
+                &accounts.recipient_token_account,
+                &accounts.token_program,
+            ) else {
+                return err!(FormApprovalError::IncompleteTokenAccounts);
+            };
+            require_keys_eq!(
+                recipient_token_account.owner,
+                recipient,
+                FormApprovalError::IncompleteTokenAccounts
+            );
+            
+            let depositor = escrow.depositor;
+            let signer_seeds: &[&[&[u8]]] = &[&[
+                Config::ESCROW_SEED,
+                form_id.as_bytes(),
+                depositor.as_ref(),
+                &[escrow.bump],
+            ]];
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    token_interface::TransferChecked {
+                        from: vault.to_account_info(),
+                        mint: mint.to_account_info(),
+                        to: recipient_token_account.to_account_info(),
+                        authority: escrow.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                escrow.amount,
+                mint.decimals,
+            )?;
+            token_interface::close_account(CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token_interface::CloseAccount {
+                    account: vault.to_account_info(),
+                    destination: accounts.depositor.to_account_info(),
+                    authority: escrow.to_account_info(),
+                },
+                signer_seeds,
+            ))?;
+        }
+        
+        emit_event!(ctx, EscrowSettled {
+            form_id: form_id.clone(),
+            escrow: escrow.key(),
+            recipient,
+            mint: escrow.mint,
+            amount: escrow.amount,
+            released,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!(
+            "Escrow for form {} {} to {}",
+            form_id,
+            if released { "released" } else { "refunded" },
+            recipient
+        );
+        Ok(())
+    }
+
+    /// Verify a form approval (read-only function)
+    pub fn verify_form_approval(
+        ctx: Context<VerifyFormApproval>,
+        _form_id: String,
+        expected_hash: [u8; 32],
+    ) -> Result<bool> {
+        let form_approval = &ctx.accounts.form_approval;
+        let is_valid = form_approval.is_valid_for(&expected_hash, current_timestamp(ctx.remaining_accounts)?);
+        
+        msg!(
+            "Form verification result: {} (expected: {:?}, actual: {:?})",
+            is_valid,
+            expected_hash,
+            form_approval.form_hash
+        );
+        
+        Ok(is_valid)
+    }
+
+    /// Verify a form approval like `verify_form_approval` against its full digest, computed with `algorithm`
+    pub fn verify_form_digest(
+        ctx: Context<VerifyFormApproval>,
+        _form_id: String,
+        algorithm: HashAlgorithm,
+        expected_digest: Vec<u8>,
+    ) -> Result<bool> {
+        let form_approval = &ctx.accounts.form_approval;
+        let is_valid = form_approval.is_valid_for_digest(algorithm, &expected_digest, current_timestamp(ctx.remaining_accounts)?);
+        
+        msg!(
+            "Form digest verification result: {} (expected: {:?}, actual: {:?})",
+            is_valid,
+            algorithm,
+            form_approval.hash_algorithm
+        );
+        
+        Ok(is_valid)
+    }
+
+    /// Verify that a page belongs to an approved document whose form hash is a page Merkle root
+    pub fn verify_page_proof(
+        ctx: Context<VerifyFormApproval>,
+        _form_id: String,
+        page_index: u32,
+        page_hash: [u8; 32],
+        proof: Vec<[u8; 32]>,
+    ) -> Result<bool> {
+        let form_approval = &ctx.accounts.form_approval;
+        let is_valid = form_approval.covers_page(page_index, &page_hash, &proof, current_timestamp(ctx.remaining_accounts)?);
+        
+        msg!("Page {} proof verification result: {}", page_index, is_valid);
+        Ok(is_valid)
+    }
+
+    /// Verify a form approval in an organization's namespace (read-only function)
+    pub fn verify_org_form_approval(
+        ctx: Context<VerifyOrgFormApproval>,
+        _form_id: String,
+        expected_hash: [u8; 32],
+    ) -> Result<bool> {
+        let is_valid = ctx
+            .accounts
+            .form_approval
+            .is_valid_for(&expected_hash, current_timestamp(ctx.remaining_accounts)?);
+        
+        msg!(
+            "Form verification result in organization {}: {}",
+            ctx.accounts.organization.org_id,
+            is_valid
+        );
+        Ok(is_valid)
+    }
+
+    /// Verify a form approval like `verify_form_approval`, counting the verification per approval and verifier
+    pub fn verify_and_record(
+        ctx: Context<VerifyAndRecord>,
+        form_id: String,
+        expected_hash: [u8; 32],
+    ) -> Result<bool> {
+        let now = current_timestamp(ctx.remaining_accounts)?;
+        let approval = ctx.accounts.form_approval.key();
+        let is_valid = ctx.accounts.form_approval.is_valid_for(&expected_hash, now);
+        
+        let verifier_record = &mut ctx.accounts.verifier_record;
+        let first_verification = verifier_record.count == 0;
+        verifier_record.approval = approval;
+        verifier_record.verifier = ctx.accounts.verifier.key();
+        verifier_record.bump = ctx.bumps.verifier_record;
+        verifier_record.record(now, ctx.accounts.program_config.verifier_rate_limit)?;
+        
+        let verification_stats = &mut ctx.accounts.verification_stats;
+        verification_stats.approval = approval;
+        verification_stats.total_verifications += 1;
+        if first_verification {
+            verification_stats.verifier_count += 1;
+        }
+        verification_stats.last_verified_at = now;
+        verification_stats.bump = ctx.bumps.verification_stats;
+        
+        emit_event!(ctx, VerificationRecorded {
+            form_id,
+            verifier: verifier_record.verifier,
+            is_valid,
+            total_verifications: verification_stats.total_verifications,
+            verifier_verifications: verifier_record.count,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        Ok(is_valid)
+    }
+
+    /// Count a reported signing attempt by a key that is not an admin
+    pub fn report_unauthorized_attempt(
+        ctx: Context<ReportUnauthorizedAttempt>,
+        attempted_by: Pubkey,
+        evidence_hash: [u8; 32],
+    ) -> Result<()> {
+        let confirmed = ctx.accounts.reporter.key() == ctx.accounts.admin_config.authority;
+        let abuse_counters = &mut ctx.accounts.abuse_counters;
+        abuse_counters.reported_attempts += 1;
+        if confirmed {
+            abuse_counters.confirmed_attempts += 1;
+        }
+        abuse_counters.last_attempted_by = attempted_by;
+        abuse_counters.last_evidence_hash = evidence_hash;
+        abuse_counters.last_reported_at = current_timestamp(ctx.remaining_accounts)?;
+        abuse_counters.bump = ctx.bumps.abuse_counters;
+        
+        emit_event!(ctx, UnauthorizedAttemptReported {
+            attempted_by,
+            reporter: ctx.accounts.reporter.key(),
+            evidence_hash,
+            confirmed,
+            reported_attempts: abuse_counters.reported_attempts,
+            confirmed_attempts: abuse_counters.confirmed_attempts,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        Ok(())
+    }
+
+    /// Count the distinct signers whose approval of a form matches the expected hash
+    pub fn verify_form_signatures(
+        ctx: Context<VerifyFormSignatures>,
+        form_id: String,
+        expected_hash: [u8; 32],
+    ) -> Result<u32> {
+        let now = current_timestamp(ctx.remaining_accounts)?;
+        let mut signers: Vec<Pubkey> = Vec::new();
+        
+        for account_info in ctx.remaining_accounts.iter().filter(|account| !is_localnet_clock(account)) {
+            require_keys_eq!(
+                *account_info.owner,
+                crate::ID,
+                FormApprovalError::InvalidFormApprovalAccount
+            );
+            let approval = FormApproval::try_deserialize(&mut &account_info.try_borrow_data()?[..])
+                .map_err(|_| error!(FormApprovalError::InvalidFormApprovalAccount))?;
+            require!(
+                approval.is_signer_record(account_info.key, &form_id)
+                    || approval.is_primary_record(account_info.key, &form_id),
+                FormApprovalError::InvalidFormApprovalAccount
+            );
+            
+            if approval.is_valid_for(&expected_hash, now) && !signers.contains(&approval.signer) {
+                signers.push(approval.signer);
+            }
+        }
+        
+        msg!("Form {} has {} matching signatures", form_id, signers.len());
+        Ok(signers.len() as u32)
+    }
+
+    /// Check cross-account consistency and emit a health report (read-only)
+    pub fn assert_invariants(
+        ctx: Context<AssertInvariants>,
+        form_id: String,
+        baseline: InvariantBaseline,
+    ) -> Result<bool> {
+        let admins_consistent = ctx.accounts.admin_config.is_consistent();
+        
+        let program_config = &ctx.accounts.program_config;
+        let counters_monotonic = program_config.config_sequence >= baseline.config_sequence
+            && program_config.checkpoint_count >= baseline.checkpoint_count;
+        
+        let tally_consistent = match &ctx.accounts.approval_tally {
+            Some(tally) => {
+                tally.is_consistent() && tally_matches_signatures(tally, &form_id, ctx.remaining_accounts)
+            }
+            None => true,
+        };
+        
+        let healthy = admins_consistent && counters_monotonic && tally_consistent;
+        
+        emit_event!(ctx, InvariantReport {
+            healthy,
+            admins_consistent,
+            tally_consistent,
+            counters_monotonic,
+            config_sequence: program_config.config_sequence,
+            checkpoint_count: program_config.checkpoint_count,
+            checked_at: current_timestamp(ctx.remaining_accounts)?,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        msg!("Invariant check: {}", if healthy { "healthy" } else { "violations found" });
+        Ok(healthy)
+    }
+
+    /// Verify a form approval on behalf of a registered application, counting the verification
+    pub fn record_app_verification(
+        ctx: Context<RecordAppVerification>,
+        form_id: String,
+        expected_hash: [u8; 32],
+    ) -> Result<bool> {
+        let form_approval = &ctx.accounts.form_approval;
+        let is_valid = form_approval.is_valid_for(&expected_hash, current_timestamp(ctx.remaining_accounts)?);
+        
+        let registered_app = &mut ctx.accounts.registered_app;
+        registered_app.verifications += 1;
+        
+        emit_event!(ctx, AppVerificationRecorded {
+            app_key: registered_app.app_key,
+            form_id,
+            is_valid,
+            verifications: registered_app.verifications,
+            event_sequence: ctx.accounts.event_sequence.advance(),
+        });
+        
+        Ok(is_valid)
+    }
+
+    /// Get form approval details (read-only function)
+    pub fn get_form_approval_details(
+        ctx: Context<VerifyFormApproval>,
+        _form_id: String,
+    ) -> Result<FormApprovalDetails> {
+        Ok(ctx.accounts.form_approval.details())
+    }
+
+    /// Report which required signers of a form have signed (read-only function)
+    pub fn get_signing_progress(
+        ctx: Context<VerifyFormApproval>,
+        _form_id: String,
+    ) -> Result<SigningProgress> {
+        Ok(ctx.accounts.form_approval.signing_progress())
+    }
+
+    /// Shift the clock seen by time-dependent checks (localnet builds only)
+    #[cfg(feature = "localnet")]
+    pub fn force_set_clock_offset(ctx: Context<ForceSetClockOffset>, offset: i64) -> Result<()> {
+        let localnet_clock = &mut ctx.accounts.localnet_clock;
+        localnet_clock.offset = offset;
+        localnet_clock.bump = ctx.bumps.localnet_clock;
+        
+        msg!("Localnet clock offset set to {} seconds", offset);
+        Ok(())
+    }
+}
+
+/// Advance the config sequence and build the `ConfigChanged` event for `setting`
+fn config_change(
+    accounts: &mut UpdateProgramConfig,
+    setting: &str,
+    prior_hash: [u8; 32],
+) -> Result<ConfigChanged> {
+    let program_config = &mut accounts.program_config;
+    program_config.config_sequence += 1;
+    
+    Ok(ConfigChanged {
+        sequence: program_config.config_sequence,
+        setting: setting.to_string(),
+        prior_hash,
+        new_hash: program_config.config_hash()?,
+        changed_by: accounts.authority.key(),
+        changed_at: Clock::get()?.unix_timestamp,
+        event_sequence: accounts.event_sequence.advance(),
+    })
+}
+
+/// Invoke the program itself with `event`, signed by the event authority PDA
+///
+/// Equivalent to Anchor's `emit_cpi!`, which cannot name the handler's `ctx`
+/// when expanded inside `emit_event!`.
+#[cfg(feature = "event-cpi")]
+fn emit_cpi_event<'info, T: anchor_lang::Event>(
+    event_authority: &AccountInfo<'info>,
+    bump: u8,
+    event: &T,
+) -> Result<()> {
+    let data: Vec<u8> = anchor_lang::event::EVENT_IX_TAG_LE
+        .iter()
+        .copied()
+        .chain(event.data())
+        .collect();
+    let ix = anchor_lang::solana_program::instruction::Instruction::new_with_bytes(
+        crate::ID,
+        &data,
+        vec![AccountMeta::new_readonly(event_authority.key(), true)],
+    );
+    anchor_lang::solana_program::program::invoke_signed(
+        &ix,
+        std::slice::from_ref(event_authority),
+        &[&[b"__event_authority", &[bump]]],
+    )?;
+    Ok(())
+}
+
+/// Current unix time, shifted by the localnet clock offset in localnet builds
+#[cfg(feature = "localnet")]
+fn current_timestamp(remaining_accounts: &[AccountInfo]) -> Result<i64> {
+    localnet::now(remaining_accounts)
+}
+
+/// Current unix time
+#[cfg(not(feature = "localnet"))]
+fn current_timestamp(_remaining_accounts: &[AccountInfo]) -> Result<i64> {
+    Ok(Clock::get()?.unix_timestamp)
+}
+
+/// Whether a remaining account is the localnet clock read by `current_timestamp`
+#[cfg(feature = "localnet")]
+fn is_localnet_clock(account: &AccountInfo) -> bool {
+    account.key() == Pubkey::find_program_address(&[localnet::LOCALNET_CLOCK_SEED], &crate::ID).0
+}
+
+/// Whether a remaining account is the localnet clock, which only exists in localnet builds
+#[cfg(not(feature = "localnet"))]
+fn is_localnet_clock(_account: &AccountInfo) -> bool {
+    false
+}
+
+/// The account passed as the `AdminRecord` address of `admin`, failing if it is missing or another account
+fn admin_record_account<'a, 'info>(
+    record: Option<&'a AccountInfo<'info>>,
+    admin: &Pubkey,
+) -> Result<&'a AccountInfo<'info>> {
+    let (address, _) = Pubkey::find_program_address(&[Config::ADMIN_RECORD_SEED, admin.as_ref()], &crate::ID);
+    let record = record.ok_or(FormApprovalError::InvalidAdminRecordAccount)?;
+    require_keys_eq!(record.key(), address, FormApprovalError::InvalidAdminRecordAccount);
+    Ok(record)
+}
+
+/// Fail unless `record` is the `AdminRecord` address of `admin` and holds no record
+fn require_no_admin_record(record: Option<&AccountInfo>, admin: &Pubkey) -> Result<()> {
+    require!(
+        admin_record_account(record, admin)?.data_is_empty(),
+        FormApprovalError::AdminAlreadyExists
+    );
+    Ok(())
+}
+
+/// Create a program-owned account at a PDA of this program, topping up any lamports already sent to it
+fn create_pda<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    seeds: &[&[u8]],
+) -> Result<()> {
+    let rent_due = Rent::get()?.minimum_balance(space).saturating_sub(account.lamports());
+    if rent_due > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+    
+    let signer_seeds: &[&[&[u8]]] = &[seeds];
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Allocate { account_to_allocate: account.clone() },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Assign { account_to_assign: account.clone() },
+            signer_seeds,
+        ),
+        &crate::ID,
+    )?;
+    Ok(())
+}
+
+/// Whether `approvals` are exactly the tally's signer approvals, each over the tallied hash
+fn tally_matches_signatures(tally: &ApprovalTally, form_id: &str, approvals: &[AccountInfo]) -> bool {
+    if approvals.len() != tally.signers.len() {
+        return false;
+    }
+    
+    let mut matched: Vec<Pubkey> = Vec::new();
+    for account_info in approvals {
+        if *account_info.owner != crate::ID {
+            return false;
+        }
+        let Ok(data) = account_info.try_borrow_data() else {
+            return false;
+        };
+        let Ok(approval) = FormApproval::try_deserialize(&mut &data[..]) else {
+            return false;
+        };
+        
+        if !approval.is_signer_record(account_info.key, form_id)
+            || approval.form_hash != tally.form_hash
+            || !tally.signers.contains(&approval.signer)
+            || matched.contains(&approval.signer)
+        {
+            return false;
+        }
+        matched.push(approval.signer);
+    }
+    true
+}
+
+/// Append `entry` to an approval's history, returning the `FormHistoryAppended` event
+fn append_history(
+    history: &mut FormHistory,
+    approval: Pubkey,
+    entry: HistoryEntry,
+    bump: u8,
+    event_sequence: &mut EventSequence,
+) -> FormHistoryAppended {
+    history.append(approval, entry, bump);
+    
+    FormHistoryAppended {
+        approval,
+        index: history.entry_count - 1,
+        action: entry.action,
+        actor: entry.actor,
+        at: entry.at,
+        summary_hash: entry.summary_hash,
+        head: history.head,
+        event_sequence: event_sequence.advance(),
+    }
+}
+
+/// `ObserverNotified` events for every observer of `form_id` passed among `remaining_accounts`
+fn observer_notifications(
+    remaining_accounts: &[AccountInfo],
+    form_id: &str,
+    organization: Pubkey,
+    action: FormAction,
+    event_sequence: &mut EventSequence,
+) -> Result<Vec<ObserverNotified>> {
+    let mut notifications = Vec::new();
+    for account_info in remaining_accounts {
+        if *account_info.owner != crate::ID
+            || !account_info.try_borrow_data()?.starts_with(FormObserver::DISCRIMINATOR)
+        {
+            continue;
+        }
+        let form_observer = FormObserver::try_deserialize(&mut &account_info.try_borrow_data()?[..])?;
+        require!(
+            form_observer.form_id == form_id && form_observer.organization == organization,
+            FormApprovalError::InvalidObserverAccount
+        );
+        
+        notifications.push(ObserverNotified {
+            observer: form_observer.observer,
+            form_id: form_id.to_string(),
+            action,
+            event_sequence: event_sequence.advance(),
+        });
+    }
+    Ok(notifications)
+}
+
+/// Accounts read and written when recording a signed submission, in either namespace
+struct SubmissionAccounts<'a, 'info> {
+    form_approval: &'a mut Account<'info, FormApproval>,
+    form_approval_bump: u8,
+    approval_chain: &'a mut ApprovalChain,
+    approval_chain_bump: u8,
+    hash_index: &'a mut HashIndex,
+    hash_index_bump: u8,
+    /// Admin configuration counting the signer's activity, absent for CPI signers
+    admin_config: Option<&'a mut AdminConfig>,
+    admin_record: Option<&'a mut AdminRecord>,
+    program_config: &'a ProgramConfig,
+    registered_app: Option<&'a mut RegisteredApp>,
+    app_signer: Option<Pubkey>,
+    template_version: Option<&'a Account<'info, TemplateVersion>>,
+    consent_record: Option<&'a ConsentRecord>,
+    /// Feature gates, required when the request collects more than one signature
+    feature_gates: Option<&'a FeatureGates>,
+    admin: Pubkey,
+    /// Organization owning the form ID namespace (default for the global namespace)
+    organization: Pubkey,
+    event_sequence: &'a mut EventSequence,
+}
+
+/// Events of a recorded submission, in the order they are emitted
+struct SubmissionEvents {
+    duplicate: Option<DuplicateHashDetected>,
+    approved: FormApproved,
+    notifications: Vec<ObserverNotified>,
+}
+
+/// Check and record a signed submission, shared by `sign_form_submission`, `sign_org_form_submission` and `sign_form_submission_cpi`
+///
+/// Applies the client version, consent, app, template, dependency and multi-sign gate checks,
+/// fills the approval from `options`, counts the admin's activity, links the
+/// approval into the chain and anchors its hash, returning the events to emit.
+fn record_submission(
+    accounts: SubmissionAccounts,
+    remaining_accounts: &[AccountInfo],
+    form_id: &str,
+    form_hash: [u8; 32],
+    metadata: Option<String>,
+    options: SignOptions,
+) -> Result<SubmissionEvents> {
+    let program_config = accounts.program_config;
+    program_config.check_client_version(options.client_version)?;
+    ConsentRecord::check_required(accounts.consent_record, program_config.strict_consent)?;
+    FormApproval::validate_submission(form_id, &form_hash, metadata.as_deref())?;
+    let reason_code = options.approval_reason()?;
+    let timestamp_token_hash = options.timestamp_token_hash()?;
+    let app_id = RegisteredApp::resolve(
+        accounts.registered_app,
+        accounts.app_signer,
+        program_config.require_app_signature,
+    )?;
+    let locale = options.locale()?;
+    let jurisdiction = options.jurisdiction()?;
+    let compliance = options.compliance()?;
+    let required_signatures = options.signature_quorum(program_config.max_signers_per_form)?;
+    let template_version = TemplateVersion::pin(accounts.template_version, &locale, &jurisdiction)?;
+    
+    let form_approval = accounts.form_approval;
+    let clock = Clock {
+        unix_timestamp: current_timestamp(remaining_accounts)?,
+        ..Clock::get()?
+    };
+    
+    // Initialize the form approval account
+    form_approval.record(
+        form_id.to_string(),
+        form_hash,
+        accounts.admin,
+        metadata.unwrap_or_default(),
+        accounts.form_approval_bump,
+        &clock,
+    );
+    form_approval.organization = accounts.organization;
+    form_approval.reason_code = reason_code;
+    form_approval.timestamp_token_hash = timestamp_token_hash;
+    form_approval.app_id = app_id;
+    form_approval.template_version = template_version;
+    form_approval.locale = locale;
+    form_approval.jurisdiction = jurisdiction;
+    (form_approval.document_uri, form_approval.content_type) = options.document_location()?;
+    (form_approval.hash_algorithm, form_approval.form_hash_tail) = options.digest()?;
+    form_approval.page_count = options.page_count(form_approval.hash_algorithm)?;
+    form_approval.compliance = compliance;
+    form_approval.client_signed_at = options.client_signed_at(clock.unix_timestamp, program_config.max_clock_skew)?;
+    form_approval.expires_at = options.expires_at(clock.unix_timestamp)?;
+    form_approval.counterparties = options.counterparties()?;
+    form_approval.required_signers = options.required_signers()?;
+    (form_approval.not_before, form_approval.not_after) = options.signing_window(clock.unix_timestamp)?;
+    form_approval.check_signing_window(clock.unix_timestamp)?;
+    form_approval.dependencies = options.dependencies(&form_approval.key())?;
+    check_dependencies(remaining_accounts, &form_approval.dependencies, clock.unix_timestamp)?;
+    form_approval.mark_signed(&accounts.admin);
+    form_approval.require_signatures(required_signatures);
+    
+    // Update, co-sign and the other follow-up instructions only reach the global namespace
+    require!(
+        accounts.organization == Pubkey::default()
+            || (required_signatures == 1 && form_approval.required_signers.is_empty()),
+        FormApprovalError::OrgCoSigningUnsupported
+    );
+    require!(
+        (required_signatures == 1 && form_approval.required_signers.is_empty())
+            || accounts.feature_gates.is_some_and(|gates| gates.is_enabled(FeatureGate::MultiSign)),
+        FormApprovalError::FeatureDisabled
+    );
+    
+    if let Some(admin_config) = accounts.admin_config {
+        admin_config.record_signature(&accounts.admin, accounts.admin_record, clock.unix_timestamp);
+    }
+    
+    let approval_address = form_approval.key();
+    let approval_chain = accounts.approval_chain;
+    approval_chain.bump = accounts.approval_chain_bump;
+    approval_chain.link(form_approval, &approval_address);
+    
+    let event_sequence = accounts.event_sequence;
+    let hash_index = accounts.hash_index;
+    let mut duplicate = None;
+    if let Some(existing_approval) = hash_index.anchor(
+        form_hash,
+        form_id,
+        approval_address,
+        clock.unix_timestamp,
+        accounts.hash_index_bump,
+    ) {
+        require!(
+            !program_config.reject_duplicate_hashes || options.allow_duplicate_hash.unwrap_or(false),
+            FormApprovalError::DuplicateFormHash
+        );
+        form_approval.duplicate_of = existing_approval;
+        
+        duplicate = Some(DuplicateHashDetected {
+            form_hash,
+            form_id: form_id.to_string(),
+            existing_approval,
+            existing_form_id: hash_index.form_id.clone(),
+            duplicate_count: hash_index.duplicate_count,
+            event_sequence: event_sequence.advance(),
+        });
+    }
+    
+    let approved = FormApproved {
+        form_id: form_id.to_string(),
+        form_hash,
+        signer: accounts.admin,
+        approved_at: clock.unix_timestamp,
+        reason_code,
+        event_sequence: event_sequence.advance(),
+    };
+    let notifications = observer_notifications(
+        remaining_accounts,
+        form_id,
+        accounts.organization,
+        FormAction::Approved,
+        event_sequence,
+    )?;
+    
+    Ok(SubmissionEvents {
+        duplicate,
+        approved,
+        notifications,
+    })
+}
+
+/// Check that every dependency approval is passed among `remaining_accounts` and in force at `now`
+fn check_dependencies(remaining_accounts: &[AccountInfo], dependencies: &[Pubkey], now: i64) -> Result<()> {
+    for dependency in dependencies {
+        let account_info = remaining_accounts
+            .iter()
+            .find(|account_info| account_info.key == dependency && *account_info.owner == crate::ID)
+            .ok_or(FormApprovalError::MissingDependency)?;
+        let approval = FormApproval::try_deserialize(&mut &account_info.try_borrow_data()?[..])?;
+        require!(
+            approval.is_in_force(now),
+            FormApprovalError::DependencyNotMet
+        );
+    }
+    Ok(())
+}
+
+fn approval_finalized(form_id: &str, tally: &ApprovalTally, event_sequence: &mut EventSequence) -> ApprovalFinalized {
+    ApprovalFinalized {
+        form_id: form_id.to_string(),
+        form_hash: tally.form_hash,
+        signers: tally.signers.clone(),
+        weights: tally.weights.clone(),
+        weighted_sum: tally.weighted_sum,
+        finalized_at: tally.approved_at,
+        event_sequence: event_sequence.advance(),
+    }
+}
+
+/// Events emitted by the contract
+#[event]
+pub struct FormApproved {
+    pub form_id: String,
+    pub form_hash: [u8; 32],
+    pub signer: Pubkey,
+    pub approved_at: i64,
+    pub reason_code: ReasonCode,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct FormUpdated {
+    pub form_id: String,
+    pub metadata: String,
+    pub updated_by: Pubkey,
+    pub updated_at: i64,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct AdminAdded {
+    pub admin: Pubkey,
+    pub authority: Pubkey,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct AdminRemoved {
+    pub admin: Pubkey,
+    pub authority: Pubkey,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct AdminReplaced {
+    pub old_admin: Pubkey,
+    pub new_admin: Pubkey,
+    pub authority: Pubkey,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct CheckpointCreated {
+    pub checkpoint_id: u64,
+    pub period_start: i64,
+    pub period_end: i64,
+    pub merkle_root: [u8; 32],
+    pub approval_count: u32,
+    pub created_by: Pubkey,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct CheckpointAttested {
+    pub checkpoint_id: u64,
+    pub admin: Pubkey,
+    pub attestation_count: u8,
+    pub required_attestations: u8,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct CheckpointFinalized {
+    pub checkpoint_id: u64,
+    pub merkle_root: [u8; 32],
+    pub attesters: Vec<Pubkey>,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct ApprovalFinalized {
+    pub form_id: String,
+    pub form_hash: [u8; 32],
+    pub signers: Vec<Pubkey>,
+    pub weights: Vec<u16>,
+    pub weighted_sum: u32,
+    pub finalized_at: i64,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct SignatureWithdrawn {
+    pub form_id: String,
+    pub signer: Pubkey,
+    pub signature_count: u32,
+    pub weighted_sum: u32,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct FormRevoked {
+    pub form_id: String,
+    pub signer: Pubkey,
+    pub revoked_at: i64,
+    pub reason: String,
+    pub revoked_by: Pubkey,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct FormReapproved {
+    pub form_id: String,
+    pub form_hash: [u8; 32],
+    pub signer: Pubkey,
+    pub revision: u32,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct AppVerificationRecorded {
+    pub app_key: Pubkey,
+    pub form_id: String,
+    pub is_valid: bool,
+    pub verifications: u64,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct ConfigChanged {
+    pub sequence: u64,
+    pub setting: String,
+    pub prior_hash: [u8; 32],
+    pub new_hash: [u8; 32],
+    pub changed_by: Pubkey,
+    pub changed_at: i64,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct AdminInvited {
+    pub invitee: Pubkey,
+    pub invited_by: Pubkey,
+    pub expires_at: i64,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct AdminRemovalScheduled {
+    pub admin: Pubkey,
+    pub effective_at: i64,
+    pub scheduled_by: Pubkey,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct AdminRemovalCancelled {
+    pub admin: Pubkey,
+    pub cancelled_by: Pubkey,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct InvariantReport {
+    pub healthy: bool,
+    pub admins_consistent: bool,
+    pub tally_consistent: bool,
+    pub counters_monotonic: bool,
+    pub config_sequence: u64,
+    pub checkpoint_count: u64,
+    pub checked_at: i64,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct TemplateVersionPublished {
+    pub template_id: String,
+    pub version: u32,
+    pub content_hash: [u8; 32],
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct TemplateVersionDeprecated {
+    pub template_id: String,
+    pub version: u32,
+    pub deprecated_at: i64,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct ApprovalArchived {
+    pub approval: Pubkey,
+    pub form_id: String,
+    pub approval_hash: [u8; 32],
+    pub archive_uri: String,
+    pub archived_at: i64,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct ObserverNotified {
+    pub observer: Pubkey,
+    pub form_id: String,
+    pub action: FormAction,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct FormApprovalClosed {
+    pub form_id: String,
+    pub approval: Pubkey,
+    pub closed_by: Pubkey,
+    pub recipient: Pubkey,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct FormCoSigned {
+    pub form_id: String,
+    pub co_signer: Pubkey,
+    pub signature_count: u8,
+    pub required_signatures: u8,
+    pub approved: bool,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct FormStatusChanged {
+    pub form_id: String,
+    pub previous: ApprovalStatus,
+    pub status: ApprovalStatus,
+    pub changed_by: Pubkey,
+    pub changed_at: i64,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct FormRejected {
+    pub form_id: String,
+    pub form_hash: [u8; 32],
+    pub rejected_by: Pubkey,
+    pub reason_code: ReasonCode,
+    pub reason: String,
+    pub rejected_at: i64,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct FormRenewed {
+    pub form_id: String,
+    pub previous_expires_at: i64,
+    pub expires_at: i64,
+    pub renewed_by: Pubkey,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct EscrowCreated {
+    pub form_id: String,
+    pub escrow: Pubkey,
+    pub depositor: Pubkey,
+    pub counterparty: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub refund_after: i64,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct EscrowSettled {
+    pub form_id: String,
+    pub escrow: Pubkey,
+    pub recipient: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub released: bool,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct FormCounterSigned {
+    pub form_id: String,
+    pub signer: Pubkey,
+    pub form_hash: [u8; 32],
+    pub signed_at: i64,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct SignatureReminder {
+    pub form_id: String,
+    pub admin: Pubkey,
+    pub webhook_hash: [u8; 32],
+    pub escalated: bool,
+    pub reminded_at: i64,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct AnnotationAdded {
+    pub form_id: String,
+    pub approval: Pubkey,
+    pub index: u16,
+    pub author: Pubkey,
+    pub note_hash: [u8; 32],
+    pub page_ref: u32,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct AccessGranted {
+    pub form_id: String,
+    pub grantee: Pubkey,
+    pub granted_by: Pubkey,
+    pub expires_at: i64,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct AccessRevoked {
+    pub form_id: String,
+    pub grantee: Pubkey,
+    pub revoked_by: Pubkey,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct ApprovalsBundled {
+    pub bundle_id: [u8; 32],
+    pub archiver: Pubkey,
+    pub approvals: Vec<Pubkey>,
+    pub archived_at: i64,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct AdminPermissionsChanged {
+    pub admin: Pubkey,
+    pub permissions: u8,
+    pub authority: Pubkey,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct AdminWeightChanged {
+    pub admin: Pubkey,
+    pub weight: u16,
+    pub authority: Pubkey,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct AdminExpirySet {
+    pub admin: Pubkey,
+    pub expires_at: i64,
+    pub authority: Pubkey,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct AuthorityTransferProposed {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct AuthorityRenounced {
+    pub previous_authority: Pubkey,
+    pub renounced_at: i64,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct MirrorAttested {
+    pub form_id: String,
+    pub approval: Pubkey,
+    pub location: MirrorLocation,
+    pub remote_tx_hash: [u8; 32],
+    pub attester: Pubkey,
+    pub recorded_at: i64,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct MirrorAttestationRemoved {
+    pub form_id: String,
+    pub location: MirrorLocation,
+    pub remote_tx_hash: [u8; 32],
+    pub removed_by: Pubkey,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct AdminChangeQueued {
+    pub id: u64,
+    pub change: AdminChange,
+    pub queued_by: Pubkey,
+    pub effective_at: i64,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct AdminChangeFinalized {
+    pub id: u64,
+    pub change: AdminChange,
+    pub queued_by: Pubkey,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct AdminChangeCancelled {
+    pub id: u64,
+    pub change: AdminChange,
+    pub cancelled_by: Pubkey,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct WebhookManifestUpdated {
+    pub version: u32,
+    pub previous_hash: [u8; 32],
+    pub manifest_hash: [u8; 32],
+    pub updated_by: Pubkey,
+    pub updated_at: i64,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct VerificationRecorded {
+    pub form_id: String,
+    pub verifier: Pubkey,
+    pub is_valid: bool,
+    pub total_verifications: u64,
+    pub verifier_verifications: u64,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct LegacyApprovalImported {
+    pub form_id: String,
+    pub form_hash: [u8; 32],
+    pub signer: Pubkey,
+    pub approved_at: i64,
+    pub imported_by: Pubkey,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct FormApprovalFrozen {
+    pub form_id: String,
+    pub authority: Pubkey,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct FormApprovalUnfrozen {
+    pub form_id: String,
+    pub authority: Pubkey,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct PolicyCatalogUpdated {
+    pub version: u32,
+    pub previous_hash: [u8; 32],
+    pub catalog_hash: [u8; 32],
+    pub updated_by: Pubkey,
+    pub updated_at: i64,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct UnauthorizedAttemptReported {
+    pub attempted_by: Pubkey,
+    pub reporter: Pubkey,
+    pub evidence_hash: [u8; 32],
+    pub confirmed: bool,
+    pub reported_attempts: u64,
+    pub confirmed_attempts: u64,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct DocumentLocationUpdated {
+    pub form_id: String,
+    pub previous_uri: String,
+    pub document_uri: String,
+    pub content_type: String,
+    pub updated_by: Pubkey,
+    pub updated_at: i64,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct DocumentContentVerified {
+    pub form_id: String,
+    pub content_hash: [u8; 32],
+    pub content_len: u32,
+    pub verified_by: Pubkey,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct DuplicateHashDetected {
+    pub form_hash: [u8; 32],
+    pub form_id: String,
+    pub existing_approval: Pubkey,
+    pub existing_form_id: String,
+    pub duplicate_count: u32,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct OrganizationCreated {
+    pub organization: Pubkey,
+    pub org_id: String,
+    pub authority: Pubkey,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct OrgAdminAdded {
+    pub organization: Pubkey,
+    pub admin: Pubkey,
+    pub authority: Pubkey,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct OrgAdminRemoved {
+    pub organization: Pubkey,
+    pub admin: Pubkey,
+    pub authority: Pubkey,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct FormHistoryAppended {
+    pub approval: Pubkey,
+    pub index: u32,
+    pub action: FormAction,
+    pub actor: Pubkey,
+    pub at: i64,
+    pub summary_hash: [u8; 32],
+    pub head: [u8; 32],
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct OrgCounterpartySigned {
+    pub form_id: String,
+    pub organization: Pubkey,
+    pub counterparty_org: Pubkey,
+    pub signer: Pubkey,
+    pub signed_at: i64,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct OrganizationSuspended {
+    pub organization: Pubkey,
+    pub reason_hash: [u8; 32],
+    pub suspended_by: Pubkey,
+    pub suspended_at: i64,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct OrganizationReinstated {
+    pub organization: Pubkey,
+    pub reinstated_by: Pubkey,
+    pub reinstated_at: i64,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct FeatureGateChanged {
+    pub feature: FeatureGate,
+    pub enabled: bool,
+    pub changed_by: Pubkey,
+    pub changed_at: i64,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct CategoryPolicySet {
+    pub category: String,
+    pub policy: ThresholdPolicy,
+    pub set_by: Pubkey,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct BrandingUpdated {
+    pub logo_uri_hash: [u8; 32],
+    pub issuer_display_name: String,
+    pub support_contact_hash: [u8; 32],
+    pub updated_by: Pubkey,
+    pub updated_at: i64,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct AppRegistered {
+    pub app_key: Pubkey,
+    pub name: String,
+    pub registered_by: Pubkey,
+    pub registered_at: i64,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct AppActiveChanged {
+    pub app_key: Pubkey,
+    pub active: bool,
+    pub authority: Pubkey,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct NotificationPrefsUpdated {
+    pub admin: Pubkey,
+    pub reminder_interval: i64,
+    pub escalation_opt_in: bool,
+    pub webhook_hash: [u8; 32],
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct DocumentUploadStarted {
+    pub form_id: String,
+    pub uploader: Pubkey,
+    pub total_len: u32,
+    pub started_at: i64,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct DocumentChunkAppended {
+    pub form_id: String,
+    pub uploader: Pubkey,
+    pub offset: u32,
+    pub len: u32,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct ConsentRecorded {
+    pub form_id: String,
+    pub signer: Pubkey,
+    pub disclosure_hash: [u8; 32],
+    pub consented_at: i64,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct SearchKeyAdded {
+    pub key_hash: [u8; 32],
+    pub form_id: String,
+    pub approval: Pubkey,
+    pub created_by: Pubkey,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct SearchKeyRemoved {
+    pub key_hash: [u8; 32],
+    pub form_id: String,
+    pub removed_by: Pubkey,
+    pub event_sequence: u64,
+}
+
+#[event]
+pub struct AdminInvitationCancelled {
+    pub invitee: Pubkey,
+    pub cancelled_by: Pubkey,
+    pub event_sequence: u64,
 }
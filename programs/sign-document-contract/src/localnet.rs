@@ -0,0 +1,68 @@
+//! Test-only instructions for local validators.
+//!
+//! Compiled only with the `localnet` feature, so none of these accounts or
+//! instructions exist in production builds.
+
+use anchor_lang::prelude::*;
+
+use crate::config::{Config, FormApprovalError};
+use crate::state::AdminConfig;
+
+/// Seed for the localnet clock offset PDA
+pub const LOCALNET_CLOCK_SEED: &[u8] = b"localnet_clock";
+
+/// Offset added to the cluster clock by time-dependent checks
+#[account]
+pub struct LocalnetClock {
+    /// Seconds added to `Clock::unix_timestamp`
+    pub offset: i64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl LocalnetClock {
+    /// Calculate the space required for the account
+    pub const fn space() -> usize {
+        8 + // discriminator
+        8 + // offset (i64)
+        1 // bump (u8)
+    }
+}
+
+/// Context for shifting the clock seen by time-dependent checks
+#[derive(Accounts)]
+pub struct ForceSetClockOffset<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = LocalnetClock::space(),
+        seeds = [LOCALNET_CLOCK_SEED],
+        bump
+    )]
+    pub localnet_clock: Account<'info, LocalnetClock>,
+    
+    #[account(
+        seeds = [Config::ADMIN_CONFIG_SEED],
+        bump = admin_config.bump,
+        constraint = admin_config.authority == authority.key() @ FormApprovalError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Current time shifted by the localnet clock passed among `remaining_accounts`, if any
+pub fn now(remaining_accounts: &[AccountInfo]) -> Result<i64> {
+    let now = Clock::get()?.unix_timestamp;
+    let (clock_address, _) = Pubkey::find_program_address(&[LOCALNET_CLOCK_SEED], &crate::ID);
+    
+    let Some(account_info) = remaining_accounts.iter().find(|account| account.key() == clock_address) else {
+        return Ok(now);
+    };
+    let clock = LocalnetClock::try_deserialize(&mut &account_info.try_borrow_data()?[..])?;
+    Ok(now.saturating_add(clock.offset))
+}
@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+/// Domain separator for leaf hashes
+const LEAF_PREFIX: &[u8] = &[0];
+
+/// Domain separator for internal node hashes
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Domain separator for approval chain links
+const CHAIN_PREFIX: &[u8] = &[2];
+
+/// Hash an approval into a checkpoint leaf
+///
+/// Leaves of a checkpoint are ordered by `approved_at`, then by approval address.
+pub fn approval_leaf(
+    approval_address: &Pubkey,
+    form_hash: &[u8; 32],
+    signer: &Pubkey,
+    approved_at: i64,
+    approved_slot: u64,
+) -> [u8; 32] {
+    hashv(&[
+        LEAF_PREFIX,
+        approval_address.as_ref(),
+        form_hash,
+        signer.as_ref(),
+        &approved_at.to_le_bytes(),
+        &approved_slot.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Hash a document page into a leaf of the document's page tree
+///
+/// The page index is part of the leaf, since sorted pairs do not record positions.
+pub fn page_leaf(index: u32, page_hash: &[u8; 32]) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, &index.to_le_bytes(), page_hash]).to_bytes()
+}
+
+/// Hash a key into a leaf of a bulk admin import tree
+pub fn admin_leaf(admin: &Pubkey) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, admin.as_ref()]).to_bytes()
+}
+
+/// Hash a change to an approval into a leaf of its history chain
+pub fn history_leaf(action: u8, actor: &Pubkey, at: i64, summary_hash: &[u8; 32]) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, &[action], actor.as_ref(), &at.to_le_bytes(), summary_hash]).to_bytes()
+}
+
+/// Extend the approval hash chain with an approval leaf
+pub fn chain_link(prev: &[u8; 32], leaf: &[u8; 32]) -> [u8; 32] {
+    hashv(&[CHAIN_PREFIX, prev, leaf]).to_bytes()
+}
+
+/// Hash two sibling nodes, ordering them so proofs need no direction bits
+pub fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[NODE_PREFIX, left, right]).to_bytes()
+}
+
+/// Compute the root over a list of leaves (odd nodes are promoted unchanged)
+pub fn compute_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    if leaves.is_empty() {
+        return [0u8; 32];
+    }
+    
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [a, b] => hash_pair(a, b),
+                [a] => *a,
+                _ => unreachable!(),
+            })
+            .collect();
+    }
+    level[0]
+}
+
+/// Build the sibling path proving the leaf at `index`
+pub fn compute_proof(leaves: &[[u8; 32]], mut index: usize) -> Vec<[u8; 32]> {
+    let mut proof = Vec::new();
+    let mut level = leaves.to_vec();
+    
+    while level.len() > 1 {
+        let sibling = index ^ 1;
+        if sibling < level.len() {
+            proof.push(level[sibling]);
+        }
+        level = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [a, b] => hash_pair(a, b),
+                [a] => *a,
+                _ => unreachable!(),
+            })
+            .collect();
+        index /= 2;
+    }
+    proof
+}
+
+/// Compute the document root over page hashes, in page order, to sign as the form hash
+pub fn document_root(page_hashes: &[[u8; 32]]) -> [u8; 32] {
+    compute_root(&page_leaves(page_hashes))
+}
+
+/// Build the sibling path proving the page at `index` belongs to the document root
+pub fn page_proof(page_hashes: &[[u8; 32]], index: usize) -> Vec<[u8; 32]> {
+    compute_proof(&page_leaves(page_hashes), index)
+}
+
+fn page_leaves(page_hashes: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    page_hashes
+        .iter()
+        .enumerate()
+        .map(|(index, page_hash)| page_leaf(index as u32, page_hash))
+        .collect()
+}
+
+/// Check that a leaf belongs to the tree with the given root
+pub fn verify_proof(leaf: &[u8; 32], proof: &[[u8; 32]], root: &[u8; 32]) -> bool {
+    let computed = proof
+        .iter()
+        .fold(*leaf, |node, sibling| hash_pair(&node, sibling));
+    computed == *root
+}
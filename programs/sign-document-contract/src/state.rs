@@ -20,6 +20,129 @@ pub struct FormApproval {
     
     /// Bump seed for PDA derivation
     pub bump: u8,
+    
+    /// Program version the approval was created under
+    pub program_version: SemVer,
+    
+    /// Slot in which the approval was recorded
+    pub approved_slot: u64,
+    
+    /// Program that created the approval via CPI (default for direct signatures)
+    pub cpi_program: Pubkey,
+    
+    /// Time the approval was revoked (0 while in force)
+    pub revoked_at: i64,
+    
+    /// Number of times the form was re-approved after a revocation
+    pub revision: u32,
+    
+    /// Standardized reason recorded with the signature
+    pub reason_code: ReasonCode,
+    
+    /// Hash of an external timestamp authority token over the document (zero if none)
+    pub timestamp_token_hash: [u8; 32],
+    
+    /// Registered application that submitted the signature (default if none)
+    pub app_id: Pubkey,
+    
+    /// Template version the form was filled from (default if none)
+    pub template_version: Pubkey,
+    
+    /// BCP-47 tag of the language version that was signed (empty if unspecified)
+    pub locale: String,
+    
+    /// ISO 3166 code of the jurisdiction the signature was executed in (empty if unspecified)
+    pub jurisdiction: String,
+    
+    /// E-signature compliance evidence captured at signing
+    pub compliance: ComplianceBlock,
+    
+    /// Reason given when the approval was revoked (empty while in force)
+    pub revocation_reason: String,
+    
+    /// Key that revoked the approval (default while in force)
+    pub revoked_by: Pubkey,
+    
+    /// Approval chain head before this approval was linked (zero for the first link)
+    pub prev_chain_hash: [u8; 32],
+    
+    /// 1-based position of the approval in the approval chain (0 if unchained)
+    pub chain_index: u64,
+    
+    /// Signing time supplied by the client (0 if none), alongside the chain's `approved_at`
+    pub client_signed_at: i64,
+    
+    /// Lifecycle status of the approval
+    pub status: ApprovalStatus,
+    
+    /// Signatures, including the original signer's, needed before the approval is in force
+    pub required_signatures: u8,
+    
+    /// Admins who co-signed after the original signer
+    pub co_signers: Vec<Pubkey>,
+    
+    /// Time after which the approval no longer verifies (0 if it never expires)
+    pub expires_at: i64,
+    
+    /// Non-admin parties who may attach a counter-signature to the approval
+    pub counterparties: Vec<Pubkey>,
+    
+    /// Parties whose signatures the form calls for, tracked in `signatures_collected`
+    pub required_signers: Vec<Pubkey>,
+    
+    /// Bitmap of `required_signers` who have signed, bit `i` for entry `i`
+    pub signatures_collected: u16,
+    
+    /// Whether the approval was exported in an Arweave archive bundle
+    pub archived: bool,
+    
+    /// Whether the approval was imported from a previous system with its historical timestamps
+    pub imported: bool,
+    
+    /// Time from which further signatures are accepted (0 if unrestricted)
+    pub not_before: i64,
+    
+    /// Time after which further signatures are refused (0 if unrestricted)
+    pub not_after: i64,
+    
+    /// Whether the authority froze the approval against updates and further signatures
+    pub frozen: bool,
+    
+    /// Hash of the policy catalog in force when the approval was revoked (zero if none)
+    pub revocation_catalog_hash: [u8; 32],
+    
+    /// Approvals that must be in force before this one accepts signatures
+    pub dependencies: Vec<Pubkey>,
+    
+    /// Where the signed document can be retrieved, e.g. an IPFS, Arweave or HTTPS URI (empty if unspecified)
+    pub document_uri: String,
+    
+    /// MIME type of the signed document, e.g. `application/pdf` (empty if unspecified)
+    pub content_type: String,
+    
+    /// Digest algorithm `form_hash` was computed with
+    pub hash_algorithm: HashAlgorithm,
+    
+    /// Bytes 32 to 64 of a 64-byte digest, whose first 32 bytes are `form_hash` (zero for 32-byte digests)
+    pub form_hash_tail: [u8; 32],
+    
+    /// Whether the document's content was uploaded and hashed on-chain to `form_hash`
+    pub content_verified: bool,
+    
+    /// Pages covered when `form_hash` is a Merkle root over page hashes (0 for a plain document hash)
+    pub page_count: u32,
+    
+    /// Earlier approval already anchored over the same hash under another form ID (default if none)
+    pub duplicate_of: Pubkey,
+    
+    /// Organization whose namespace the form ID belongs to (default for the global namespace)
+    pub organization: Pubkey,
+    
+    /// Second organization one of whose admins must also sign before the approval is in force (default if none)
+    pub counterparty_org: Pubkey,
+    
+    /// Counter-party organization admin who signed (default until it signs)
+    pub counterparty_org_signer: Pubkey,
 }
 
 impl FormApproval {
@@ -31,7 +154,855 @@ impl FormApproval {
         32 + // signer (Pubkey)
         8 + // approved_at (i64)
         4 + metadata_len + // metadata (String)
-        1 // bump (u8)
+        1 + // bump (u8)
+        SemVer::SIZE + // program_version (SemVer)
+        8 + // approved_slot (u64)
+        32 + // cpi_program (Pubkey)
+        8 + // revoked_at (i64)
+        4 + // revision (u32)
+        1 + // reason_code (ReasonCode)
+        32 + // timestamp_token_hash ([u8; 32])
+        32 + // app_id (Pubkey)
+        32 + // template_version (Pubkey)
+        4 + crate::config::Config::MAX_LOCALE_LENGTH + // locale (String)
+        4 + crate::config::Config::MAX_JURISDICTION_LENGTH + // jurisdiction (String)
+        ComplianceBlock::SIZE + // compliance (ComplianceBlock)
+        4 + crate::config::Config::MAX_REVOCATION_REASON_LENGTH + // revocation_reason (String)
+        32 + // revoked_by (Pubkey)
+        32 + // prev_chain_hash ([u8; 32])
+        8 + // chain_index (u64)
+        8 + // client_signed_at (i64)
+        1 + // status (ApprovalStatus)
+        1 + // required_signatures (u8)
+        4 + (32 * (crate::config::Config::MAX_ADMINS - 1)) + // co_signers (Vec<Pubkey>)
+        8 + // expires_at (i64)
+        4 + (32 * crate::config::Config::MAX_COUNTERPARTIES) + // counterparties (Vec<Pubkey>)
+        4 + (32 * crate::config::Config::MAX_REQUIRED_SIGNERS) + // required_signers (Vec<Pubkey>)
+        2 + // signatures_collected (u16)
+        1 + // archived (bool)
+        1 + // imported (bool)
+        8 + // not_before (i64)
+        8 + // not_after (i64)
+        1 + // frozen (bool)
+        32 + // revocation_catalog_hash ([u8; 32])
+        4 + (32 * crate::config::Config::MAX_DEPENDENCIES) + // dependencies (Vec<Pubkey>)
+        4 + crate::config::Config::MAX_DOCUMENT_URI_LENGTH + // document_uri (String)
+        4 + crate::config::Config::MAX_CONTENT_TYPE_LENGTH + // content_type (String)
+        1 + // hash_algorithm (HashAlgorithm)
+        32 + // form_hash_tail ([u8; 32])
+        1 + // content_verified (bool)
+        4 + // page_count (u32)
+        32 + // duplicate_of (Pubkey)
+        32 + // organization (Pubkey)
+        32 + // counterparty_org (Pubkey)
+        32 // counterparty_org_signer (Pubkey)
+    }
+    
+    /// Whether the approval has been revoked
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at != 0
+    }
+    
+    /// Whether the approval still waits for an admin of its counter-party organization to sign
+    pub fn awaits_counterparty_org(&self) -> bool {
+        self.counterparty_org != Pubkey::default() && self.counterparty_org_signer == Pubkey::default()
+    }
+    
+    /// Validate a document URI and content type, either of which may be left empty
+    pub fn validate_document_location(document_uri: &str, content_type: &str) -> Result<()> {
+        require!(
+            document_uri.is_empty() || is_valid_document_uri(document_uri),
+            crate::config::FormApprovalError::InvalidDocumentUri
+        );
+        require!(
+            content_type.is_empty() || is_valid_content_type(content_type),
+            crate::config::FormApprovalError::InvalidContentType
+        );
+        Ok(())
+    }
+    
+    /// Validate the inputs of a new approval
+    pub fn validate_submission(
+        form_id: &str,
+        form_hash: &[u8; 32],
+        metadata: Option<&str>,
+    ) -> Result<()> {
+        require!(
+            form_id.len() <= crate::config::Config::MAX_FORM_ID_LENGTH,
+            crate::config::FormApprovalError::FormIdTooLong
+        );
+        
+        if let Some(meta) = metadata {
+            require!(
+                meta.len() <= crate::config::Config::MAX_METADATA_LENGTH,
+                crate::config::FormApprovalError::MetadataTooLong
+            );
+        }
+        
+        require!(
+            *form_hash != [0u8; 32],
+            crate::config::FormApprovalError::InvalidFormHash
+        );
+        Ok(())
+    }
+    
+    /// Fill in a freshly created approval
+    pub fn record(
+        &mut self,
+        form_id: String,
+        form_hash: [u8; 32],
+        signer: Pubkey,
+        metadata: String,
+        bump: u8,
+        clock: &Clock,
+    ) {
+        self.form_id = form_id;
+        self.form_hash = form_hash;
+        self.signer = signer;
+        self.approved_at = clock.unix_timestamp;
+        self.metadata = metadata;
+        self.bump = bump;
+        self.program_version = crate::config::Config::PROGRAM_VERSION;
+        self.approved_slot = clock.slot;
+        self.cpi_program = Pubkey::default();
+        self.revoked_at = 0;
+        self.revision = 0;
+        self.reason_code = ReasonCode::Approved;
+        self.timestamp_token_hash = [0u8; 32];
+        self.app_id = Pubkey::default();
+        self.template_version = Pubkey::default();
+        self.locale = String::new();
+        self.jurisdiction = String::new();
+        self.compliance = ComplianceBlock::default();
+        self.revocation_reason = String::new();
+        self.revoked_by = Pubkey::default();
+        self.prev_chain_hash = [0u8; 32];
+        self.chain_index = 0;
+        self.client_signed_at = 0;
+        self.expires_at = 0;
+        self.counterparties = Vec::new();
+        self.required_signers = Vec::new();
+        self.signatures_collected = 0;
+        self.archived = false;
+        self.imported = false;
+        self.not_before = 0;
+        self.not_after = 0;
+        self.frozen = false;
+        self.revocation_catalog_hash = [0u8; 32];
+        self.dependencies = Vec::new();
+        self.document_uri = String::new();
+        self.content_type = String::new();
+        self.hash_algorithm = HashAlgorithm::Sha256;
+        self.form_hash_tail = [0u8; 32];
+        self.content_verified = false;
+        self.page_count = 0;
+        self.duplicate_of = Pubkey::default();
+        self.organization = Pubkey::default();
+        self.counterparty_org = Pubkey::default();
+        self.counterparty_org_signer = Pubkey::default();
+        self.require_signatures(1);
+    }
+    
+    /// Require `required` signatures in total, discarding any co-signatures collected so far
+    pub fn require_signatures(&mut self, required: u8) {
+        self.required_signatures = required;
+        self.co_signers.clear();
+        self.status = if required > 1 {
+            ApprovalStatus::Pending
+        } else {
+            ApprovalStatus::Approved
+        };
+    }
+    
+    /// Move the approval to `next`, rejecting transitions the lifecycle does not allow
+    pub fn transition(&mut self, next: ApprovalStatus) -> Result<()> {
+        require!(
+            self.status.can_transition_to(next),
+            crate::config::FormApprovalError::InvalidStatusTransition
+        );
+        self.status = next;
+        Ok(())
+    }
+    
+    /// Add an admin's co-signature, putting the approval in force once the quorum is reached
+    pub fn co_sign(&mut self, co_signer: Pubkey) -> Result<()> {
+        require!(
+            self.status == ApprovalStatus::Pending,
+            crate::config::FormApprovalError::FormNotPending
+        );
+        require!(
+            co_signer != self.signer && !self.co_signers.contains(&co_signer),
+            crate::config::FormApprovalError::AlreadyCoSigned
+        );
+        
+        self.co_signers.push(co_signer);
+        self.mark_signed(&co_signer);
+        if self.signature_count() >= self.required_signatures as usize {
+            self.transition(ApprovalStatus::Approved)?;
+        }
+        Ok(())
+    }
+    
+    /// Record `signer`'s signature in the required-signers bitmap, if they are listed
+    pub fn mark_signed(&mut self, signer: &Pubkey) {
+        if let Some(position) = self.required_signers.iter().position(|required| required == signer) {
+            self.signatures_collected |= 1 << position;
+        }
+    }
+    
+    /// Required signers who have signed and those still outstanding
+    pub fn signing_progress(&self) -> SigningProgress {
+        let (signed, outstanding) = self
+            .required_signers
+            .iter()
+            .enumerate()
+            .partition::<Vec<_>, _>(|(position, _)| self.signatures_collected & (1 << position) != 0);
+        SigningProgress {
+            signed: signed.into_iter().map(|(_, signer)| *signer).collect(),
+            outstanding: outstanding.into_iter().map(|(_, signer)| *signer).collect(),
+        }
+    }
+    
+    /// The approval's identifying details, as returned by `get_form_approval_details`
+    pub fn details(&self) -> FormApprovalDetails {
+        FormApprovalDetails {
+            form_id: self.form_id.clone(),
+            form_hash: self.form_hash,
+            signer: self.signer,
+            approved_at: self.approved_at,
+            metadata: self.metadata.clone(),
+        }
+    }
+    
+    /// Signatures collected, including the original signer's
+    pub fn signature_count(&self) -> usize {
+        1 + self.co_signers.len()
+    }
+    
+    /// Check that further signatures are accepted at `now`
+    pub fn check_signing_window(&self, now: i64) -> Result<()> {
+        require!(
+            self.not_before == 0 || now >= self.not_before,
+            crate::config::FormApprovalError::SigningWindowNotOpen
+        );
+        require!(
+            self.not_after == 0 || now <= self.not_after,
+            crate::config::FormApprovalError::SigningWindowClosed
+        );
+        Ok(())
+    }
+    
+    /// Whether the approval's expiry has passed at `now`
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at != 0 && now >= self.expires_at
+    }
+    
+    /// Whether the approval is approved, not revoked and not expired at `now`, whatever hash it covers
+    pub fn is_in_force(&self, now: i64) -> bool {
+        !self.is_revoked() && !self.is_expired(now) && self.status == ApprovalStatus::Approved
+    }
+    
+    /// Whether the approval is in force at `now` and covers the SHA-256 `expected_hash`
+    ///
+    /// Approvals over other algorithms never match a bare 32-byte hash; check them with `is_valid_for_digest`.
+    pub fn is_valid_for(&self, expected_hash: &[u8; 32], now: i64) -> bool {
+        self.hash_algorithm == HashAlgorithm::Sha256
+            && self.form_hash == *expected_hash
+            && self.is_in_force(now)
+    }
+    
+    /// Full digest of the form, `form_hash` followed by the tail for 64-byte algorithms
+    pub fn digest(&self) -> Vec<u8> {
+        let mut digest = self.form_hash.to_vec();
+        if self.hash_algorithm.digest_len() == 64 {
+            digest.extend_from_slice(&self.form_hash_tail);
+        }
+        digest
+    }
+    
+    /// Whether the approval is in force at `now` and covers the full `expected_digest` computed with `algorithm`
+    pub fn is_valid_for_digest(&self, algorithm: HashAlgorithm, expected_digest: &[u8], now: i64) -> bool {
+        self.hash_algorithm == algorithm
+            && self.digest() == expected_digest
+            && self.is_in_force(now)
+    }
+    
+    /// Whether the approval is in force at `now` and its document root includes `page_hash` at `index`
+    pub fn covers_page(&self, index: u32, page_hash: &[u8; 32], proof: &[[u8; 32]], now: i64) -> bool {
+        index < self.page_count
+            && crate::merkle::verify_proof(&crate::merkle::page_leaf(index, page_hash), proof, &self.form_hash)
+            && self.is_in_force(now)
+    }
+    
+    /// Check whether `address` is this form's primary approval record
+    pub fn is_primary_record(&self, address: &Pubkey, form_id: &str) -> bool {
+        Pubkey::create_program_address(
+            &[crate::config::Config::FORM_APPROVAL_SEED, form_id.as_bytes(), &[self.bump]],
+            &crate::ID,
+        )
+        .is_ok_and(|expected| expected == *address)
+    }
+    
+    /// Check whether `address` is this form's approval record in its organization's namespace
+    pub fn is_org_record(&self, address: &Pubkey, form_id: &str) -> bool {
+        self.organization != Pubkey::default()
+            && Pubkey::create_program_address(
+                &[
+                    crate::config::Config::ORG_FORM_APPROVAL_SEED,
+                    self.organization.as_ref(),
+                    form_id.as_bytes(),
+                    &[self.bump],
+                ],
+                &crate::ID,
+            )
+            .is_ok_and(|expected| expected == *address)
+    }
+    
+    /// Check whether `address` is this signer's per-signer approval record for the form
+    pub fn is_signer_record(&self, address: &Pubkey, form_id: &str) -> bool {
+        Pubkey::create_program_address(
+            &[
+                crate::config::Config::SIGNER_APPROVAL_SEED,
+                form_id.as_bytes(),
+                self.signer.as_ref(),
+                &[self.bump],
+            ],
+            &crate::ID,
+        )
+        .is_ok_and(|expected| expected == *address)
+    }
+    
+    /// Leaf committing this approval into a checkpoint
+    pub fn checkpoint_leaf(&self, address: &Pubkey) -> [u8; 32] {
+        crate::merkle::approval_leaf(
+            address,
+            &self.form_hash,
+            &self.signer,
+            self.approved_at,
+            self.approved_slot,
+        )
+    }
+}
+
+/// Required signers of a form approval, split by whether they have signed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SigningProgress {
+    pub signed: Vec<Pubkey>,
+    pub outstanding: Vec<Pubkey>,
+}
+
+/// Identifying details of a form approval
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct FormApprovalDetails {
+    pub form_id: String,
+    pub form_hash: [u8; 32],
+    pub signer: Pubkey,
+    pub approved_at: i64,
+    pub metadata: String,
+}
+
+/// Lifecycle status of a form approval
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ApprovalStatus {
+    /// In force and verifiable
+    #[default]
+    Approved,
+    
+    /// Still collecting co-signatures
+    Pending,
+    
+    /// Reviewed and declined; final
+    Rejected,
+    
+    /// Withdrawn after approval; may be re-approved
+    Revoked,
+    
+    /// No longer in force because its validity lapsed; may be approved again
+    Expired,
+}
+
+impl ApprovalStatus {
+    /// Whether the lifecycle allows moving from this status to `next`
+    pub fn can_transition_to(self, next: ApprovalStatus) -> bool {
+        use ApprovalStatus::*;
+        matches!(
+            (self, next),
+            (Pending, Approved | Rejected | Revoked | Expired)
+                | (Approved, Revoked | Expired)
+                | (Revoked | Expired, Approved | Pending)
+        )
+    }
+}
+
+/// Layout of `FormApproval` accounts created before approvals were versioned
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct FormApprovalV1 {
+    pub form_id: String,
+    pub form_hash: [u8; 32],
+    pub signer: Pubkey,
+    pub approved_at: i64,
+    pub metadata: String,
+    pub bump: u8,
+}
+
+impl From<FormApprovalV1> for FormApproval {
+    fn from(legacy: FormApprovalV1) -> Self {
+        Self {
+            form_id: legacy.form_id,
+            form_hash: legacy.form_hash,
+            signer: legacy.signer,
+            approved_at: legacy.approved_at,
+            metadata: legacy.metadata,
+            bump: legacy.bump,
+            // 0.0.0 marks approvals created before versioning
+            program_version: SemVer::default(),
+            approved_slot: 0,
+            cpi_program: Pubkey::default(),
+            revoked_at: 0,
+            revision: 0,
+            reason_code: ReasonCode::Approved,
+            timestamp_token_hash: [0u8; 32],
+            app_id: Pubkey::default(),
+            template_version: Pubkey::default(),
+            locale: String::new(),
+            jurisdiction: String::new(),
+            compliance: ComplianceBlock::default(),
+            revocation_reason: String::new(),
+            revoked_by: Pubkey::default(),
+            prev_chain_hash: [0u8; 32],
+            chain_index: 0,
+            client_signed_at: 0,
+            status: ApprovalStatus::Approved,
+            required_signatures: 1,
+            co_signers: Vec::new(),
+            expires_at: 0,
+            counterparties: Vec::new(),
+            required_signers: Vec::new(),
+            signatures_collected: 0,
+            archived: false,
+            imported: false,
+            not_before: 0,
+            not_after: 0,
+            frozen: false,
+            revocation_catalog_hash: [0u8; 32],
+            dependencies: Vec::new(),
+            document_uri: String::new(),
+            content_type: String::new(),
+            hash_algorithm: HashAlgorithm::Sha256,
+            form_hash_tail: [0u8; 32],
+            content_verified: false,
+            page_count: 0,
+            duplicate_of: Pubkey::default(),
+            organization: Pubkey::default(),
+            counterparty_org: Pubkey::default(),
+            counterparty_org_signer: Pubkey::default(),
+        }
+    }
+}
+
+/// Standardized reason attached to a signature or rejection
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReasonCode {
+    Approved,
+    ApprovedWithChanges,
+    ApprovedConditionally,
+    RejectedIncomplete,
+    RejectedPolicy,
+    RejectedInvalidDocument,
+    RejectedOther,
+}
+
+impl ReasonCode {
+    /// Whether the code may accompany an approval
+    pub fn is_approval(&self) -> bool {
+        matches!(
+            self,
+            ReasonCode::Approved | ReasonCode::ApprovedWithChanges | ReasonCode::ApprovedConditionally
+        )
+    }
+}
+
+/// Digest algorithm the form hash was computed with
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HashAlgorithm {
+    #[default]
+    Sha256,
+    Keccak256,
+    Blake3,
+    Sha512,
+}
+
+impl HashAlgorithm {
+    /// Length in bytes of the algorithm's digests
+    pub fn digest_len(&self) -> usize {
+        match self {
+            HashAlgorithm::Sha512 => 64,
+            _ => 32,
+        }
+    }
+}
+
+/// Cluster or chain an approval was mirrored to
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MirrorLocation {
+    SolanaMainnet,
+    SolanaDevnet,
+    SolanaTestnet,
+    Ethereum,
+    Polygon,
+    Arweave,
+    Ipfs,
+    Other,
+}
+
+/// How the signer was authenticated before signing
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AuthenticationMethod {
+    #[default]
+    Unspecified,
+    WalletSignature,
+    Password,
+    OneTimeCode,
+    Biometric,
+    QualifiedCertificate,
+}
+
+/// Evidence auditors expect under e-signature regulations such as eIDAS and ESIGN
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ComplianceBlock {
+    /// Whether the signer consented to signing electronically
+    pub consent_to_electronic_signature: bool,
+    
+    /// SHA-256 hash of the statement of intent to sign shown to the signer
+    pub intent_statement_hash: [u8; 32],
+    
+    /// How the signer was authenticated
+    pub authentication_method: AuthenticationMethod,
+}
+
+impl ComplianceBlock {
+    /// Serialized size in bytes
+    pub const SIZE: usize = 1 + 32 + 1;
+    
+    /// Check that a supplied block records consent and an intent statement
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.consent_to_electronic_signature,
+            crate::config::FormApprovalError::ConsentRequired
+        );
+        require!(
+            self.intent_statement_hash != [0u8; 32],
+            crate::config::FormApprovalError::InvalidIntentStatement
+        );
+        Ok(())
+    }
+}
+
+/// Counter values from a previous health report, which the current state must not fall below
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct InvariantBaseline {
+    pub config_sequence: u64,
+    pub checkpoint_count: u64,
+}
+
+/// Whether no key appears twice in `keys`
+fn is_distinct(keys: &[Pubkey]) -> bool {
+    keys.iter().enumerate().all(|(i, key)| !keys[..i].contains(key))
+}
+
+/// Check the shape of a BCP-47 tag: a 2-8 letter language subtag followed by 1-8 character alphanumeric subtags
+pub fn is_valid_locale(tag: &str) -> bool {
+    if tag.len() > crate::config::Config::MAX_LOCALE_LENGTH {
+        return false;
+    }
+    
+    let mut subtags = tag.split('-');
+    let language_ok = subtags
+        .next()
+        .is_some_and(|language| (2..=8).contains(&language.len()) && language.bytes().all(|b| b.is_ascii_alphabetic()));
+    
+    language_ok
+        && subtags.all(|subtag| (1..=8).contains(&subtag.len()) && subtag.bytes().all(|b| b.is_ascii_alphanumeric()))
+}
+
+/// Check the shape of an ISO 3166 code: an alpha-2 country, optionally followed by a 1-3 character subdivision
+pub fn is_valid_jurisdiction(code: &str) -> bool {
+    let (country, subdivision) = match code.split_once('-') {
+        Some((country, subdivision)) => (country, Some(subdivision)),
+        None => (code, None),
+    };
+    
+    let country_ok = country.len() == 2 && country.bytes().all(|b| b.is_ascii_uppercase());
+    let subdivision_ok = match subdivision {
+        Some(subdivision) => {
+            (1..=3).contains(&subdivision.len())
+                && subdivision.bytes().all(|b| b.is_ascii_uppercase() || b.is_ascii_digit())
+        }
+        None => true,
+    };
+    
+    country_ok && subdivision_ok
+}
+
+/// Check the shape of a document URI: an allowed scheme followed by a non-empty location without whitespace
+pub fn is_valid_document_uri(uri: &str) -> bool {
+    uri.len() <= crate::config::Config::MAX_DOCUMENT_URI_LENGTH
+        && crate::config::Config::DOCUMENT_URI_SCHEMES.iter().any(|scheme| {
+            uri.strip_prefix(scheme)
+                .is_some_and(|location| !location.is_empty() && location.bytes().all(|b| b.is_ascii_graphic()))
+        })
+}
+
+/// Check the shape of a MIME type: `type/subtype`, each made of RFC 6838 name characters
+pub fn is_valid_content_type(content_type: &str) -> bool {
+    let is_name = |name: &str| {
+        !name.is_empty()
+            && name
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b"!#$&-^_.+".contains(&b))
+    };
+    
+    content_type.len() <= crate::config::Config::MAX_CONTENT_TYPE_LENGTH
+        && content_type
+            .split_once('/')
+            .is_some_and(|(kind, subtype)| is_name(kind) && is_name(subtype))
+}
+
+/// Optional arguments accepted when signing a form
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct SignOptions {
+    /// Version of the signing client, required while a minimum is configured
+    pub client_version: Option<SemVer>,
+    
+    /// Reason recorded with the signature (defaults to `Approved`)
+    pub reason_code: Option<ReasonCode>,
+    
+    /// Hash of an RFC 3161 timestamp authority token binding an external timestamp to the record
+    pub timestamp_token_hash: Option<[u8; 32]>,
+    
+    /// BCP-47 tag of the language version being signed
+    pub locale: Option<String>,
+    
+    /// ISO 3166 code of the jurisdiction the signature is executed in
+    pub jurisdiction: Option<String>,
+    
+    /// E-signature compliance evidence to record with the signature
+    pub compliance: Option<ComplianceBlock>,
+    
+    /// Time the client considers the document signed, for legal "signed at" semantics
+    pub client_timestamp: Option<i64>,
+    
+    /// Signatures, including this one, needed before the approval is in force (defaults to 1)
+    pub required_signatures: Option<u8>,
+    
+    /// Time after which the approval no longer verifies (never, if unset)
+    pub expires_at: Option<i64>,
+    
+    /// Non-admin parties who may counter-sign the approval
+    pub counterparties: Option<Vec<Pubkey>>,
+    
+    /// Parties whose signatures the form calls for, so progress can be reported
+    pub required_signers: Option<Vec<Pubkey>>,
+    
+    /// Time from which further signatures are accepted (unrestricted, if unset)
+    pub not_before: Option<i64>,
+    
+    /// Time after which further signatures are refused (unrestricted, if unset)
+    pub not_after: Option<i64>,
+    
+    /// Approvals that must be in force before the request accepts signatures
+    pub depends_on: Option<Vec<Pubkey>>,
+    
+    /// Where the signed document can be retrieved (IPFS, Arweave or HTTPS)
+    pub document_uri: Option<String>,
+    
+    /// MIME type of the signed document
+    pub content_type: Option<String>,
+    
+    /// Digest algorithm the form hash was computed with (defaults to `Sha256`)
+    pub hash_algorithm: Option<HashAlgorithm>,
+    
+    /// Bytes 32 to 64 of a 64-byte digest, required exactly when the algorithm produces one
+    pub form_hash_tail: Option<[u8; 32]>,
+    
+    /// Pages covered when the form hash is a Merkle root over page hashes
+    pub page_count: Option<u32>,
+    
+    /// Sign even if the hash is already anchored under another form ID, when duplicates are rejected
+    pub allow_duplicate_hash: Option<bool>,
+}
+
+impl SignOptions {
+    /// Reason to record, rejecting codes that do not describe an approval
+    pub fn approval_reason(&self) -> Result<ReasonCode> {
+        let reason_code = self.reason_code.unwrap_or(ReasonCode::Approved);
+        require!(
+            reason_code.is_approval(),
+            crate::config::FormApprovalError::InvalidReasonCode
+        );
+        Ok(reason_code)
+    }
+    
+    /// Locale to record, empty when none was supplied
+    pub fn locale(&self) -> Result<String> {
+        match &self.locale {
+            Some(locale) => {
+                require!(is_valid_locale(locale), crate::config::FormApprovalError::InvalidLocale);
+                Ok(locale.clone())
+            }
+            None => Ok(String::new()),
+        }
+    }
+    
+    /// Jurisdiction to record, empty when none was supplied
+    pub fn jurisdiction(&self) -> Result<String> {
+        match &self.jurisdiction {
+            Some(jurisdiction) => {
+                require!(
+                    is_valid_jurisdiction(jurisdiction),
+                    crate::config::FormApprovalError::InvalidJurisdiction
+                );
+                Ok(jurisdiction.clone())
+            }
+            None => Ok(String::new()),
+        }
+    }
+    
+    /// Digest algorithm and hash tail to record, checking the tail against the algorithm's digest length
+    pub fn digest(&self) -> Result<(HashAlgorithm, [u8; 32])> {
+        let algorithm = self.hash_algorithm.unwrap_or_default();
+        require!(
+            self.form_hash_tail.is_some() == (algorithm.digest_len() == 64),
+            crate::config::FormApprovalError::InvalidDigestLength
+        );
+        Ok((algorithm, self.form_hash_tail.unwrap_or([0u8; 32])))
+    }
+    
+    /// Page count to record, zero for a plain document hash; page roots are always SHA-256 trees
+    pub fn page_count(&self, algorithm: HashAlgorithm) -> Result<u32> {
+        match self.page_count {
+            Some(page_count) => {
+                require!(
+                    (1..=crate::config::Config::MAX_DOCUMENT_PAGES).contains(&page_count)
+                        && algorithm == HashAlgorithm::Sha256,
+                    crate::config::FormApprovalError::InvalidPageCount
+                );
+                Ok(page_count)
+            }
+            None => Ok(0),
+        }
+    }
+    
+    /// Document URI and content type to record, each empty when none was supplied
+    pub fn document_location(&self) -> Result<(String, String)> {
+        let document_uri = self.document_uri.clone().unwrap_or_default();
+        let content_type = self.content_type.clone().unwrap_or_default();
+        FormApproval::validate_document_location(&document_uri, &content_type)?;
+        Ok((document_uri, content_type))
+    }
+    
+    /// Compliance block to record, empty when none was supplied
+    pub fn compliance(&self) -> Result<ComplianceBlock> {
+        match self.compliance {
+            Some(compliance) => {
+                compliance.validate()?;
+                Ok(compliance)
+            }
+            None => Ok(ComplianceBlock::default()),
+        }
+    }
+    
+    /// Signature quorum to require, within the per-form signer limit
+    pub fn signature_quorum(&self, max_signers_per_form: u8) -> Result<u8> {
+        let required = self.required_signatures.unwrap_or(1);
+        require!(
+            required >= 1 && required <= max_signers_per_form,
+            crate::config::FormApprovalError::InvalidRequiredSignatures
+        );
+        Ok(required)
+    }
+    
+    /// Client signing time to record, zero when none was supplied
+    pub fn client_signed_at(&self, now: i64, max_clock_skew: i64) -> Result<i64> {
+        match self.client_timestamp {
+            Some(timestamp) => {
+                require!(
+                    timestamp > 0 && timestamp.abs_diff(now) <= max_clock_skew.unsigned_abs(),
+                    crate::config::FormApprovalError::ClientTimestampOutOfRange
+                );
+                Ok(timestamp)
+            }
+            None => Ok(0),
+        }
+    }
+    
+    /// Counterparties to record, empty when none were supplied
+    pub fn counterparties(&self) -> Result<Vec<Pubkey>> {
+        let counterparties = self.counterparties.clone().unwrap_or_default();
+        require!(
+            counterparties.len() <= crate::config::Config::MAX_COUNTERPARTIES && is_distinct(&counterparties),
+            crate::config::FormApprovalError::InvalidCounterparties
+        );
+        Ok(counterparties)
+    }
+    
+    /// Required signers to record, empty when none were supplied
+    pub fn required_signers(&self) -> Result<Vec<Pubkey>> {
+        let required_signers = self.required_signers.clone().unwrap_or_default();
+        require!(
+            required_signers.len() <= crate::config::Config::MAX_REQUIRED_SIGNERS
+                && is_distinct(&required_signers),
+            crate::config::FormApprovalError::InvalidRequiredSigners
+        );
+        Ok(required_signers)
+    }
+    
+    /// Dependencies to record, empty when none were supplied; `approval` cannot depend on itself
+    pub fn dependencies(&self, approval: &Pubkey) -> Result<Vec<Pubkey>> {
+        let dependencies = self.depends_on.clone().unwrap_or_default();
+        require!(
+            dependencies.len() <= crate::config::Config::MAX_DEPENDENCIES
+                && is_distinct(&dependencies)
+                && !dependencies.contains(approval),
+            crate::config::FormApprovalError::InvalidDependencies
+        );
+        Ok(dependencies)
+    }
+    
+    /// Expiry to record, zero when none was supplied
+    pub fn expires_at(&self, now: i64) -> Result<i64> {
+        match self.expires_at {
+            Some(expires_at) => {
+                require!(expires_at > now, crate::config::FormApprovalError::InvalidExpiry);
+                Ok(expires_at)
+            }
+            None => Ok(0),
+        }
+    }
+    
+    /// Signing window to record as `(not_before, not_after)`, zero for unset bounds
+    pub fn signing_window(&self, now: i64) -> Result<(i64, i64)> {
+        let not_before = self.not_before.unwrap_or(0);
+        let not_after = self.not_after.unwrap_or(0);
+        require!(
+            not_before >= 0 && (not_after == 0 || (not_after > now && not_after > not_before)),
+            crate::config::FormApprovalError::InvalidSigningWindow
+        );
+        Ok((not_before, not_after))
+    }
+    
+    /// Timestamp token hash to record, zero when none was supplied
+    pub fn timestamp_token_hash(&self) -> Result<[u8; 32]> {
+        match self.timestamp_token_hash {
+            Some(hash) => {
+                require!(
+                    hash != [0u8; 32],
+                    crate::config::FormApprovalError::InvalidTimestampToken
+                );
+                Ok(hash)
+            }
+            None => Ok([0u8; 32]),
+        }
     }
 }
 
@@ -49,31 +1020,243 @@ pub struct AdminConfig {
     
     /// Bump seed for PDA derivation
     pub bump: u8,
+    
+    /// Signature weight of each admin, parallel to `admins`
+    pub weights: [u16; 10],
+    
+    /// Permission flags of each admin, parallel to `admins`
+    pub permissions: [u8; 10],
+    
+    /// Key proposed as the next authority, until it accepts (default if none)
+    pub pending_authority: Pubkey,
+    
+    /// Time each admin's rights lapse, parallel to `admins` (0 never expires)
+    pub expires_at: [i64; 10],
+    
+    /// Number of admins registered as `AdminRecord` accounts beyond `admins`
+    pub record_count: u32,
+    
+    /// Forms each admin signed with `sign_form_submission`, parallel to `admins`
+    pub signatures_count: [u32; 10],
+    
+    /// Time each admin last signed a form, parallel to `admins` (0 if never)
+    pub last_signed_at: [i64; 10],
 }
 
 impl AdminConfig {
+    /// May sign, co-sign, reapprove and reject form submissions
+    pub const CAN_SIGN: u8 = 1 << 0;
+    
+    /// May update and renew approvals
+    pub const CAN_UPDATE: u8 = 1 << 1;
+    
+    /// May revoke approvals and move them to Rejected or Expired
+    pub const CAN_REVOKE: u8 = 1 << 2;
+    
+    /// May add, invite, replace, reweight and remove admins
+    pub const CAN_MANAGE_ADMINS: u8 = 1 << 3;
+    
+    /// Permissions of a newly added admin
+    pub const DEFAULT_PERMISSIONS: u8 = Self::CAN_SIGN | Self::CAN_UPDATE | Self::CAN_REVOKE;
+    
+    /// Every defined permission flag
+    pub const ALL_PERMISSIONS: u8 = Self::DEFAULT_PERMISSIONS | Self::CAN_MANAGE_ADMINS;
+    
+    /// Authority recorded once it is renounced; no one holds its private key
+    pub const RENOUNCED_AUTHORITY: Pubkey = Pubkey::new_from_array([0u8; 32]);
+    
     /// Calculate the space required for the account
     pub const fn space() -> usize {
         8 + // discriminator
         (32 * 10) + // admins ([Pubkey; 10])
         1 + // admin_count (u8)
         32 + // authority (Pubkey)
-        1 // bump (u8)
+        1 + // bump (u8)
+        (2 * 10) + // weights ([u16; 10])
+        10 + // permissions ([u8; 10])
+        32 + // pending_authority (Pubkey)
+        (8 * 10) + // expires_at ([i64; 10])
+        4 + // record_count (u32)
+        (4 * 10) + // signatures_count ([u32; 10])
+        (8 * 10) // last_signed_at ([i64; 10])
     }
     
-    /// Check if a public key is an authorized admin
-    pub fn is_admin(&self, pubkey: &Pubkey) -> bool {
+    /// Current time from the clock sysvar; expiry is not enforced where it is unavailable (off-chain)
+    fn now() -> i64 {
+        Clock::get().map(|clock| clock.unix_timestamp).unwrap_or(i64::MIN)
+    }
+    
+    /// Slot of an admin, including one whose rights have lapsed
+    pub fn slot_of(&self, pubkey: &Pubkey) -> Option<usize> {
+        self.admins[..self.admin_count as usize]
+            .iter()
+            .position(|admin| admin == pubkey)
+    }
+    
+    /// Slot of an admin whose rights have not lapsed at `now`
+    fn active_slot(&self, pubkey: &Pubkey, now: i64) -> Option<usize> {
+        self.slot_of(pubkey)
+            .filter(|i| self.expires_at[*i] == 0 || now < self.expires_at[*i])
+    }
+    
+    /// Whether an admin holds every flag in `permission` (false for non-admins and lapsed admins)
+    pub fn has_permission(&self, pubkey: &Pubkey, permission: u8) -> bool {
+        self.active_slot(pubkey, Self::now())
+            .is_some_and(|i| self.permissions[i] & permission == permission)
+    }
+    
+    /// Whether a key is an admin, either in `admins` or through its `AdminRecord`
+    pub fn is_admin_with_record(&self, pubkey: &Pubkey, record: Option<&AdminRecord>) -> bool {
+        self.is_admin(pubkey) || record.is_some_and(|record| record.admin == *pubkey && record.is_active(Self::now()))
+    }
+    
+    /// Whether a key holds every flag in `permission`, either in `admins` or through its `AdminRecord`
+    pub fn has_permission_with_record(&self, pubkey: &Pubkey, record: Option<&AdminRecord>, permission: u8) -> bool {
+        self.has_permission(pubkey, permission)
+            || record.is_some_and(|record| {
+                record.admin == *pubkey
+                    && record.is_active(Self::now())
+                    && record.permissions & permission == permission
+            })
+    }
+    
+    /// Set the time an existing admin's rights lapse (0 never expires)
+    pub fn set_expiry(&mut self, admin: &Pubkey, expires_at: i64) -> Result<()> {
+        require!(!self.is_renounced(), crate::config::FormApprovalError::AuthorityRenounced);
+        require!(
+            expires_at == 0 || expires_at > Self::now(),
+            crate::config::FormApprovalError::InvalidAdminExpiry
+        );
+        
+        let index = self.slot_of(admin).ok_or(crate::config::FormApprovalError::AdminNotFound)?;
+        self.expires_at[index] = expires_at;
+        Ok(())
+    }
+    
+    /// Whether a key may manage admins: the authority, or an admin with `CAN_MANAGE_ADMINS`
+    pub fn can_manage_admins(&self, pubkey: &Pubkey) -> bool {
+        !self.is_renounced()
+            && (self.authority == *pubkey || self.has_permission(pubkey, Self::CAN_MANAGE_ADMINS))
+    }
+    
+    /// Whether a key may manage admins, also granting `CAN_MANAGE_ADMINS` through its `AdminRecord`
+    pub fn can_manage_admins_with_record(&self, pubkey: &Pubkey, record: Option<&AdminRecord>) -> bool {
+        !self.is_renounced()
+            && (self.authority == *pubkey
+                || self.has_permission_with_record(pubkey, record, Self::CAN_MANAGE_ADMINS))
+    }
+    
+    /// Whether the authority was renounced, freezing the admin set
+    pub fn is_renounced(&self) -> bool {
+        self.authority == Self::RENOUNCED_AUTHORITY
+    }
+    
+    /// Replace the permission flags of an existing admin
+    pub fn set_permissions(&mut self, admin: &Pubkey, permissions: u8) -> Result<()> {
+        require!(!self.is_renounced(), crate::config::FormApprovalError::AuthorityRenounced);
+        require!(
+            permissions & !Self::ALL_PERMISSIONS == 0,
+            crate::config::FormApprovalError::InvalidPermissions
+        );
+        
+        for i in 0..self.admin_count as usize {
+            if self.admins[i] == *admin {
+                self.permissions[i] = permissions;
+                return Ok(());
+            }
+        }
+        Err(crate::config::FormApprovalError::AdminNotFound.into())
+    }
+    
+    /// Signature weight of an admin (0 for non-admins and lapsed admins)
+    pub fn weight_of(&self, pubkey: &Pubkey) -> u16 {
+        self.active_slot(pubkey, Self::now())
+            .map_or(0, |i| self.weights[i])
+    }
+    
+    /// Signature weight of an admin in `admins`, or 1 for an active `AdminRecord`
+    pub fn weight_of_with_record(&self, pubkey: &Pubkey, record: Option<&AdminRecord>) -> u16 {
+        match self.weight_of(pubkey) {
+            0 if record.is_some_and(|record| record.admin == *pubkey && record.is_active(Self::now())) => 1,
+            weight => weight,
+        }
+    }
+    
+    /// Combined weight of every admin, counting each registered record as 1
+    pub fn total_weight(&self) -> u32 {
+        self.weights[..self.admin_count as usize]
+            .iter()
+            .map(|weight| *weight as u32)
+            .sum::<u32>()
+            + self.record_count
+    }
+    
+    /// Change the signature weight of an existing admin
+    pub fn set_weight(&mut self, admin: &Pubkey, weight: u16) -> Result<()> {
+        require!(!self.is_renounced(), crate::config::FormApprovalError::AuthorityRenounced);
+        require!(weight >= 1, crate::config::FormApprovalError::InvalidAdminWeight);
+        
         for i in 0..self.admin_count as usize {
-            if self.admins[i] == *pubkey {
-                return true;
+            if self.admins[i] == *admin {
+                self.weights[i] = weight;
+                return Ok(());
             }
         }
-        false
+        Err(crate::config::FormApprovalError::AdminNotFound.into())
+    }
+    
+    /// Count a form signed by `admin` at `now`, on its slot or else on its record
+    pub fn record_signature(&mut self, admin: &Pubkey, record: Option<&mut AdminRecord>, now: i64) {
+        if let Some(i) = self.slot_of(admin) {
+            self.signatures_count[i] += 1;
+            self.last_signed_at[i] = now;
+        } else if let Some(record) = record {
+            record.signatures_count += 1;
+            record.last_signed_at = now;
+        }
+    }
+    
+    /// Check that the active entries are distinct non-default keys with a weight, and the rest are cleared
+    pub fn is_consistent(&self) -> bool {
+        let count = self.admin_count as usize;
+        if count == 0 || count > crate::config::Config::MAX_ADMINS {
+            return false;
+        }
+        
+        let active = &self.admins[..count];
+        let active_ok = active.iter().enumerate().all(|(i, admin)| {
+            *admin != Pubkey::default()
+                && !active[..i].contains(admin)
+                && self.weights[i] >= 1
+                && self.permissions[i] & !Self::ALL_PERMISSIONS == 0
+        });
+        let cleared_ok = self.admins[count..].iter().all(|admin| *admin == Pubkey::default())
+            && self.weights[count..].iter().all(|weight| *weight == 0)
+            && self.permissions[count..].iter().all(|permissions| *permissions == 0)
+            && self.expires_at[count..].iter().all(|expires_at| *expires_at == 0)
+            && self.signatures_count[count..].iter().all(|signatures| *signatures == 0)
+            && self.last_signed_at[count..].iter().all(|last_signed_at| *last_signed_at == 0);
+        
+        active_ok && cleared_ok
+    }
+    
+    /// Check if a public key is an authorized admin whose rights have not lapsed
+    pub fn is_admin(&self, pubkey: &Pubkey) -> bool {
+        self.is_admin_at(pubkey, Self::now())
+    }
+    
+    /// Check if a public key is an authorized admin at `now`, e.g. for off-chain callers
+    pub fn is_admin_at(&self, pubkey: &Pubkey, now: i64) -> bool {
+        self.active_slot(pubkey, now).is_some()
     }
     
     /// Add a new admin if not already present
     pub fn add_admin(&mut self, admin: Pubkey) -> Result<()> {
-        if self.is_admin(&admin) {
+        if self.is_renounced() {
+            return Err(crate::config::FormApprovalError::AuthorityRenounced.into());
+        }
+        
+        if self.slot_of(&admin).is_some() {
             return Err(crate::config::FormApprovalError::AdminAlreadyExists.into());
         }
         
@@ -82,12 +1265,42 @@ impl AdminConfig {
         }
         
         self.admins[self.admin_count as usize] = admin;
+        self.weights[self.admin_count as usize] = 1;
+        self.permissions[self.admin_count as usize] = Self::DEFAULT_PERMISSIONS;
+        self.expires_at[self.admin_count as usize] = 0;
+        self.signatures_count[self.admin_count as usize] = 0;
+        self.last_signed_at[self.admin_count as usize] = 0;
         self.admin_count += 1;
         Ok(())
     }
     
+    /// Swap an admin for a new key in place, keeping its slot, weight and permissions but not its activity
+    pub fn replace_admin(&mut self, old_admin: &Pubkey, new_admin: Pubkey) -> Result<()> {
+        if self.is_renounced() {
+            return Err(crate::config::FormApprovalError::AuthorityRenounced.into());
+        }
+        
+        if self.slot_of(&new_admin).is_some() {
+            return Err(crate::config::FormApprovalError::AdminAlreadyExists.into());
+        }
+        
+        for i in 0..self.admin_count as usize {
+            if self.admins[i] == *old_admin {
+                self.admins[i] = new_admin;
+                self.signatures_count[i] = 0;
+                self.last_signed_at[i] = 0;
+                return Ok(());
+            }
+        }
+        Err(crate::config::FormApprovalError::AdminNotFound.into())
+    }
+    
     /// Remove an admin if present
     pub fn remove_admin(&mut self, admin: &Pubkey) -> Result<()> {
+        if self.is_renounced() {
+            return Err(crate::config::FormApprovalError::AuthorityRenounced.into());
+        }
+        
         if self.admin_count <= 1 {
             return Err(crate::config::FormApprovalError::CannotRemoveLastAdmin.into());
         }
@@ -106,12 +1319,2716 @@ impl AdminConfig {
         // Move the last admin to the removed position
         if index < (self.admin_count - 1) as usize {
             self.admins[index] = self.admins[(self.admin_count - 1) as usize];
+            self.weights[index] = self.weights[(self.admin_count - 1) as usize];
+            self.permissions[index] = self.permissions[(self.admin_count - 1) as usize];
+            self.expires_at[index] = self.expires_at[(self.admin_count - 1) as usize];
+            self.signatures_count[index] = self.signatures_count[(self.admin_count - 1) as usize];
+            self.last_signed_at[index] = self.last_signed_at[(self.admin_count - 1) as usize];
         }
         
         // Clear the last position and decrement count
         self.admins[(self.admin_count - 1) as usize] = Pubkey::default();
+        self.weights[(self.admin_count - 1) as usize] = 0;
+        self.permissions[(self.admin_count - 1) as usize] = 0;
+        self.expires_at[(self.admin_count - 1) as usize] = 0;
+        self.signatures_count[(self.admin_count - 1) as usize] = 0;
+        self.last_signed_at[(self.admin_count - 1) as usize] = 0;
         self.admin_count -= 1;
         
         Ok(())
     }
 }
+
+/// State account registering one admin beyond the fixed `AdminConfig` list
+#[account]
+pub struct AdminRecord {
+    /// Admin key, also used in the PDA seeds
+    pub admin: Pubkey,
+    
+    /// Permission flags, as in `AdminConfig`
+    pub permissions: u8,
+    
+    /// Time the admin's rights lapse (0 never expires)
+    pub expires_at: i64,
+    
+    /// Key that registered the admin
+    pub added_by: Pubkey,
+    
+    /// Time the admin was registered
+    pub added_at: i64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+    
+    /// Forms the admin signed with `sign_form_submission`
+    pub signatures_count: u32,
+    
+    /// Time the admin last signed a form (0 if never)
+    pub last_signed_at: i64,
+}
+
+impl AdminRecord {
+    /// Calculate the space required for the account
+    pub const fn space() -> usize {
+        8 + // discriminator
+        32 + // admin (Pubkey)
+        1 + // permissions (u8)
+        8 + // expires_at (i64)
+        32 + // added_by (Pubkey)
+        8 + // added_at (i64)
+        1 + // bump (u8)
+        4 + // signatures_count (u32)
+        8 // last_signed_at (i64)
+    }
+    
+    /// Whether the admin's rights have not lapsed at `now`
+    pub fn is_active(&self, now: i64) -> bool {
+        self.expires_at == 0 || now < self.expires_at
+    }
+}
+
+/// Change to the admin set or its delay, applied once its timelock passes
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AdminChange {
+    Add { admin: Pubkey },
+    Remove { admin: Pubkey },
+    SetDelay { delay: i64 },
+    AddRecord { admin: Pubkey },
+    RemoveRecord { admin: Pubkey },
+}
+
+impl AdminChange {
+    /// Serialized size of the largest variant
+    pub const SIZE: usize = 1 + 32;
+}
+
+/// State account holding an admin change until its timelock passes
+#[account]
+pub struct PendingAdminChange {
+    /// Sequence number of the change, also used in the PDA seeds
+    pub id: u64,
+    
+    /// Change to apply
+    pub change: AdminChange,
+    
+    /// Key that queued the change and receives the rent back
+    pub queued_by: Pubkey,
+    
+    /// Time the change was queued
+    pub queued_at: i64,
+    
+    /// Time from which the change can be finalized
+    pub effective_at: i64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl PendingAdminChange {
+    /// Calculate the space required for the account
+    pub const fn space() -> usize {
+        8 + // discriminator
+        8 + // id (u64)
+        AdminChange::SIZE + // change (AdminChange)
+        32 + // queued_by (Pubkey)
+        8 + // queued_at (i64)
+        8 + // effective_at (i64)
+        1 // bump (u8)
+    }
+}
+
+/// Semantic version, ordered by major, minor then patch
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SemVer {
+    pub major: u16,
+    pub minor: u16,
+    pub patch: u16,
+}
+
+impl SemVer {
+    /// Serialized size in bytes
+    pub const SIZE: usize = 2 + 2 + 2;
+}
+
+impl std::fmt::Display for SemVer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// State account for program-wide settings
+#[account]
+pub struct ProgramConfig {
+    /// Version of the program that last updated this config
+    pub program_version: SemVer,
+    
+    /// Oldest client version allowed to sign forms (0.0.0 disables the check)
+    pub min_client_version: SemVer,
+    
+    /// Bitmask of retired instructions, indexed by `ProgramInstruction`
+    pub deprecated_instructions: u64,
+    
+    /// Number of checkpoints created, used as the next checkpoint ID
+    pub checkpoint_count: u64,
+    
+    /// End of the most recent checkpoint period
+    pub last_checkpoint_end: i64,
+    
+    /// Number of admin attestations required to finalize a checkpoint
+    pub checkpoint_quorum: u8,
+    
+    /// Rule deciding when a form's per-signer approvals are enough, unless its category overrides it
+    pub threshold_policy: ThresholdPolicy,
+    
+    /// External programs allowed to create approvals via CPI
+    pub cpi_allow_list: Vec<Pubkey>,
+    
+    /// Seconds after a revocation during which a form can be re-approved (0 disables)
+    pub reapproval_window: i64,
+    
+    /// Whether signatures must be co-signed by a registered application
+    pub require_app_signature: bool,
+    
+    /// Number of configuration changes made, sequencing `ConfigChanged` events
+    pub config_sequence: u64,
+    
+    /// Maximum number of signers a single form can collect
+    pub max_signers_per_form: u8,
+    
+    /// Merkle root of keys allowed to self-register as admins (zero disables)
+    pub admin_import_root: [u8; 32],
+    
+    /// Whether signers must record consent before their signature is accepted
+    pub strict_consent: bool,
+    
+    /// Whether approvals must be archived in their current state before they can be closed
+    pub require_archive_before_close: bool,
+    
+    /// Seconds a client-supplied signing timestamp may differ from the chain clock
+    pub max_clock_skew: i64,
+    
+    /// Seconds a queued admin change waits before it can be finalized (0 applies changes directly)
+    pub admin_change_delay: i64,
+    
+    /// Number of admin changes queued, used to derive their PDAs
+    pub admin_change_count: u64,
+    
+    /// Seconds an admin invitation stays open (0 never expires)
+    pub invitation_ttl: i64,
+    
+    /// Recorded verifications allowed per verifier and approval in each rate window (0 is unlimited)
+    pub verifier_rate_limit: u32,
+    
+    /// Whether signing and updating approvals is suspended
+    pub paused: bool,
+    
+    /// Whether signing a hash already anchored under another form is refused unless overridden
+    pub reject_duplicate_hashes: bool,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl ProgramConfig {
+    /// Calculate the space required for the account
+    pub const fn space() -> usize {
+        8 + // discriminator
+        SemVer::SIZE + // program_version (SemVer)
+        SemVer::SIZE + // min_client_version (SemVer)
+        8 + // deprecated_instructions (u64)
+        8 + // checkpoint_count (u64)
+        8 + // last_checkpoint_end (i64)
+        1 + // checkpoint_quorum (u8)
+        ThresholdPolicy::SIZE + // threshold_policy (ThresholdPolicy)
+        4 + (32 * crate::config::Config::MAX_CPI_PROGRAMS) + // cpi_allow_list (Vec<Pubkey>)
+        8 + // reapproval_window (i64)
+        1 + // require_app_signature (bool)
+        8 + // config_sequence (u64)
+        1 + // max_signers_per_form (u8)
+        32 + // admin_import_root ([u8; 32])
+        1 + // strict_consent (bool)
+        1 + // require_archive_before_close (bool)
+        8 + // max_clock_skew (i64)
+        8 + // admin_change_delay (i64)
+        8 + // admin_change_count (u64)
+        8 + // invitation_ttl (i64)
+        4 + // verifier_rate_limit (u32)
+        1 + // paused (bool)
+        1 + // reject_duplicate_hashes (bool)
+        1 // bump (u8)
+    }
+    
+    /// Check that the fixed admin list still has enough admins to reach the checkpoint quorum
+    pub fn require_quorum_reachable(&self, admin_config: &AdminConfig) -> Result<()> {
+        require!(
+            self.checkpoint_quorum <= admin_config.admin_count,
+            crate::config::FormApprovalError::QuorumExceedsAdmins
+        );
+        Ok(())
+    }
+    
+    /// Hash of the serialized config, chaining `ConfigChanged` events together
+    pub fn config_hash(&self) -> Result<[u8; 32]> {
+        Ok(anchor_lang::solana_program::hash::hash(&self.try_to_vec()?).to_bytes())
+    }
+    
+    /// Check whether a program may create approvals via CPI
+    pub fn is_cpi_allowed(&self, program: &Pubkey) -> bool {
+        self.cpi_allow_list.contains(program)
+    }
+    
+    /// Check whether an instruction has been retired
+    pub fn is_deprecated(&self, instruction: ProgramInstruction) -> bool {
+        self.deprecated_instructions & instruction.mask() != 0
+    }
+    
+    /// Retire or restore an instruction
+    pub fn set_deprecated(&mut self, instruction: ProgramInstruction, deprecated: bool) {
+        if deprecated {
+            self.deprecated_instructions |= instruction.mask();
+        } else {
+            self.deprecated_instructions &= !instruction.mask();
+        }
+    }
+    
+    /// Check a client's declared version against the configured minimum
+    pub fn check_client_version(&self, client_version: Option<SemVer>) -> Result<()> {
+        if self.min_client_version == SemVer::default() {
+            return Ok(());
+        }
+        
+        let version = client_version.ok_or(crate::config::FormApprovalError::ClientVersionRequired)?;
+        require!(
+            version >= self.min_client_version,
+            crate::config::FormApprovalError::ClientVersionTooOld
+        );
+        Ok(())
+    }
+}
+
+/// Instructions that can be retired through the program config
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProgramInstruction {
+    SignFormSubmission,
+    UpdateFormApproval,
+    AddAdmin,
+    RemoveAdmin,
+    SignFormSubmissionCpi,
+    SignFormAsSigner,
+    ReapproveFormSubmission,
+    InviteAdmin,
+    ReplaceAdmin,
+    SignOrgFormSubmission,
+    CountersignOrgFormApproval,
+}
+
+impl ProgramInstruction {
+    /// Bit for this instruction in `ProgramConfig::deprecated_instructions`
+    pub const fn mask(self) -> u64 {
+        1 << self as u8
+    }
+}
+
+/// Subsystems that can be toggled at runtime
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeatureGate {
+    MultiSign,
+}
+
+/// State account for runtime feature flags
+#[account]
+pub struct FeatureGates {
+    /// Whether multi-signature approvals are enabled
+    pub multi_sign_enabled: bool,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl FeatureGates {
+    /// Calculate the space required for the account
+    pub const fn space() -> usize {
+        8 + // discriminator
+        1 + // multi_sign_enabled (bool)
+        1 // bump (u8)
+    }
+    
+    /// Check whether a feature is enabled
+    pub fn is_enabled(&self, feature: FeatureGate) -> bool {
+        match feature {
+            FeatureGate::MultiSign => self.multi_sign_enabled,
+        }
+    }
+    
+    /// Toggle a feature
+    pub fn set(&mut self, feature: FeatureGate, enabled: bool) {
+        match feature {
+            FeatureGate::MultiSign => self.multi_sign_enabled = enabled,
+        }
+    }
+}
+
+/// State account committing to every approval created in a period
+#[account]
+pub struct Checkpoint {
+    /// Sequential checkpoint ID
+    pub checkpoint_id: u64,
+    
+    /// Start of the covered period (inclusive)
+    pub period_start: i64,
+    
+    /// End of the covered period (exclusive)
+    pub period_end: i64,
+    
+    /// Merkle root over the approval leaves of the period
+    pub merkle_root: [u8; 32],
+    
+    /// Number of approvals committed to by the root
+    pub approval_count: u32,
+    
+    /// Admin who submitted the checkpoint
+    pub created_by: Pubkey,
+    
+    /// Timestamp when the checkpoint was recorded
+    pub created_at: i64,
+    
+    /// Slot in which the checkpoint was recorded
+    pub created_slot: u64,
+    
+    /// Attestations required, snapshotted from the program config at creation
+    pub required_attestations: u8,
+    
+    /// Admins who have attested the root, starting with the creator
+    pub attesters: Vec<Pubkey>,
+    
+    /// Timestamp when the quorum was reached (0 while pending)
+    pub finalized_at: i64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl Checkpoint {
+    /// Calculate the space required for the account
+    pub const fn space() -> usize {
+        8 + // discriminator
+        8 + // checkpoint_id (u64)
+        8 + // period_start (i64)
+        8 + // period_end (i64)
+        32 + // merkle_root ([u8; 32])
+        4 + // approval_count (u32)
+        32 + // created_by (Pubkey)
+        8 + // created_at (i64)
+        8 + // created_slot (u64)
+        1 + // required_attestations (u8)
+        4 + (32 * crate::config::Config::MAX_ADMINS) + // attesters (Vec<Pubkey>)
+        8 + // finalized_at (i64)
+        1 // bump (u8)
+    }
+    
+    /// Whether the checkpoint has reached its quorum
+    pub fn is_finalized(&self) -> bool {
+        self.finalized_at != 0
+    }
+    
+    /// Record an admin's attestation, returning true if it completed the quorum
+    pub fn attest(&mut self, admin: Pubkey, now: i64) -> Result<bool> {
+        require!(
+            !self.is_finalized(),
+            crate::config::FormApprovalError::CheckpointAlreadyFinalized
+        );
+        require!(
+            !self.attesters.contains(&admin),
+            crate::config::FormApprovalError::CheckpointAlreadyAttested
+        );
+        
+        self.attesters.push(admin);
+        if self.attesters.len() >= self.required_attestations as usize {
+            self.finalized_at = now;
+            return Ok(true);
+        }
+        Ok(false)
+    }
+}
+
+/// Rule deciding when a form's per-signer approvals are enough
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ThresholdPolicy {
+    /// At least `required` distinct signers
+    Count { required: u32 },
+    
+    /// Signer weights summing to at least `required`
+    WeightedSum { required: u32 },
+    
+    /// `required_signer` (e.g. the legal officer) plus at least `required` signers in total
+    RequiredSignerPlusCount { required_signer: Pubkey, required: u32 },
+}
+
+impl ThresholdPolicy {
+    /// Serialized size of the largest variant
+    pub const SIZE: usize = 1 + 32 + 4;
+    
+    /// Check that the policy can be met by the current admins
+    pub fn validate(&self, admin_config: &AdminConfig) -> Result<()> {
+        let achievable = match self {
+            ThresholdPolicy::Count { required } => {
+                *required >= 1 && *required <= admin_config.admin_count as u32
+            }
+            ThresholdPolicy::WeightedSum { required } => {
+                *required >= 1 && *required <= admin_config.total_weight()
+            }
+            ThresholdPolicy::RequiredSignerPlusCount { required_signer, required } => {
+                admin_config.is_admin(required_signer)
+                    && *required >= 1
+                    && *required <= admin_config.admin_count as u32
+            }
+        };
+        require!(achievable, crate::config::FormApprovalError::InvalidThresholdPolicy);
+        Ok(())
+    }
+    
+    /// Check that the policy can be met without exceeding the signer limit
+    pub fn fits_signer_limit(&self, max_signers: u8) -> bool {
+        match self {
+            ThresholdPolicy::Count { required }
+            | ThresholdPolicy::RequiredSignerPlusCount { required, .. } => *required <= max_signers as u32,
+            ThresholdPolicy::WeightedSum { .. } => true,
+        }
+    }
+    
+    /// Evaluate the policy against a form's tally
+    pub fn is_met(&self, tally: &ApprovalTally) -> bool {
+        match self {
+            ThresholdPolicy::Count { required } => tally.signature_count >= *required,
+            ThresholdPolicy::WeightedSum { required } => tally.weighted_sum >= *required,
+            ThresholdPolicy::RequiredSignerPlusCount { required_signer, required } => {
+                tally.signers.contains(required_signer) && tally.signature_count >= *required
+            }
+        }
+    }
+}
+
+/// State account for an admin invitation awaiting the invitee's acceptance
+#[account]
+pub struct AdminInvitation {
+    /// Key invited to become an admin, also used in the PDA seeds
+    pub invitee: Pubkey,
+    
+    /// Authority that sent the invitation and receives the rent back
+    pub invited_by: Pubkey,
+    
+    /// Time the invitation was sent
+    pub invited_at: i64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+    
+    /// Time after which the invitation can no longer be accepted (0 if it never expires)
+    pub expires_at: i64,
+}
+
+impl AdminInvitation {
+    /// Calculate the space required for the account
+    pub const fn space() -> usize {
+        8 + // discriminator
+        32 + // invitee (Pubkey)
+        32 + // invited_by (Pubkey)
+        8 + // invited_at (i64)
+        1 + // bump (u8)
+        8 // expires_at (i64)
+    }
+    
+    /// Whether the invitation can still be accepted at `now`
+    pub fn is_open(&self, now: i64) -> bool {
+        self.expires_at == 0 || now < self.expires_at
+    }
+}
+
+/// State account for an admin removal that takes effect after a wind-down period
+#[account]
+pub struct AdminRemovalSchedule {
+    /// Admin to be removed, also used in the PDA seeds
+    pub admin: Pubkey,
+    
+    /// Authority that scheduled the removal and receives the rent back
+    pub scheduled_by: Pubkey,
+    
+    /// Time the removal was scheduled
+    pub scheduled_at: i64,
+    
+    /// Time from which the removal can be executed
+    pub effective_at: i64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl AdminRemovalSchedule {
+    /// Calculate the space required for the account
+    pub const fn space() -> usize {
+        8 + // discriminator
+        32 + // admin (Pubkey)
+        32 + // scheduled_by (Pubkey)
+        8 + // scheduled_at (i64)
+        8 + // effective_at (i64)
+        1 // bump (u8)
+    }
+}
+
+/// State account for an integrating application allowed to submit signatures
+#[account]
+pub struct RegisteredApp {
+    /// Key the application co-signs with, also used in the PDA seeds
+    pub app_key: Pubkey,
+    
+    /// Human-readable application name
+    pub name: String,
+    
+    /// Authority that registered the application
+    pub registered_by: Pubkey,
+    
+    /// Time the application was registered
+    pub registered_at: i64,
+    
+    /// Whether the application may currently submit signatures
+    pub active: bool,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+    
+    /// Signatures submitted through the application
+    pub signings: u64,
+    
+    /// Verifications the application recorded on-chain
+    pub verifications: u64,
+}
+
+impl RegisteredApp {
+    /// Calculate the space required for the account
+    pub const fn space(name_len: usize) -> usize {
+        8 + // discriminator
+        32 + // app_key (Pubkey)
+        4 + name_len + // name (String)
+        32 + // registered_by (Pubkey)
+        8 + // registered_at (i64)
+        1 + // active (bool)
+        1 + // bump (u8)
+        8 + // signings (u64)
+        8 // verifications (u64)
+    }
+    
+    /// Resolve the app ID to stamp on an approval from the optional app accounts, counting the signing
+    pub fn resolve(
+        app: Option<&mut RegisteredApp>,
+        app_signer: Option<Pubkey>,
+        required: bool,
+    ) -> Result<Pubkey> {
+        let Some(app) = app else {
+            require!(!required, crate::config::FormApprovalError::AppSignatureRequired);
+            return Ok(Pubkey::default());
+        };
+        
+        require!(app.active, crate::config::FormApprovalError::AppInactive);
+        require!(
+            app_signer == Some(app.app_key),
+            crate::config::FormApprovalError::InvalidAppSigner
+        );
+        app.signings += 1;
+        Ok(app.app_key)
+    }
+}
+
+/// State account for one published version of a form template
+#[account]
+pub struct TemplateVersion {
+    /// Template identifier, also used in the PDA seeds
+    pub template_id: String,
+    
+    /// Version number, also used in the PDA seeds
+    pub version: u32,
+    
+    /// SHA-256 hash of the template's contract language
+    pub content_hash: [u8; 32],
+    
+    /// Authority that published the version
+    pub published_by: Pubkey,
+    
+    /// Time the version was published
+    pub published_at: i64,
+    
+    /// Time the version was deprecated (0 while current)
+    pub deprecated_at: i64,
+    
+    /// Locales the template is available in (empty allows any)
+    pub allowed_locales: Vec<String>,
+    
+    /// Jurisdictions the template may be signed in (empty allows any)
+    pub allowed_jurisdictions: Vec<String>,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl TemplateVersion {
+    /// Calculate the space required for the account
+    pub const fn space(template_id_len: usize, locale_count: usize, jurisdiction_count: usize) -> usize {
+        8 + // discriminator
+        4 + template_id_len + // template_id (String)
+        4 + // version (u32)
+        32 + // content_hash ([u8; 32])
+        32 + // published_by (Pubkey)
+        8 + // published_at (i64)
+        8 + // deprecated_at (i64)
+        4 + locale_count * (4 + crate::config::Config::MAX_LOCALE_LENGTH) + // allowed_locales (Vec<String>)
+        4 + jurisdiction_count * (4 + crate::config::Config::MAX_JURISDICTION_LENGTH) + // allowed_jurisdictions (Vec<String>)
+        1 // bump (u8)
+    }
+    
+    /// Whether new signings against this version are refused
+    pub fn is_deprecated(&self) -> bool {
+        self.deprecated_at != 0
+    }
+    
+    /// Whether the template is available in `locale`
+    pub fn allows_locale(&self, locale: &str) -> bool {
+        self.allowed_locales.is_empty() || self.allowed_locales.iter().any(|allowed| allowed == locale)
+    }
+    
+    /// Whether the template may be signed in `jurisdiction`
+    pub fn allows_jurisdiction(&self, jurisdiction: &str) -> bool {
+        self.allowed_jurisdictions.is_empty()
+            || self.allowed_jurisdictions.iter().any(|allowed| allowed == jurisdiction)
+    }
+    
+    /// Resolve the template version to pin on a new approval signed in `locale` and `jurisdiction` (default if none)
+    pub fn pin(
+        template: Option<&Account<TemplateVersion>>,
+        locale: &str,
+        jurisdiction: &str,
+    ) -> Result<Pubkey> {
+        let Some(template) = template else {
+            return Ok(Pubkey::default());
+        };
+        
+        require!(
+            !template.is_deprecated(),
+            crate::config::FormApprovalError::TemplateVersionDeprecated
+        );
+        require!(
+            template.allows_locale(locale),
+            crate::config::FormApprovalError::LocaleNotAllowed
+        );
+        require!(
+            template.allows_jurisdiction(jurisdiction),
+            crate::config::FormApprovalError::JurisdictionNotAllowed
+        );
+        Ok(template.key())
+    }
+}
+
+/// State account holding the issuer presentation data shown on certificates
+#[account]
+pub struct BrandingConfig {
+    /// SHA-256 hash of the issuer logo URI
+    pub logo_uri_hash: [u8; 32],
+    
+    /// Issuer name displayed on certificates
+    pub issuer_display_name: String,
+    
+    /// SHA-256 hash of the issuer support contact
+    pub support_contact_hash: [u8; 32],
+    
+    /// Authority that last updated the branding
+    pub updated_by: Pubkey,
+    
+    /// Time the branding was last updated
+    pub updated_at: i64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl BrandingConfig {
+    /// Calculate the space required for the account
+    pub const fn space() -> usize {
+        8 + // discriminator
+        32 + // logo_uri_hash ([u8; 32])
+        4 + crate::config::Config::MAX_ISSUER_NAME_LENGTH + // issuer_display_name (String)
+        32 + // support_contact_hash ([u8; 32])
+        32 + // updated_by (Pubkey)
+        8 + // updated_at (i64)
+        1 // bump (u8)
+    }
+}
+
+/// State account counting the recorded verifications of an approval
+#[account]
+pub struct VerificationStats {
+    /// Verified approval account, also used in the PDA seeds
+    pub approval: Pubkey,
+    
+    /// Number of recorded verifications
+    pub total_verifications: u64,
+    
+    /// Number of distinct verifiers
+    pub verifier_count: u32,
+    
+    /// Time of the most recent recorded verification
+    pub last_verified_at: i64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl VerificationStats {
+    /// Calculate the space required for the account
+    pub const fn space() -> usize {
+        8 + // discriminator
+        32 + // approval (Pubkey)
+        8 + // total_verifications (u64)
+        4 + // verifier_count (u32)
+        8 + // last_verified_at (i64)
+        1 // bump (u8)
+    }
+}
+
+/// State account counting one verifier's recorded verifications of an approval
+#[account]
+pub struct VerifierRecord {
+    /// Verified approval account, also used in the PDA seeds
+    pub approval: Pubkey,
+    
+    /// Verifier key, also used in the PDA seeds
+    pub verifier: Pubkey,
+    
+    /// Number of verifications this verifier recorded
+    pub count: u64,
+    
+    /// Start of the current rate window
+    pub window_start: i64,
+    
+    /// Verifications recorded in the current rate window
+    pub window_count: u32,
+    
+    /// Time of the verifier's most recent verification
+    pub last_verified_at: i64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl VerifierRecord {
+    /// Calculate the space required for the account
+    pub const fn space() -> usize {
+        8 + // discriminator
+        32 + // approval (Pubkey)
+        32 + // verifier (Pubkey)
+        8 + // count (u64)
+        8 + // window_start (i64)
+        4 + // window_count (u32)
+        8 + // last_verified_at (i64)
+        1 // bump (u8)
+    }
+    
+    /// Count a verification at `now`, failing once `limit` is reached within the window (0 is unlimited)
+    pub fn record(&mut self, now: i64, limit: u32) -> Result<()> {
+        if now >= self.window_start + crate::config::Config::VERIFICATION_RATE_WINDOW {
+            self.window_start = now;
+            self.window_count = 0;
+        }
+        require!(
+            limit == 0 || self.window_count < limit,
+            crate::config::FormApprovalError::VerificationRateLimited
+        );
+        
+        self.window_count += 1;
+        self.count += 1;
+        self.last_verified_at = now;
+        Ok(())
+    }
+}
+
+/// State account holding the hash of the catalog mapping reason codes to internal policy references
+#[account]
+pub struct PolicyCatalog {
+    /// SHA-256 hash of the current catalog
+    pub catalog_hash: [u8; 32],
+    
+    /// Version of the catalog, incremented on every update
+    pub version: u32,
+    
+    /// Authority that last updated the catalog
+    pub updated_by: Pubkey,
+    
+    /// Time the catalog was last updated
+    pub updated_at: i64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl PolicyCatalog {
+    /// Calculate the space required for the account
+    pub const fn space() -> usize {
+        8 + // discriminator
+        32 + // catalog_hash ([u8; 32])
+        4 + // version (u32)
+        32 + // updated_by (Pubkey)
+        8 + // updated_at (i64)
+        1 // bump (u8)
+    }
+    
+    /// Catalog hash to stamp on an outcome, zero when no catalog is registered
+    pub fn stamp(catalog: Option<&PolicyCatalog>) -> [u8; 32] {
+        catalog.map_or([0u8; 32], |catalog| catalog.catalog_hash)
+    }
+}
+
+/// State account counting reported signing attempts by unauthorized keys
+#[account]
+pub struct AbuseCounters {
+    /// Number of attempts reported by anyone
+    pub reported_attempts: u64,
+    
+    /// Number of attempts reported by the authority
+    pub confirmed_attempts: u64,
+    
+    /// Key behind the most recently reported attempt
+    pub last_attempted_by: Pubkey,
+    
+    /// Hash of the evidence (e.g. the failed simulation logs) behind the most recent report
+    pub last_evidence_hash: [u8; 32],
+    
+    /// Time of the most recent report
+    pub last_reported_at: i64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl AbuseCounters {
+    /// Calculate the space required for the account
+    pub const fn space() -> usize {
+        8 + // discriminator
+        8 + // reported_attempts (u64)
+        8 + // confirmed_attempts (u64)
+        32 + // last_attempted_by (Pubkey)
+        32 + // last_evidence_hash ([u8; 32])
+        8 + // last_reported_at (i64)
+        1 // bump (u8)
+    }
+}
+
+/// State account holding the hash of the authorized webhook endpoint manifest
+#[account]
+pub struct WebhookManifest {
+    /// SHA-256 hash of the current manifest (zero if no endpoints are authorized)
+    pub manifest_hash: [u8; 32],
+    
+    /// Version of the manifest, incremented on every update
+    pub version: u32,
+    
+    /// Authority that last updated the manifest
+    pub updated_by: Pubkey,
+    
+    /// Time the manifest was last updated
+    pub updated_at: i64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl WebhookManifest {
+    /// Calculate the space required for the account
+    pub const fn space() -> usize {
+        8 + // discriminator
+        32 + // manifest_hash ([u8; 32])
+        4 + // version (u32)
+        32 + // updated_by (Pubkey)
+        8 + // updated_at (i64)
+        1 // bump (u8)
+    }
+}
+
+/// State account holding the head of the rolling hash chain over all approvals
+#[account]
+pub struct ApprovalChain {
+    /// Hash of the most recent link (zero before the first approval)
+    pub head: [u8; 32],
+    
+    /// Number of approvals linked so far
+    pub length: u64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl ApprovalChain {
+    /// Calculate the space required for the account
+    pub const fn space() -> usize {
+        8 + // discriminator
+        32 + // head ([u8; 32])
+        8 + // length (u64)
+        1 // bump (u8)
+    }
+    
+    /// Link an approval into the chain, stamping it with the previous head and its position
+    pub fn link(&mut self, approval: &mut FormApproval, address: &Pubkey) {
+        approval.prev_chain_hash = self.head;
+        self.head = crate::merkle::chain_link(&self.head, &approval.checkpoint_leaf(address));
+        self.length += 1;
+        approval.chain_index = self.length;
+    }
+}
+
+/// State account subscribing a wallet to a form's notification events
+#[account]
+pub struct FormObserver {
+    /// Observing wallet, also used in the PDA seeds
+    pub observer: Pubkey,
+    
+    /// Observed form, also used in the PDA seeds
+    pub form_id: String,
+    
+    /// Time the observer registered
+    pub registered_at: i64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+    
+    /// Organization whose namespace the observed form ID belongs to (default for the global namespace)
+    pub organization: Pubkey,
+}
+
+impl FormObserver {
+    /// Calculate the space required for the account
+    pub const fn space(form_id_len: usize) -> usize {
+        8 + // discriminator
+        32 + // observer (Pubkey)
+        4 + form_id_len + // form_id (String)
+        8 + // registered_at (i64)
+        1 + // bump (u8)
+        32 // organization (Pubkey)
+    }
+}
+
+/// State change reported to a form's observers
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FormAction {
+    Approved,
+    Updated,
+    Revoked,
+    Reapproved,
+    SignatureWithdrawn,
+    CoSigned,
+    StatusChanged,
+    CounterSigned,
+}
+
+/// State account numbering every event the program emits
+#[account]
+pub struct EventSequence {
+    /// Sequence number of the most recent event (0 before the first)
+    pub last: u64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl EventSequence {
+    /// Calculate the space required for the account
+    pub const fn space() -> usize {
+        8 + // discriminator
+        8 + // last (u64)
+        1 // bump (u8)
+    }
+    
+    /// Number the next event
+    pub fn advance(&mut self) -> u64 {
+        self.last += 1;
+        self.last
+    }
+}
+
+/// State account recording the changes made to an approval after it was signed
+#[account]
+pub struct FormHistory {
+    /// Approval the history belongs to, also used in the PDA seeds
+    pub approval: Pubkey,
+    
+    /// Most recent entries, oldest first (up to `MAX_FORM_HISTORY_ENTRIES`)
+    pub entries: Vec<HistoryEntry>,
+    
+    /// Entries appended so far, including those no longer kept in `entries`
+    pub entry_count: u32,
+    
+    /// Head of the hash chain over every entry (zero before the first)
+    pub head: [u8; 32],
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl FormHistory {
+    /// Calculate the space required for the account
+    pub const fn space() -> usize {
+        8 + // discriminator
+        32 + // approval (Pubkey)
+        4 + (HistoryEntry::SIZE * crate::config::Config::MAX_FORM_HISTORY_ENTRIES) + // entries (Vec<HistoryEntry>)
+        4 + // entry_count (u32)
+        32 + // head ([u8; 32])
+        1 // bump (u8)
+    }
+    
+    /// Append an entry, chaining it into the head and dropping the oldest kept entry when full
+    pub fn append(&mut self, approval: Pubkey, entry: HistoryEntry, bump: u8) {
+        self.approval = approval;
+        self.bump = bump;
+        self.head = crate::merkle::chain_link(&self.head, &entry.leaf());
+        self.entry_count += 1;
+        if self.entries.len() == crate::config::Config::MAX_FORM_HISTORY_ENTRIES {
+            self.entries.remove(0);
+        }
+        self.entries.push(entry);
+    }
+}
+
+/// One change recorded in a `FormHistory`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HistoryEntry {
+    /// Kind of change
+    pub action: FormAction,
+    
+    /// Key that made the change
+    pub actor: Pubkey,
+    
+    /// Time of the change
+    pub at: i64,
+    
+    /// Hash summarizing the change: the new metadata, the revocation reason or the co-signed form hash
+    pub summary_hash: [u8; 32],
+}
+
+impl HistoryEntry {
+    /// Serialized size in bytes
+    pub const SIZE: usize = 1 + 32 + 8 + 32;
+    
+    /// Leaf committing the entry into the history chain
+    pub fn leaf(&self) -> [u8; 32] {
+        crate::merkle::history_leaf(self.action as u8, &self.actor, self.at, &self.summary_hash)
+    }
+}
+
+/// State account buffering a document uploaded in chunks, so its hash can be computed on-chain
+#[account]
+pub struct DocumentUpload {
+    /// Form ID the document belongs to, also used in the PDA seeds
+    pub form_id: String,
+    
+    /// Key uploading the document, also used in the PDA seeds
+    pub uploader: Pubkey,
+    
+    /// Declared size of the document in bytes
+    pub total_len: u32,
+    
+    /// Content received so far
+    pub content: Vec<u8>,
+    
+    /// Time the upload began
+    pub started_at: i64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl DocumentUpload {
+    /// Calculate the space required for the account
+    pub const fn space(form_id_len: usize, total_len: usize) -> usize {
+        8 + // discriminator
+        4 + form_id_len + // form_id (String)
+        32 + // uploader (Pubkey)
+        4 + // total_len (u32)
+        4 + total_len + // content (Vec<u8>)
+        8 + // started_at (i64)
+        1 // bump (u8)
+    }
+    
+    /// Append `chunk`, which must start at `offset`, the end of the content received so far
+    pub fn append(&mut self, offset: u32, chunk: &[u8]) -> Result<()> {
+        require!(
+            offset as usize == self.content.len()
+                && self.content.len() + chunk.len() <= self.total_len as usize,
+            crate::config::FormApprovalError::UploadChunkOutOfOrder
+        );
+        self.content.extend_from_slice(chunk);
+        Ok(())
+    }
+    
+    /// SHA-256 hash of the complete content
+    pub fn content_hash(&self) -> Result<[u8; 32]> {
+        require!(
+            self.content.len() == self.total_len as usize,
+            crate::config::FormApprovalError::UploadIncomplete
+        );
+        Ok(anchor_lang::solana_program::hash::hash(&self.content).to_bytes())
+    }
+}
+
+/// State account proving an approval was exported to off-chain retention storage
+#[account]
+pub struct ArchiveRecord {
+    /// Archived approval account, also used in the PDA seeds
+    pub approval: Pubkey,
+    
+    /// Form ID of the archived approval
+    pub form_id: String,
+    
+    /// SHA-256 hash of the approval's full serialized account data at archive time
+    pub approval_hash: [u8; 32],
+    
+    /// Location of the archived copy
+    pub archive_uri: String,
+    
+    /// Admin that archived the approval
+    pub archived_by: Pubkey,
+    
+    /// Time the approval was archived
+    pub archived_at: i64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl ArchiveRecord {
+    /// Check the archive requirement for closing an approval with the given account data
+    pub fn check_before_close(
+        archive_record: Option<&ArchiveRecord>,
+        approval_data: &[u8],
+        required: bool,
+    ) -> Result<()> {
+        if !required {
+            return Ok(());
+        }
+        let approval_hash = anchor_lang::solana_program::hash::hash(approval_data).to_bytes();
+        require!(
+            archive_record.is_some_and(|record| record.approval_hash == approval_hash),
+            crate::config::FormApprovalError::ArchiveRequired
+        );
+        Ok(())
+    }
+    
+    /// Calculate the space required for the account
+    pub const fn space(form_id_len: usize) -> usize {
+        8 + // discriminator
+        32 + // approval (Pubkey)
+        4 + form_id_len + // form_id (String)
+        32 + // approval_hash ([u8; 32])
+        4 + crate::config::Config::MAX_ARCHIVE_URI_LENGTH + // archive_uri (String)
+        32 + // archived_by (Pubkey)
+        8 + // archived_at (i64)
+        1 // bump (u8)
+    }
+}
+
+/// State account attesting that an approval was copied to another cluster or chain
+#[account]
+pub struct MirrorAttestation {
+    /// Mirrored approval account, also used in the PDA seeds
+    pub approval: Pubkey,
+    
+    /// Form ID of the mirrored approval
+    pub form_id: String,
+    
+    /// Cluster or chain holding the copy
+    pub location: MirrorLocation,
+    
+    /// Hash of the remote transaction or object reference, also used in the PDA seeds
+    pub remote_tx_hash: [u8; 32],
+    
+    /// Key of the attester that checked the copy
+    pub attester: Pubkey,
+    
+    /// Attester's ed25519 signature over the remote reference
+    pub attester_signature: [u8; 64],
+    
+    /// Admin that recorded the attestation
+    pub recorded_by: Pubkey,
+    
+    /// Time the attestation was recorded
+    pub recorded_at: i64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl MirrorAttestation {
+    /// Calculate the space required for the account
+    pub const fn space(form_id_len: usize) -> usize {
+        8 + // discriminator
+        32 + // approval (Pubkey)
+        4 + form_id_len + // form_id (String)
+        1 + // location (MirrorLocation)
+        32 + // remote_tx_hash ([u8; 32])
+        32 + // attester (Pubkey)
+        64 + // attester_signature ([u8; 64])
+        32 + // recorded_by (Pubkey)
+        8 + // recorded_at (i64)
+        1 // bump (u8)
+    }
+}
+
+/// State account recording a batch of approvals exported in one Arweave bundle
+#[account]
+pub struct ArchiveBundle {
+    /// Arweave transaction ID of the bundle (raw 32 bytes), also used in the PDA seeds
+    pub bundle_id: [u8; 32],
+    
+    /// Key of the archiver that signed the bundle
+    pub archiver: Pubkey,
+    
+    /// Archiver's ed25519 signature over the bundle, as submitted to the bundler
+    pub archiver_signature: [u8; 64],
+    
+    /// Approval accounts exported in the bundle
+    pub approvals: Vec<Pubkey>,
+    
+    /// Admin that recorded the bundle
+    pub archived_by: Pubkey,
+    
+    /// Time the bundle was recorded
+    pub archived_at: i64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl ArchiveBundle {
+    /// Calculate the space required for the account
+    pub const fn space() -> usize {
+        8 + // discriminator
+        32 + // bundle_id ([u8; 32])
+        32 + // archiver (Pubkey)
+        64 + // archiver_signature ([u8; 64])
+        4 + (32 * crate::config::Config::MAX_ARCHIVE_BATCH) + // approvals (Vec<Pubkey>)
+        32 + // archived_by (Pubkey)
+        8 + // archived_at (i64)
+        1 // bump (u8)
+    }
+}
+
+/// State account proving a form submission was reviewed and declined
+#[account]
+pub struct FormRejection {
+    /// Rejected form, also used in the PDA seeds
+    pub form_id: String,
+    
+    /// SHA-256 hash of the submission that was reviewed
+    pub form_hash: [u8; 32],
+    
+    /// Admin who rejected the submission
+    pub rejected_by: Pubkey,
+    
+    /// Standardized rejection reason
+    pub reason_code: ReasonCode,
+    
+    /// Free-form explanation for the rejection
+    pub reason: String,
+    
+    /// Time the submission was rejected
+    pub rejected_at: i64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+    
+    /// Hash of the policy catalog in force when the submission was rejected (zero if none)
+    pub policy_catalog_hash: [u8; 32],
+}
+
+impl FormRejection {
+    /// Calculate the space required for the account
+    pub const fn space(form_id_len: usize) -> usize {
+        8 + // discriminator
+        4 + form_id_len + // form_id (String)
+        32 + // form_hash ([u8; 32])
+        32 + // rejected_by (Pubkey)
+        1 + // reason_code (ReasonCode)
+        4 + crate::config::Config::MAX_REJECTION_REASON_LENGTH + // reason (String)
+        8 + // rejected_at (i64)
+        1 + // bump (u8)
+        32 // policy_catalog_hash ([u8; 32])
+    }
+}
+
+/// State account sharing the key to an approval's encrypted metadata with a grantee until it expires
+#[account]
+pub struct AccessGrant {
+    /// Approval whose metadata is shared, also used in the PDA seeds
+    pub approval: Pubkey,
+    
+    /// Form ID of the shared approval
+    pub form_id: String,
+    
+    /// Wallet the metadata is shared with, also used in the PDA seeds
+    pub grantee: Pubkey,
+    
+    /// Key that issued the grant and receives its rent when revoked
+    pub granted_by: Pubkey,
+    
+    /// Metadata key re-encrypted to the grantee, or a reference to where it is wrapped
+    pub wrapped_key: Vec<u8>,
+    
+    /// Time the grant was issued or last updated
+    pub granted_at: i64,
+    
+    /// Time after which the grant no longer applies
+    pub expires_at: i64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl AccessGrant {
+    /// Calculate the space required for the account
+    pub const fn space(form_id_len: usize) -> usize {
+        8 + // discriminator
+        32 + // approval (Pubkey)
+        4 + form_id_len + // form_id (String)
+        32 + // grantee (Pubkey)
+        32 + // granted_by (Pubkey)
+        4 + crate::config::Config::MAX_WRAPPED_KEY_LENGTH + // wrapped_key (Vec<u8>)
+        8 + // granted_at (i64)
+        8 + // expires_at (i64)
+        1 // bump (u8)
+    }
+    
+    /// Whether the grant still applies at `now`
+    pub fn is_active(&self, now: i64) -> bool {
+        now < self.expires_at
+    }
+}
+
+/// State account counting the annotations attached to an approval
+#[account]
+pub struct AnnotationLog {
+    /// Annotated approval, also used in the PDA seeds
+    pub approval: Pubkey,
+    
+    /// Number of annotations added, and the index of the next one
+    pub count: u16,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl AnnotationLog {
+    /// Calculate the space required for the account
+    pub const fn space() -> usize {
+        8 + // discriminator
+        32 + // approval (Pubkey)
+        2 + // count (u16)
+        1 // bump (u8)
+    }
+}
+
+/// State account holding a reviewer's note on an approval, kept apart from the approval itself
+#[account]
+pub struct Annotation {
+    /// Annotated approval, also used in the PDA seeds
+    pub approval: Pubkey,
+    
+    /// Form ID of the annotated approval
+    pub form_id: String,
+    
+    /// Position of the annotation on the approval, also used in the PDA seeds
+    pub index: u16,
+    
+    /// Admin who wrote the annotation
+    pub author: Pubkey,
+    
+    /// SHA-256 hash of the annotation text, stored off-chain
+    pub note_hash: [u8; 32],
+    
+    /// Page of the document the note refers to (0 for the whole document)
+    pub page_ref: u32,
+    
+    /// Time the annotation was added
+    pub created_at: i64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl Annotation {
+    /// Calculate the space required for the account
+    pub const fn space(form_id_len: usize) -> usize {
+        8 + // discriminator
+        32 + // approval (Pubkey)
+        4 + form_id_len + // form_id (String)
+        2 + // index (u16)
+        32 + // author (Pubkey)
+        32 + // note_hash ([u8; 32])
+        4 + // page_ref (u32)
+        8 + // created_at (i64)
+        1 // bump (u8)
+    }
+}
+
+/// State account holding how often an admin wants to be reminded of pending signatures
+#[account]
+pub struct NotificationPrefs {
+    /// Admin the preferences belong to, also used in the PDA seeds
+    pub admin: Pubkey,
+    
+    /// Minimum seconds between reminders (0 disables reminders)
+    pub reminder_interval: i64,
+    
+    /// Whether reminders for long-pending approvals are flagged for escalation
+    pub escalation_opt_in: bool,
+    
+    /// SHA-256 hash of the webhook endpoint reminders should be delivered to
+    pub webhook_hash: [u8; 32],
+    
+    /// Time of the last reminder sent to the admin (0 if none)
+    pub last_reminded_at: i64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl NotificationPrefs {
+    /// Calculate the space required for the account
+    pub const fn space() -> usize {
+        8 + // discriminator
+        32 + // admin (Pubkey)
+        8 + // reminder_interval (i64)
+        1 + // escalation_opt_in (bool)
+        32 + // webhook_hash ([u8; 32])
+        8 + // last_reminded_at (i64)
+        1 // bump (u8)
+    }
+    
+    /// Whether the admin accepts a reminder at `now`
+    pub fn reminder_due(&self, now: i64) -> bool {
+        self.reminder_interval > 0 && now >= self.last_reminded_at.saturating_add(self.reminder_interval)
+    }
+}
+
+/// State account holding a named counterparty's signature over an approved form
+#[account]
+pub struct CounterSignature {
+    /// Counter-signed form, also used in the PDA seeds
+    pub form_id: String,
+    
+    /// Counterparty who signed, also used in the PDA seeds
+    pub signer: Pubkey,
+    
+    /// Document hash the counterparty signed
+    pub form_hash: [u8; 32],
+    
+    /// Time the counter-signature was recorded
+    pub signed_at: i64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl CounterSignature {
+    /// Calculate the space required for the account
+    pub const fn space(form_id_len: usize) -> usize {
+        8 + // discriminator
+        4 + form_id_len + // form_id (String)
+        32 + // signer (Pubkey)
+        32 + // form_hash ([u8; 32])
+        8 + // signed_at (i64)
+        1 // bump (u8)
+    }
+}
+
+/// State account holding SOL or SPL tokens until a form's approval settles them
+#[account]
+pub struct Escrow {
+    /// Form whose approval releases the funds, also used in the PDA seeds
+    pub form_id: String,
+    
+    /// Document hash the approval must cover for the funds to be released
+    pub form_hash: [u8; 32],
+    
+    /// Wallet that deposited the funds and receives refunds, also used in the PDA seeds
+    pub depositor: Pubkey,
+    
+    /// Wallet paid when the form is approved
+    pub counterparty: Pubkey,
+    
+    /// Token mint of the deposit (default for native SOL)
+    pub mint: Pubkey,
+    
+    /// Deposited amount, in lamports or base token units
+    pub amount: u64,
+    
+    /// Time after which the deposit is refunded unless the form is approved
+    pub refund_after: i64,
+    
+    /// Time the escrow was funded
+    pub created_at: i64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl Escrow {
+    /// Calculate the space required for the account
+    pub const fn space(form_id_len: usize) -> usize {
+        8 + // discriminator
+        4 + form_id_len + // form_id (String)
+        32 + // form_hash ([u8; 32])
+        32 + // depositor (Pubkey)
+        32 + // counterparty (Pubkey)
+        32 + // mint (Pubkey)
+        8 + // amount (u64)
+        8 + // refund_after (i64)
+        8 + // created_at (i64)
+        1 // bump (u8)
+    }
+    
+    /// Whether the deposit is native SOL rather than SPL tokens
+    pub fn is_native(&self) -> bool {
+        self.mint == Pubkey::default()
+    }
+    
+    /// Whether settling at `now` releases the funds (`true`) or refunds them (`false`)
+    pub fn releases(&self, approval: Option<&FormApproval>, rejected: bool, now: i64) -> Result<bool> {
+        if approval.is_some_and(|approval| approval.is_valid_for(&self.form_hash, now)) {
+            return Ok(true);
+        }
+        
+        let lapsed = approval.is_some_and(|approval| {
+            approval.is_expired(now)
+                || matches!(approval.status, ApprovalStatus::Rejected | ApprovalStatus::Expired)
+        });
+        require!(
+            rejected || lapsed || now >= self.refund_after,
+            crate::config::FormApprovalError::EscrowNotSettleable
+        );
+        Ok(false)
+    }
+}
+
+/// State account recording that a signer accepted the consent disclosure for a form
+#[account]
+pub struct ConsentRecord {
+    /// Signer who gave consent, also used in the PDA seeds
+    pub signer: Pubkey,
+    
+    /// Form the consent applies to, also used in the PDA seeds
+    pub form_id: String,
+    
+    /// SHA-256 hash of the consent disclosure the signer accepted
+    pub disclosure_hash: [u8; 32],
+    
+    /// Time consent was recorded
+    pub consented_at: i64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl ConsentRecord {
+    /// Calculate the space required for the account
+    pub const fn space(form_id_len: usize) -> usize {
+        8 + // discriminator
+        32 + // signer (Pubkey)
+        4 + form_id_len + // form_id (String)
+        32 + // disclosure_hash ([u8; 32])
+        8 + // consented_at (i64)
+        1 // bump (u8)
+    }
+    
+    /// Check that consent was recorded when the program requires it
+    pub fn check_required(record: Option<&ConsentRecord>, strict: bool) -> Result<()> {
+        require!(
+            !strict || record.is_some(),
+            crate::config::FormApprovalError::ConsentNotRecorded
+        );
+        Ok(())
+    }
+}
+
+/// State account indexing an approval by the hash of an external reference
+#[account]
+pub struct SearchKey {
+    /// Hash of the external reference (e.g. an HR employee ID or case number), also used in the PDA seeds
+    pub key_hash: [u8; 32],
+    
+    /// Approval the reference points to
+    pub approval: Pubkey,
+    
+    /// Form ID of the approval, so the record can be read without another lookup
+    pub form_id: String,
+    
+    /// Admin that created the index entry and receives the rent back
+    pub created_by: Pubkey,
+    
+    /// Time the index entry was created
+    pub created_at: i64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl SearchKey {
+    /// Calculate the space required for the account
+    pub const fn space(form_id_len: usize) -> usize {
+        8 + // discriminator
+        32 + // key_hash ([u8; 32])
+        32 + // approval (Pubkey)
+        4 + form_id_len + // form_id (String)
+        32 + // created_by (Pubkey)
+        8 + // created_at (i64)
+        1 // bump (u8)
+    }
+}
+
+/// State account pointing from a form hash to the first approval anchored over it
+#[account]
+pub struct HashIndex {
+    /// Indexed form hash, also used in the PDA seeds
+    pub form_hash: [u8; 32],
+    
+    /// Approval the hash was first signed under
+    pub approval: Pubkey,
+    
+    /// Form ID of the approval, so the record can be found without another lookup
+    pub form_id: String,
+    
+    /// Time the hash was first anchored
+    pub anchored_at: i64,
+    
+    /// Later approvals signed over the same hash under other form IDs
+    pub duplicate_count: u32,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl HashIndex {
+    /// Calculate the space required for the account
+    pub const fn space() -> usize {
+        8 + // discriminator
+        32 + // form_hash ([u8; 32])
+        32 + // approval (Pubkey)
+        4 + crate::config::Config::MAX_FORM_ID_LENGTH + // form_id (String)
+        8 + // anchored_at (i64)
+        4 + // duplicate_count (u32)
+        1 // bump (u8)
+    }
+    
+    /// Point a new index entry at `approval`, leaving an existing entry on its first approval
+    ///
+    /// Returns the first approval when the hash was already anchored under a different one.
+    pub fn anchor(&mut self, form_hash: [u8; 32], form_id: &str, approval: Pubkey, now: i64, bump: u8) -> Option<Pubkey> {
+        if self.approval == Pubkey::default() {
+            self.form_hash = form_hash;
+            self.approval = approval;
+            self.form_id = form_id.to_string();
+            self.anchored_at = now;
+            self.bump = bump;
+            return None;
+        }
+        if self.approval == approval {
+            return None;
+        }
+        self.duplicate_count += 1;
+        Some(self.approval)
+    }
+}
+
+/// State account for a tenant with its own form ID namespace
+#[account]
+pub struct Organization {
+    /// Organization identifier, also used in the PDA seeds
+    pub org_id: String,
+    
+    /// Key that manages the organization and its admins, and signs its forms
+    pub authority: Pubkey,
+    
+    /// Time the organization was created
+    pub created_at: i64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+    
+    /// Keys besides the authority allowed to sign the organization's forms
+    pub admins: Vec<Pubkey>,
+    
+    /// Forms signed in the organization's namespace
+    pub total_forms_signed: u64,
+    
+    /// Approvals in the organization's namespace that were revoked
+    pub total_revoked: u64,
+    
+    /// Time the organization last signed a form (0 if never)
+    pub last_signed_at: i64,
+    
+    /// Time the program authority suspended the organization (0 while active)
+    pub suspended_at: i64,
+    
+    /// Hash of the reason given for the latest suspension (zero if never suspended)
+    pub suspension_reason_hash: [u8; 32],
+}
+
+impl Organization {
+    /// Calculate the space required for the account
+    pub const fn space(org_id_len: usize) -> usize {
+        8 + // discriminator
+        4 + org_id_len + // org_id (String)
+        32 + // authority (Pubkey)
+        8 + // created_at (i64)
+        1 + // bump (u8)
+        4 + (32 * crate::config::Config::MAX_ADMINS) + // admins (Vec<Pubkey>)
+        8 + // total_forms_signed (u64)
+        8 + // total_revoked (u64)
+        8 + // last_signed_at (i64)
+        8 + // suspended_at (i64)
+        32 // suspension_reason_hash ([u8; 32])
+    }
+    
+    /// Whether the program authority suspended the organization
+    pub fn is_suspended(&self) -> bool {
+        self.suspended_at != 0
+    }
+    
+    /// Count a form signed in the organization at `now`
+    pub fn record_signing(&mut self, now: i64) {
+        self.total_forms_signed += 1;
+        self.last_signed_at = now;
+    }
+    
+    /// Whether `key` may sign the organization's forms
+    pub fn is_admin(&self, key: &Pubkey) -> bool {
+        self.authority == *key || self.admins.contains(key)
+    }
+    
+    /// Add an admin to the organization
+    pub fn add_admin(&mut self, admin: Pubkey) -> Result<()> {
+        require!(!self.is_admin(&admin), crate::config::FormApprovalError::AdminAlreadyExists);
+        require!(
+            self.admins.len() < crate::config::Config::MAX_ADMINS,
+            crate::config::FormApprovalError::MaxAdminsReached
+        );
+        self.admins.push(admin);
+        Ok(())
+    }
+    
+    /// Remove an admin from the organization; the authority itself cannot be removed
+    pub fn remove_admin(&mut self, admin: &Pubkey) -> Result<()> {
+        let index = self
+            .admins
+            .iter()
+            .position(|listed| listed == admin)
+            .ok_or(crate::config::FormApprovalError::AdminNotFound)?;
+        self.admins.remove(index);
+        Ok(())
+    }
+    
+    /// Whether `org_id` can be used as an organization PDA seed
+    pub fn is_valid_org_id(org_id: &str) -> bool {
+        !org_id.is_empty() && org_id.len() <= crate::config::Config::MAX_ORG_ID_LENGTH
+    }
+}
+
+/// State account overriding the threshold policy for one form category
+#[account]
+pub struct CategoryPolicy {
+    /// Category name, also used in the PDA seeds
+    pub category: String,
+    
+    /// Policy applied to forms of this category
+    pub policy: ThresholdPolicy,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl CategoryPolicy {
+    /// Calculate the space required for the account
+    pub const fn space(category_len: usize) -> usize {
+        8 + // discriminator
+        4 + category_len + // category (String)
+        ThresholdPolicy::SIZE + // policy (ThresholdPolicy)
+        1 // bump (u8)
+    }
+}
+
+/// Progress of a form's per-signer approvals toward the threshold
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TallyStatus {
+    Pending,
+    Approved,
+}
+
+/// State account summarizing the per-signer approvals of a form
+#[account]
+pub struct ApprovalTally {
+    /// Hash every counted signer approved
+    pub form_hash: [u8; 32],
+    
+    /// Number of signatures counted
+    pub signature_count: u32,
+    
+    /// Sum of the weights of the counted signatures
+    pub weighted_sum: u32,
+    
+    /// Policy snapshotted when the first signature arrived
+    pub policy: ThresholdPolicy,
+    
+    /// Signers counted so far
+    pub signers: Vec<Pubkey>,
+    
+    /// Weight each signer held when signing, parallel to `signers`
+    pub weights: Vec<u16>,
+    
+    /// Whether the threshold has been met
+    pub status: TallyStatus,
+    
+    /// Time the threshold was met (0 while pending)
+    pub approved_at: i64,
+    
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl ApprovalTally {
+    /// Check that the counters agree with the recorded signers and the status with the approval time
+    pub fn is_consistent(&self) -> bool {
+        let weighted_sum: u32 = self.weights.iter().map(|weight| *weight as u32).sum();
+        let status_ok = match self.status {
+            TallyStatus::Pending => self.approved_at == 0,
+            TallyStatus::Approved => self.approved_at != 0,
+        };
+        
+        self.signature_count as usize == self.signers.len()
+            && self.weights.len() == self.signers.len()
+            && self.weighted_sum == weighted_sum
+            && status_ok
+    }
+    
+    /// Calculate the space required for the account
+    pub const fn space() -> usize {
+        8 + // discriminator
+        32 + // form_hash ([u8; 32])
+        4 + // signature_count (u32)
+        4 + // weighted_sum (u32)
+        ThresholdPolicy::SIZE + // policy (ThresholdPolicy)
+        4 + (32 * crate::config::Config::MAX_ADMINS) + // signers (Vec<Pubkey>)
+        4 + (2 * crate::config::Config::MAX_ADMINS) + // weights (Vec<u16>)
+        1 + // status (TallyStatus)
+        8 + // approved_at (i64)
+        1 // bump (u8)
+    }
+    
+    /// Count a signature; finalization is left to `try_finalize`
+    pub fn record_signature(
+        &mut self,
+        signer: Pubkey,
+        form_hash: [u8; 32],
+        weight: u16,
+    ) -> Result<()> {
+        require!(
+            self.form_hash == form_hash,
+            crate::config::FormApprovalError::TallyHashMismatch
+        );
+        require!(
+            self.signers.len() < crate::config::Config::MAX_ADMINS,
+            crate::config::FormApprovalError::MaxAdminsReached
+        );
+        
+        self.signers.push(signer);
+        self.weights.push(weight);
+        self.signature_count += 1;
+        self.weighted_sum = self.weighted_sum.saturating_add(weight as u32);
+        Ok(())
+    }
+    
+    /// Remove a signature before the form is finalized
+    pub fn withdraw_signature(&mut self, signer: &Pubkey) -> Result<()> {
+        require!(
+            self.status == TallyStatus::Pending,
+            crate::config::FormApprovalError::TallyAlreadyFinalized
+        );
+        let index = self
+            .signers
+            .iter()
+            .position(|counted| counted == signer)
+            .ok_or(crate::config::FormApprovalError::SignatureNotFound)?;
+        
+        self.signers.remove(index);
+        let weight = self.weights.remove(index);
+        self.signature_count -= 1;
+        self.weighted_sum = self.weighted_sum.saturating_sub(weight as u32);
+        Ok(())
+    }
+    
+    /// Flip the tally to approved if its policy is met, returning true if it did
+    pub fn try_finalize(&mut self, now: i64) -> bool {
+        if self.status != TallyStatus::Pending || !self.policy.is_met(self) {
+            return false;
+        }
+        self.status = TallyStatus::Approved;
+        self.approved_at = now;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// Account state with every field zeroed, as freshly allocated space deserializes
+    fn zeroed<T: AnchorDeserialize>(space: usize) -> T {
+        T::deserialize(&mut &vec![0u8; space - 8][..]).unwrap()
+    }
+    
+    /// Admin config with `admins` added under a live authority
+    fn admin_config(admins: &[Pubkey]) -> AdminConfig {
+        let mut config: AdminConfig = zeroed(AdminConfig::space());
+        config.authority = Pubkey::new_unique();
+        for admin in admins {
+            config.add_admin(*admin).unwrap();
+        }
+        config
+    }
+    
+    fn legacy_approval() -> FormApprovalV1 {
+        FormApprovalV1 {
+            form_id: "legacy_form".to_string(),
+            form_hash: [7u8; 32],
+            signer: Pubkey::new_unique(),
+            approved_at: 1_700_000_000,
+            metadata: "Signed before versioning".to_string(),
+            bump: 254,
+        }
+    }
+    
+    fn legacy_account_data(legacy: &FormApprovalV1) -> Vec<u8> {
+        let mut data = FormApproval::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut data).unwrap();
+        data
+    }
+    
+    #[test]
+    fn migrating_a_legacy_approval_keeps_its_fields() {
+        let legacy = legacy_approval();
+        let signer = legacy.signer;
+        let approval = FormApproval::from(legacy);
+        
+        assert_eq!(approval.form_id, "legacy_form");
+        assert_eq!(approval.form_hash, [7u8; 32]);
+        assert_eq!(approval.signer, signer);
+        assert_eq!(approval.approved_at, 1_700_000_000);
+        assert_eq!(approval.metadata, "Signed before versioning");
+        assert_eq!(approval.bump, 254);
+        assert_eq!(approval.program_version, SemVer::default());
+        assert_eq!(approval.status, ApprovalStatus::Approved);
+        assert_eq!(approval.required_signatures, 1);
+        assert_eq!(approval.organization, Pubkey::default());
+    }
+    
+    #[test]
+    fn legacy_data_only_deserializes_as_v1() {
+        let data = legacy_account_data(&legacy_approval());
+        
+        assert!(FormApproval::try_deserialize(&mut &data[..]).is_err());
+        assert!(FormApprovalV1::deserialize(&mut &data[8..]).is_ok());
+    }
+    
+    #[test]
+    fn migrated_approval_fits_its_space_and_round_trips() {
+        let data = legacy_account_data(&legacy_approval());
+        let approval: FormApproval = FormApprovalV1::deserialize(&mut &data[8..]).unwrap().into();
+        
+        let mut migrated = Vec::new();
+        approval.try_serialize(&mut migrated).unwrap();
+        assert!(migrated.len() <= FormApproval::space(approval.form_id.len(), approval.metadata.len()));
+        
+        let reread = FormApproval::try_deserialize(&mut &migrated[..]).unwrap();
+        assert_eq!(reread.form_id, approval.form_id);
+        assert_eq!(reread.form_hash, approval.form_hash);
+        assert_eq!(reread.signer, approval.signer);
+    }
+    
+    fn checkpoint(required_attestations: u8, creator: Pubkey) -> Checkpoint {
+        let mut checkpoint: Checkpoint = zeroed(Checkpoint::space());
+        checkpoint.required_attestations = required_attestations;
+        checkpoint.attesters = vec![creator];
+        checkpoint
+    }
+    
+    #[test]
+    fn checkpoint_finalizes_once_the_quorum_attests() {
+        let mut checkpoint = checkpoint(3, Pubkey::new_unique());
+        
+        assert!(!checkpoint.attest(Pubkey::new_unique(), 100).unwrap());
+        assert!(!checkpoint.is_finalized());
+        assert!(checkpoint.attest(Pubkey::new_unique(), 200).unwrap());
+        assert!(checkpoint.is_finalized());
+        assert_eq!(checkpoint.finalized_at, 200);
+        assert_eq!(checkpoint.attesters.len(), 3);
+    }
+    
+    #[test]
+    fn checkpoint_rejects_repeat_and_late_attestations() {
+        let creator = Pubkey::new_unique();
+        let mut checkpoint = checkpoint(2, creator);
+        
+        assert!(checkpoint.attest(creator, 100).is_err());
+        assert!(checkpoint.attest(Pubkey::new_unique(), 100).unwrap());
+        assert!(checkpoint.attest(Pubkey::new_unique(), 200).is_err());
+        assert_eq!(checkpoint.finalized_at, 100);
+    }
+    
+    #[test]
+    fn checkpoint_quorum_must_fit_the_admin_count() {
+        let admins = admin_config(&[Pubkey::new_unique(), Pubkey::new_unique()]);
+        let mut program_config: ProgramConfig = zeroed(ProgramConfig::space());
+        
+        program_config.checkpoint_quorum = 2;
+        assert!(program_config.require_quorum_reachable(&admins).is_ok());
+        program_config.checkpoint_quorum = 3;
+        assert!(program_config.require_quorum_reachable(&admins).is_err());
+    }
+    
+    fn tally(form_hash: [u8; 32], policy: ThresholdPolicy) -> ApprovalTally {
+        let mut tally: ApprovalTally = zeroed(ApprovalTally::space());
+        tally.form_hash = form_hash;
+        tally.policy = policy;
+        tally
+    }
+    
+    #[test]
+    fn tally_counts_each_signature() {
+        let mut tally = tally([1u8; 32], ThresholdPolicy::Count { required: 2 });
+        let signer = Pubkey::new_unique();
+        
+        tally.record_signature(signer, [1u8; 32], 1).unwrap();
+        tally.record_signature(Pubkey::new_unique(), [1u8; 32], 1).unwrap();
+        
+        assert_eq!(tally.signature_count, 2);
+        assert_eq!(tally.signers[0], signer);
+        assert_eq!(tally.status, TallyStatus::Pending);
+    }
+    
+    #[test]
+    fn tally_rejects_signatures_over_another_hash() {
+        let mut tally = tally([1u8; 32], ThresholdPolicy::Count { required: 1 });
+        
+        assert!(tally.record_signature(Pubkey::new_unique(), [2u8; 32], 1).is_err());
+        assert_eq!(tally.signature_count, 0);
+    }
+    
+    #[test]
+    fn tally_holds_at_most_max_admins_signers() {
+        let mut tally = tally([1u8; 32], ThresholdPolicy::Count { required: 1 });
+        for _ in 0..crate::config::Config::MAX_ADMINS {
+            tally.record_signature(Pubkey::new_unique(), [1u8; 32], 1).unwrap();
+        }
+        
+        assert!(tally.record_signature(Pubkey::new_unique(), [1u8; 32], 1).is_err());
+    }
+    
+    #[test]
+    fn admin_weights_default_to_one_and_sum_up() {
+        let (first, second) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut config = admin_config(&[first, second]);
+        
+        assert_eq!(config.weight_of(&first), 1);
+        config.set_weight(&second, 3).unwrap();
+        assert_eq!(config.weight_of(&second), 3);
+        assert_eq!(config.weight_of(&Pubkey::new_unique()), 0);
+        assert_eq!(config.total_weight(), 4);
+    }
+    
+    #[test]
+    fn admin_weight_must_be_positive_and_belong_to_an_admin() {
+        let admin = Pubkey::new_unique();
+        let mut config = admin_config(&[admin]);
+        
+        assert!(config.set_weight(&admin, 0).is_err());
+        assert!(config.set_weight(&Pubkey::new_unique(), 2).is_err());
+        assert_eq!(config.weight_of(&admin), 1);
+    }
+    
+    #[test]
+    fn removing_an_admin_moves_the_last_weight_into_its_slot() {
+        let (first, second, third) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let mut config = admin_config(&[first, second, third]);
+        config.set_weight(&third, 5).unwrap();
+        
+        config.remove_admin(&first).unwrap();
+        
+        assert_eq!(config.weight_of(&third), 5);
+        assert_eq!(config.weights[2], 0);
+        assert_eq!(config.total_weight(), 6);
+    }
+    
+    #[test]
+    fn tally_sums_signer_weights() {
+        let mut tally = tally([1u8; 32], ThresholdPolicy::WeightedSum { required: 4 });
+        tally.record_signature(Pubkey::new_unique(), [1u8; 32], 3).unwrap();
+        tally.record_signature(Pubkey::new_unique(), [1u8; 32], 2).unwrap();
+        
+        assert_eq!(tally.weighted_sum, 5);
+        assert_eq!(tally.weights, vec![3, 2]);
+    }
+    
+    #[test]
+    fn threshold_policy_must_be_reachable_by_the_admins() {
+        let (first, second) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut config = admin_config(&[first, second]);
+        config.set_weight(&second, 4).unwrap();
+        
+        assert!(ThresholdPolicy::Count { required: 2 }.validate(&config).is_ok());
+        assert!(ThresholdPolicy::Count { required: 3 }.validate(&config).is_err());
+        assert!(ThresholdPolicy::Count { required: 0 }.validate(&config).is_err());
+        assert!(ThresholdPolicy::WeightedSum { required: 5 }.validate(&config).is_ok());
+        assert!(ThresholdPolicy::WeightedSum { required: 6 }.validate(&config).is_err());
+        assert!(ThresholdPolicy::RequiredSignerPlusCount { required_signer: first, required: 2 }
+            .validate(&config)
+            .is_ok());
+        assert!(ThresholdPolicy::RequiredSignerPlusCount { required_signer: Pubkey::new_unique(), required: 1 }
+            .validate(&config)
+            .is_err());
+    }
+    
+    #[test]
+    fn threshold_policy_is_met_by_the_tally() {
+        let officer = Pubkey::new_unique();
+        let mut tally = tally([1u8; 32], ThresholdPolicy::Count { required: 2 });
+        tally.record_signature(Pubkey::new_unique(), [1u8; 32], 1).unwrap();
+        tally.record_signature(Pubkey::new_unique(), [1u8; 32], 2).unwrap();
+        
+        assert!(ThresholdPolicy::Count { required: 2 }.is_met(&tally));
+        assert!(!ThresholdPolicy::Count { required: 3 }.is_met(&tally));
+        assert!(ThresholdPolicy::WeightedSum { required: 3 }.is_met(&tally));
+        assert!(!ThresholdPolicy::WeightedSum { required: 4 }.is_met(&tally));
+        
+        let officer_policy = ThresholdPolicy::RequiredSignerPlusCount { required_signer: officer, required: 2 };
+        assert!(!officer_policy.is_met(&tally));
+        tally.record_signature(officer, [1u8; 32], 1).unwrap();
+        assert!(officer_policy.is_met(&tally));
+    }
+    
+    #[test]
+    fn try_finalize_waits_for_the_policy() {
+        let mut tally = tally([1u8; 32], ThresholdPolicy::Count { required: 2 });
+        tally.record_signature(Pubkey::new_unique(), [1u8; 32], 1).unwrap();
+        
+        assert!(!tally.try_finalize(100));
+        assert_eq!(tally.status, TallyStatus::Pending);
+        assert_eq!(tally.approved_at, 0);
+        
+        tally.record_signature(Pubkey::new_unique(), [1u8; 32], 1).unwrap();
+        assert!(tally.try_finalize(200));
+        assert_eq!(tally.status, TallyStatus::Approved);
+        assert_eq!(tally.approved_at, 200);
+    }
+    
+    #[test]
+    fn try_finalize_only_flips_a_tally_once() {
+        let mut tally = tally([1u8; 32], ThresholdPolicy::Count { required: 1 });
+        tally.record_signature(Pubkey::new_unique(), [1u8; 32], 1).unwrap();
+        
+        assert!(tally.try_finalize(100));
+        assert!(!tally.try_finalize(200));
+        assert_eq!(tally.approved_at, 100);
+    }
+    
+    #[test]
+    fn withdrawing_a_signature_takes_back_its_weight() {
+        let (staying, leaving) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut tally = tally([1u8; 32], ThresholdPolicy::Count { required: 3 });
+        tally.record_signature(staying, [1u8; 32], 2).unwrap();
+        tally.record_signature(leaving, [1u8; 32], 3).unwrap();
+        
+        tally.withdraw_signature(&leaving).unwrap();
+        
+        assert_eq!(tally.signers, vec![staying]);
+        assert_eq!(tally.weights, vec![2]);
+        assert_eq!(tally.signature_count, 1);
+        assert_eq!(tally.weighted_sum, 2);
+        assert!(tally.withdraw_signature(&leaving).is_err());
+    }
+    
+    #[test]
+    fn signatures_cannot_be_withdrawn_after_finalization() {
+        let signer = Pubkey::new_unique();
+        let mut tally = tally([1u8; 32], ThresholdPolicy::Count { required: 1 });
+        tally.record_signature(signer, [1u8; 32], 1).unwrap();
+        assert!(tally.try_finalize(100));
+        
+        assert!(tally.withdraw_signature(&signer).is_err());
+        assert_eq!(tally.signature_count, 1);
+    }
+    
+    #[test]
+    fn admin_config_consistency_follows_add_and_remove() {
+        let (first, second) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut config = admin_config(&[first, second]);
+        assert!(config.is_consistent());
+        
+        config.remove_admin(&first).unwrap();
+        assert!(config.is_consistent());
+        
+        let empty: AdminConfig = zeroed(AdminConfig::space());
+        assert!(!empty.is_consistent());
+    }
+    
+    #[test]
+    fn admin_config_consistency_catches_corrupted_slots() {
+        let (first, second) = (Pubkey::new_unique(), Pubkey::new_unique());
+        
+        let mut duplicated = admin_config(&[first, second]);
+        duplicated.admins[1] = first;
+        assert!(!duplicated.is_consistent());
+        
+        let mut weightless = admin_config(&[first, second]);
+        weightless.weights[0] = 0;
+        assert!(!weightless.is_consistent());
+        
+        let mut stale = admin_config(&[first]);
+        stale.weights[1] = 1;
+        assert!(!stale.is_consistent());
+    }
+    
+    #[test]
+    fn tally_consistency_checks_counters_and_status() {
+        let mut tally = tally([1u8; 32], ThresholdPolicy::Count { required: 1 });
+        tally.record_signature(Pubkey::new_unique(), [1u8; 32], 2).unwrap();
+        assert!(tally.is_consistent());
+        assert!(tally.try_finalize(100));
+        assert!(tally.is_consistent());
+        
+        tally.weighted_sum = 3;
+        assert!(!tally.is_consistent());
+        tally.weighted_sum = 2;
+        tally.approved_at = 0;
+        assert!(!tally.is_consistent());
+    }
+    
+    #[test]
+    fn signer_limit_bounds_count_policies_only() {
+        assert!(ThresholdPolicy::Count { required: 3 }.fits_signer_limit(3));
+        assert!(!ThresholdPolicy::Count { required: 4 }.fits_signer_limit(3));
+        assert!(!ThresholdPolicy::RequiredSignerPlusCount { required_signer: Pubkey::new_unique(), required: 4 }
+            .fits_signer_limit(3));
+        assert!(ThresholdPolicy::WeightedSum { required: 40 }.fits_signer_limit(3));
+    }
+    
+    fn approval() -> FormApproval {
+        FormApproval::from(legacy_approval())
+    }
+    
+    #[test]
+    fn approval_awaits_its_counterparty_org_until_countersigned() {
+        let mut approval = approval();
+        assert!(!approval.awaits_counterparty_org());
+        
+        approval.counterparty_org = Pubkey::new_unique();
+        assert!(approval.awaits_counterparty_org());
+        
+        approval.counterparty_org_signer = Pubkey::new_unique();
+        assert!(!approval.awaits_counterparty_org());
+    }
+    
+    #[test]
+    fn recording_an_approval_clears_its_counterparty_org() {
+        let mut approval = approval();
+        approval.counterparty_org = Pubkey::new_unique();
+        approval.counterparty_org_signer = Pubkey::new_unique();
+        let clock = Clock { unix_timestamp: 100, ..Clock::default() };
+        
+        approval.record("fresh_form".to_string(), [3u8; 32], Pubkey::new_unique(), String::new(), 255, &clock);
+        
+        assert_eq!(approval.counterparty_org, Pubkey::default());
+        assert_eq!(approval.counterparty_org_signer, Pubkey::default());
+    }
+    
+    #[test]
+    fn co_signatures_put_the_approval_in_force_at_the_quorum() {
+        let mut approval = approval();
+        let form_hash = approval.form_hash;
+        approval.require_signatures(3);
+        assert_eq!(approval.status, ApprovalStatus::Pending);
+        assert!(!approval.is_valid_for(&form_hash, 0));
+        
+        approval.co_sign(Pubkey::new_unique()).unwrap();
+        assert_eq!(approval.status, ApprovalStatus::Pending);
+        approval.co_sign(Pubkey::new_unique()).unwrap();
+        
+        assert_eq!(approval.signature_count(), 3);
+        assert_eq!(approval.status, ApprovalStatus::Approved);
+        assert!(approval.is_valid_for(&form_hash, 0));
+    }
+    
+    #[test]
+    fn approvals_refuse_repeat_and_late_co_signatures() {
+        let mut approval = approval();
+        approval.require_signatures(2);
+        let co_signer = Pubkey::new_unique();
+        
+        assert!(approval.co_sign(approval.signer).is_err());
+        approval.co_sign(co_signer).unwrap();
+        assert!(approval.co_sign(Pubkey::new_unique()).is_err());
+        assert_eq!(approval.co_signers, vec![co_signer]);
+    }
+    
+    #[test]
+    fn approval_lifecycle_allows_only_listed_transitions() {
+        use ApprovalStatus::*;
+        
+        assert!(Pending.can_transition_to(Approved));
+        assert!(Approved.can_transition_to(Revoked));
+        assert!(Revoked.can_transition_to(Approved));
+        assert!(Expired.can_transition_to(Pending));
+        assert!(!Rejected.can_transition_to(Approved));
+        assert!(!Approved.can_transition_to(Pending));
+        assert!(!Approved.can_transition_to(Approved));
+    }
+    
+    #[test]
+    fn transition_leaves_the_status_alone_when_refused() {
+        let mut approval = approval();
+        approval.transition(ApprovalStatus::Revoked).unwrap();
+        assert_eq!(approval.status, ApprovalStatus::Revoked);
+        
+        approval.status = ApprovalStatus::Rejected;
+        assert!(approval.transition(ApprovalStatus::Approved).is_err());
+        assert_eq!(approval.status, ApprovalStatus::Rejected);
+    }
+    
+    fn organization(org_id: &str) -> Organization {
+        let mut organization: Organization = zeroed(Organization::space(0));
+        organization.org_id = org_id.to_string();
+        organization.authority = Pubkey::new_unique();
+        organization
+    }
+    
+    #[test]
+    fn organization_is_suspended_while_stamped() {
+        let mut organization = organization("tenant_a");
+        assert!(!organization.is_suspended());
+        
+        organization.suspended_at = 100;
+        organization.suspension_reason_hash = [9u8; 32];
+        assert!(organization.is_suspended());
+        
+        organization.suspended_at = 0;
+        assert!(!organization.is_suspended());
+    }
+    
+    #[test]
+    fn signing_progress_splits_required_signers() {
+        let (legal, finance, outsider) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let mut approval = approval();
+        approval.required_signers = vec![legal, finance];
+        
+        approval.mark_signed(&finance);
+        approval.mark_signed(&outsider);
+        
+        assert_eq!(approval.signatures_collected, 0b10);
+        let progress = approval.signing_progress();
+        assert_eq!(progress.signed, vec![finance]);
+        assert_eq!(progress.outstanding, vec![legal]);
+    }
+    
+    #[test]
+    fn new_admins_get_the_default_permissions() {
+        let admin = Pubkey::new_unique();
+        let config = admin_config(&[admin]);
+        
+        assert!(config.has_permission(&admin, AdminConfig::CAN_SIGN | AdminConfig::CAN_REVOKE));
+        assert!(!config.has_permission(&admin, AdminConfig::CAN_MANAGE_ADMINS));
+        assert!(!config.has_permission(&Pubkey::new_unique(), AdminConfig::CAN_SIGN));
+        assert!(config.can_manage_admins(&config.authority));
+        assert!(!config.can_manage_admins(&admin));
+    }
+    
+    #[test]
+    fn permissions_can_be_replaced_with_defined_flags_only() {
+        let admin = Pubkey::new_unique();
+        let mut config = admin_config(&[admin]);
+        
+        config.set_permissions(&admin, AdminConfig::CAN_MANAGE_ADMINS).unwrap();
+        assert!(config.can_manage_admins(&admin));
+        assert!(!config.has_permission(&admin, AdminConfig::CAN_SIGN));
+        
+        assert!(config.set_permissions(&admin, 1 << 7).is_err());
+        assert!(config.set_permissions(&Pubkey::new_unique(), AdminConfig::CAN_SIGN).is_err());
+        assert_eq!(config.permissions[0], AdminConfig::CAN_MANAGE_ADMINS);
+    }
+    
+    #[test]
+    fn renouncing_the_authority_freezes_the_admin_set() {
+        let admin = Pubkey::new_unique();
+        let mut config = admin_config(&[admin, Pubkey::new_unique()]);
+        config.authority = AdminConfig::RENOUNCED_AUTHORITY;
+        
+        assert!(config.is_renounced());
+        assert!(!config.can_manage_admins(&AdminConfig::RENOUNCED_AUTHORITY));
+        assert!(config.add_admin(Pubkey::new_unique()).is_err());
+        assert!(config.remove_admin(&admin).is_err());
+        assert!(config.replace_admin(&admin, Pubkey::new_unique()).is_err());
+        assert!(config.set_weight(&admin, 2).is_err());
+        assert!(config.set_permissions(&admin, AdminConfig::CAN_SIGN).is_err());
+        assert_eq!(config.admin_count, 2);
+    }
+    
+    #[test]
+    fn admin_rights_lapse_at_their_expiry() {
+        let (lapsing, permanent) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut config = admin_config(&[lapsing, permanent]);
+        config.set_expiry(&lapsing, 1_000).unwrap();
+        
+        assert!(config.is_admin_at(&lapsing, 999));
+        assert!(!config.is_admin_at(&lapsing, 1_000));
+        assert!(config.is_admin_at(&permanent, i64::MAX));
+        assert_eq!(config.slot_of(&lapsing), Some(0));
+        assert!(config.set_expiry(&Pubkey::new_unique(), 1_000).is_err());
+    }
+    
+    #[test]
+    fn removing_an_admin_clears_its_expiry_slot() {
+        let (first, second) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut config = admin_config(&[first, second]);
+        config.set_expiry(&second, 1_000).unwrap();
+        
+        config.remove_admin(&first).unwrap();
+        
+        assert_eq!(config.expires_at, [1_000, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert!(config.is_consistent());
+    }
+    
+    fn admin_record(admin: Pubkey, permissions: u8) -> AdminRecord {
+        let mut record: AdminRecord = zeroed(AdminRecord::space());
+        record.admin = admin;
+        record.permissions = permissions;
+        record
+    }
+    
+    #[test]
+    fn admin_records_grant_rights_to_their_own_key_only() {
+        let (listed, registered) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let config = admin_config(&[listed]);
+        let record = admin_record(registered, AdminConfig::ALL_PERMISSIONS);
+        
+        assert!(config.is_admin_with_record(&registered, Some(&record)));
+        assert!(config.is_admin_with_record(&listed, None));
+        assert!(!config.is_admin_with_record(&Pubkey::new_unique(), Some(&record)));
+        assert!(config.can_manage_admins_with_record(&registered, Some(&record)));
+        assert!(!config.can_manage_admins_with_record(&registered, None));
+        assert!(!config.can_manage_admins_with_record(&listed, Some(&record)));
+    }
+    
+    #[test]
+    fn admin_record_permissions_are_checked() {
+        let registered = Pubkey::new_unique();
+        let config = admin_config(&[Pubkey::new_unique()]);
+        let record = admin_record(registered, AdminConfig::DEFAULT_PERMISSIONS);
+        
+        assert!(config.has_permission_with_record(&registered, Some(&record), AdminConfig::CAN_SIGN));
+        assert!(!config.can_manage_admins_with_record(&registered, Some(&record)));
+    }
+    
+    #[test]
+    fn admin_records_weigh_one_and_count_toward_the_total() {
+        let (listed, registered) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut config = admin_config(&[listed]);
+        config.set_weight(&listed, 3).unwrap();
+        let record = admin_record(registered, AdminConfig::DEFAULT_PERMISSIONS);
+        
+        assert_eq!(config.weight_of_with_record(&registered, Some(&record)), 1);
+        assert_eq!(config.weight_of_with_record(&listed, Some(&record)), 3);
+        assert_eq!(config.weight_of_with_record(&Pubkey::new_unique(), Some(&record)), 0);
+        
+        config.record_count = 2;
+        assert_eq!(config.total_weight(), 5);
+    }
+    
+    #[test]
+    fn admin_record_lapses_at_its_expiry() {
+        let mut record = admin_record(Pubkey::new_unique(), AdminConfig::DEFAULT_PERMISSIONS);
+        assert!(record.is_active(i64::MAX));
+        
+        record.expires_at = 1_000;
+        assert!(record.is_active(999));
+        assert!(!record.is_active(1_000));
+    }
+    
+    #[test]
+    fn signing_window_bounds_further_signatures() {
+        let mut approval = approval();
+        assert!(approval.check_signing_window(0).is_ok());
+        
+        approval.not_before = 100;
+        approval.not_after = 200;
+        assert!(approval.check_signing_window(99).is_err());
+        assert!(approval.check_signing_window(100).is_ok());
+        assert!(approval.check_signing_window(200).is_ok());
+        assert!(approval.check_signing_window(201).is_err());
+    }
+    
+    #[test]
+    fn org_ids_must_fit_a_seed() {
+        assert!(Organization::is_valid_org_id("tenant_a"));
+        assert!(!Organization::is_valid_org_id(""));
+        assert!(!Organization::is_valid_org_id(&"x".repeat(crate::config::Config::MAX_ORG_ID_LENGTH + 1)));
+    }
+    
+    #[test]
+    fn org_record_address_depends_on_the_organization() {
+        let (tenant_a, tenant_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (address, bump) = Pubkey::find_program_address(
+            &[crate::config::Config::ORG_FORM_APPROVAL_SEED, tenant_a.as_ref(), b"legacy_form"],
+            &crate::ID,
+        );
+        let mut approval = approval();
+        approval.bump = bump;
+        
+        assert!(!approval.is_org_record(&address, "legacy_form"));
+        approval.organization = tenant_a;
+        assert!(approval.is_org_record(&address, "legacy_form"));
+        assert!(!approval.is_org_record(&address, "other_form"));
+        approval.organization = tenant_b;
+        assert!(!approval.is_org_record(&address, "legacy_form"));
+    }
+    
+    #[test]
+    fn organization_admins_are_listed_once_beside_the_authority() {
+        let mut organization = organization("tenant_a");
+        let (authority, admin) = (organization.authority, Pubkey::new_unique());
+        
+        assert!(organization.is_admin(&authority));
+        assert!(organization.add_admin(authority).is_err());
+        organization.add_admin(admin).unwrap();
+        assert!(organization.add_admin(admin).is_err());
+        assert!(organization.is_admin(&admin));
+        
+        organization.remove_admin(&admin).unwrap();
+        assert!(!organization.is_admin(&admin));
+        assert!(organization.remove_admin(&authority).is_err());
+    }
+    
+    #[test]
+    fn organization_counts_its_signings() {
+        let mut organization = organization("tenant_a");
+        
+        organization.record_signing(100);
+        organization.record_signing(250);
+        
+        assert_eq!(organization.total_forms_signed, 2);
+        assert_eq!(organization.last_signed_at, 250);
+        assert_eq!(organization.total_revoked, 0);
+    }
+    
+    #[test]
+    fn signatures_are_counted_on_the_slot_or_else_the_record() {
+        let (listed, registered) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut config = admin_config(&[listed]);
+        let mut record = admin_record(registered, AdminConfig::DEFAULT_PERMISSIONS);
+        
+        config.record_signature(&listed, Some(&mut record), 100);
+        config.record_signature(&registered, Some(&mut record), 200);
+        
+        assert_eq!((config.signatures_count[0], config.last_signed_at[0]), (1, 100));
+        assert_eq!((record.signatures_count, record.last_signed_at), (1, 200));
+    }
+    
+    #[test]
+    fn replacing_an_admin_keeps_its_slot_but_not_its_activity() {
+        let (old_admin, new_admin) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut config = admin_config(&[old_admin]);
+        config.set_weight(&old_admin, 2).unwrap();
+        config.record_signature(&old_admin, None, 100);
+        
+        config.replace_admin(&old_admin, new_admin).unwrap();
+        
+        assert_eq!(config.slot_of(&new_admin), Some(0));
+        assert_eq!(config.weight_of(&new_admin), 2);
+        assert_eq!((config.signatures_count[0], config.last_signed_at[0]), (0, 0));
+    }
+    
+    #[test]
+    fn event_sequence_numbers_events_from_one() {
+        let mut sequence: EventSequence = zeroed(EventSequence::space());
+        
+        assert_eq!(sequence.advance(), 1);
+        assert_eq!(sequence.advance(), 2);
+        assert_eq!(sequence.last, 2);
+    }
+    
+    #[test]
+    fn approval_details_mirror_the_approval() {
+        let approval = approval();
+        let details = approval.details();
+        
+        assert_eq!(details.form_id, approval.form_id);
+        assert_eq!(details.form_hash, approval.form_hash);
+        assert_eq!(details.signer, approval.signer);
+        assert_eq!(details.approved_at, approval.approved_at);
+        assert_eq!(details.metadata, approval.metadata);
+    }
+    
+    #[test]
+    fn client_version_is_checked_against_the_minimum() {
+        let mut program_config: ProgramConfig = zeroed(ProgramConfig::space());
+        assert!(program_config.check_client_version(None).is_ok());
+        
+        program_config.min_client_version = SemVer { major: 1, minor: 2, patch: 0 };
+        assert!(program_config.check_client_version(None).is_err());
+        assert!(program_config.check_client_version(Some(SemVer { major: 1, minor: 1, patch: 9 })).is_err());
+        assert!(program_config.check_client_version(Some(SemVer { major: 1, minor: 2, patch: 0 })).is_ok());
+        assert!(program_config.check_client_version(Some(SemVer { major: 2, minor: 0, patch: 0 })).is_ok());
+    }
+    
+    #[test]
+    fn instructions_are_retired_and_restored_independently() {
+        let mut program_config: ProgramConfig = zeroed(ProgramConfig::space());
+        
+        program_config.set_deprecated(ProgramInstruction::AddAdmin, true);
+        program_config.set_deprecated(ProgramInstruction::InviteAdmin, true);
+        assert!(program_config.is_deprecated(ProgramInstruction::AddAdmin));
+        assert!(!program_config.is_deprecated(ProgramInstruction::RemoveAdmin));
+        
+        program_config.set_deprecated(ProgramInstruction::AddAdmin, false);
+        assert!(!program_config.is_deprecated(ProgramInstruction::AddAdmin));
+        assert!(program_config.is_deprecated(ProgramInstruction::InviteAdmin));
+    }
+    
+    #[test]
+    fn bare_hashes_only_verify_sha256_approvals() {
+        let mut approval = approval();
+        let form_hash = approval.form_hash;
+        assert!(approval.is_valid_for(&form_hash, 0));
+        
+        approval.hash_algorithm = HashAlgorithm::Keccak256;
+        assert!(!approval.is_valid_for(&form_hash, 0));
+        assert!(approval.is_valid_for_digest(HashAlgorithm::Keccak256, &form_hash, 0));
+        assert!(!approval.is_valid_for_digest(HashAlgorithm::Sha256, &form_hash, 0));
+    }
+    
+    #[test]
+    fn sha512_approvals_need_the_full_digest() {
+        let mut approval = approval();
+        let form_hash = approval.form_hash;
+        approval.hash_algorithm = HashAlgorithm::Sha512;
+        approval.form_hash_tail = [8u8; 32];
+        let mut digest = form_hash.to_vec();
+        digest.extend_from_slice(&[8u8; 32]);
+        
+        assert!(!approval.is_valid_for(&form_hash, 0));
+        assert!(!approval.is_valid_for_digest(HashAlgorithm::Sha512, &form_hash, 0));
+        assert!(approval.is_valid_for_digest(HashAlgorithm::Sha512, &digest, 0));
+        
+        approval.revoked_at = 1;
+        assert!(!approval.is_valid_for_digest(HashAlgorithm::Sha512, &digest, 0));
+    }
+}
@@ -0,0 +1,193 @@
+//! Generates typed mirrors of the program's errors, events and accounts.
+//!
+//! The lists are read straight from the program sources so the SDK cannot
+//! drift from the on-chain definitions when variants or events are added.
+
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const PROGRAM_SRC: &str = "../programs/sign-document-contract/src";
+
+fn main() {
+    let src = Path::new(PROGRAM_SRC);
+    let config = read(&src.join("config.rs"));
+    let lib = read(&src.join("lib.rs"));
+    let state = read(&src.join("state.rs"));
+
+    let errors = enum_variants(&config, "pub enum FormApprovalError");
+    let events = attributed_structs(&lib, "#[event]");
+    let accounts = attributed_structs(&state, "#[account]");
+
+    let mut out = String::new();
+    write_errors(&mut out, &errors);
+    write_events(&mut out, &events);
+    write_accounts(&mut out, &accounts);
+
+    let dest = PathBuf::from(env::var("OUT_DIR").unwrap()).join("generated.rs");
+    fs::write(dest, out).expect("failed to write generated.rs");
+}
+
+fn read(path: &Path) -> String {
+    println!("cargo:rerun-if-changed={}", path.display());
+    fs::read_to_string(path)
+        .unwrap_or_else(|err| panic!("failed to read {}: {err}", path.display()))
+}
+
+/// Unit variants of the enum whose declaration starts with `header`
+fn enum_variants(source: &str, header: &str) -> Vec<String> {
+    source
+        .lines()
+        .skip_while(|line| !line.trim_start().starts_with(header))
+        .skip(1)
+        .take_while(|line| line.trim() != "}")
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with("#[") && !line.starts_with("//"))
+        .map(|line| line.trim_end_matches(',').to_string())
+        .collect()
+}
+
+/// Names of the structs declared directly under `attribute`
+fn attributed_structs(source: &str, attribute: &str) -> Vec<String> {
+    let lines: Vec<&str> = source.lines().map(str::trim).collect();
+    lines
+        .windows(2)
+        .filter(|pair| pair[0] == attribute)
+        .filter_map(|pair| pair[1].strip_prefix("pub struct "))
+        .filter_map(|rest| rest.split_whitespace().next())
+        .map(str::to_string)
+        .collect()
+}
+
+fn write_errors(out: &mut String, errors: &[String]) {
+    writeln!(
+        out,
+        "/// Every `FormApprovalError` variant in declaration order"
+    )
+    .unwrap();
+    writeln!(out, "pub const PROGRAM_ERRORS: &[FormApprovalError] = &[").unwrap();
+    for error in errors {
+        writeln!(out, "    FormApprovalError::{error},").unwrap();
+    }
+    writeln!(out, "];\n").unwrap();
+}
+
+fn write_events(out: &mut String, events: &[String]) {
+    writeln!(out, "/// Decoded program event").unwrap();
+    writeln!(out, "pub enum ProgramEvent {{").unwrap();
+    for event in events {
+        writeln!(out, "    {event}(sign_document_contract::{event}),").unwrap();
+    }
+    writeln!(out, "}}\n").unwrap();
+
+    writeln!(out, "impl ProgramEvent {{").unwrap();
+    writeln!(out, "    /// Decode discriminator-prefixed event data").unwrap();
+    writeln!(out, "    pub fn decode(data: &[u8]) -> Option<Self> {{").unwrap();
+    for event in events {
+        writeln!(
+            out,
+            "        if let Some(body) = data.strip_prefix(sign_document_contract::{event}::DISCRIMINATOR) {{\n            \
+             return AnchorDeserialize::deserialize(&mut &body[..]).ok().map(Self::{event});\n        }}"
+        )
+        .unwrap();
+    }
+    writeln!(out, "        None\n    }}\n").unwrap();
+
+    writeln!(out, "    /// Name of the event as declared in the program").unwrap();
+    writeln!(
+        out,
+        "    pub fn name(&self) -> &'static str {{\n        match self {{"
+    )
+    .unwrap();
+    for event in events {
+        writeln!(out, "            Self::{event}(_) => \"{event}\",").unwrap();
+    }
+    writeln!(out, "        }}\n    }}\n}}\n").unwrap();
+}
+
+fn write_accounts(out: &mut String, accounts: &[String]) {
+    writeln!(
+        out,
+        "/// Kind of program-owned account, identified by its discriminator"
+    )
+    .unwrap();
+    writeln!(out, "#[derive(Debug, Clone, Copy, PartialEq, Eq)]").unwrap();
+    writeln!(out, "pub enum AccountKind {{").unwrap();
+    for account in accounts {
+        writeln!(out, "    {account},").unwrap();
+    }
+    writeln!(out, "}}\n").unwrap();
+
+    writeln!(out, "impl AccountKind {{").unwrap();
+    writeln!(out, "    /// Every account kind in declaration order").unwrap();
+    writeln!(out, "    pub const ALL: &'static [AccountKind] = &[").unwrap();
+    for account in accounts {
+        writeln!(out, "        AccountKind::{account},").unwrap();
+    }
+    writeln!(out, "    ];\n").unwrap();
+
+    writeln!(
+        out,
+        "    /// Eight-byte discriminator prefixing accounts of this kind"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "    pub fn discriminator(&self) -> &'static [u8] {{\n        match self {{"
+    )
+    .unwrap();
+    for account in accounts {
+        writeln!(
+            out,
+            "            Self::{account} => sign_document_contract::state::{account}::DISCRIMINATOR,"
+        )
+        .unwrap();
+    }
+    writeln!(out, "        }}\n    }}\n}}\n").unwrap();
+
+    writeln!(out, "/// Decoded program-owned account").unwrap();
+    writeln!(out, "#[derive(Clone)]").unwrap();
+    writeln!(out, "pub enum ProgramAccount {{").unwrap();
+    for account in accounts {
+        writeln!(
+            out,
+            "    {account}(Box<sign_document_contract::state::{account}>),"
+        )
+        .unwrap();
+    }
+    writeln!(out, "}}\n").unwrap();
+
+    writeln!(out, "impl ProgramAccount {{").unwrap();
+    writeln!(
+        out,
+        "    /// Decode discriminator-prefixed account data of any kind"
+    )
+    .unwrap();
+    writeln!(out, "    pub fn decode(data: &[u8]) -> Option<Self> {{").unwrap();
+    writeln!(out, "        match AccountKind::from_data(data)? {{").unwrap();
+    for account in accounts {
+        writeln!(
+            out,
+            "            AccountKind::{account} => AccountDeserialize::try_deserialize(&mut &data[..])\n                \
+             .ok()\n                .map(|account| Self::{account}(Box::new(account))),"
+        )
+        .unwrap();
+    }
+    writeln!(out, "        }}\n    }}\n").unwrap();
+
+    writeln!(out, "    /// Kind of the decoded account").unwrap();
+    writeln!(
+        out,
+        "    pub fn kind(&self) -> AccountKind {{\n        match self {{"
+    )
+    .unwrap();
+    for account in accounts {
+        writeln!(
+            out,
+            "            Self::{account}(_) => AccountKind::{account},"
+        )
+        .unwrap();
+    }
+    writeln!(out, "        }}\n    }}\n}}").unwrap();
+}
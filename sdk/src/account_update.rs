@@ -0,0 +1,64 @@
+//! Typed account changes for Geyser-style account streams.
+//!
+//! A validator plugin or account subscription reports each write as a raw
+//! key, owner, lamports and data. `AccountUpdate::decode` turns those into
+//! the same program structs the client reads over RPC, so warehouses can be
+//! fed from the stream instead of polling.
+
+use anchor_lang::prelude::Pubkey;
+
+use crate::{ProgramAccount, PROGRAM_ID};
+
+/// Account write as reported by a Geyser plugin's `update_account` callback
+#[derive(Debug, Clone, Copy)]
+pub struct RawAccountUpdate<'a> {
+    pub pubkey: Pubkey,
+    pub owner: Pubkey,
+    pub lamports: u64,
+    pub data: &'a [u8],
+    pub slot: u64,
+    pub write_version: u64,
+}
+
+/// What happened to the account
+#[derive(Clone)]
+pub enum AccountChange {
+    /// The account was created or written and holds this program account
+    Updated(ProgramAccount),
+
+    /// The account was closed; its last kind is only known to the consumer
+    Closed,
+}
+
+/// Decoded change to one account, ordered by `(slot, write_version)`
+#[derive(Clone)]
+pub struct AccountUpdate {
+    pub pubkey: Pubkey,
+    pub slot: u64,
+    pub write_version: u64,
+    pub change: AccountChange,
+}
+
+impl AccountUpdate {
+    /// Decode a raw update, or `None` if it is not a program account this SDK knows.
+    ///
+    /// Closed accounts are reassigned to the system program, so every
+    /// zero-lamport update is reported as `Closed` whatever its owner;
+    /// consumers drop those for keys they do not track.
+    pub fn decode(raw: &RawAccountUpdate) -> Option<Self> {
+        let change = if raw.lamports == 0 {
+            AccountChange::Closed
+        } else if raw.owner == PROGRAM_ID {
+            AccountChange::Updated(ProgramAccount::decode(raw.data)?)
+        } else {
+            return None;
+        };
+
+        Some(Self {
+            pubkey: raw.pubkey,
+            slot: raw.slot,
+            write_version: raw.write_version,
+            change,
+        })
+    }
+}
@@ -0,0 +1,98 @@
+//! Batch sizing against compute and transaction size limits.
+//!
+//! Instructions that take one approval account per item grow in cost with
+//! the batch. These helpers use the program's per-item compute budgets to
+//! split large batches into transactions that stay within limits.
+
+use anchor_client::solana_sdk::compute_budget::ComputeBudgetInstruction;
+use anchor_client::solana_sdk::instruction::Instruction;
+use sign_document_contract::config::Config;
+
+/// Largest serialized legacy transaction accepted by the cluster
+const MAX_TRANSACTION_BYTES: usize = 1232;
+
+/// Bytes reserved for the fee payer signature, message header, blockhash,
+/// program IDs, instruction data and the compute budget instruction
+const RESERVED_TRANSACTION_BYTES: usize = 400;
+
+/// Bytes each extra account adds: its key plus its index in the instruction
+const BYTES_PER_ACCOUNT: usize = 33;
+
+/// Program instruction whose cost grows with the number of approval accounts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchOperation {
+    /// `verify_form_signatures`, one approval account per item
+    VerifySignatures,
+
+    /// `assert_invariants`, one signer approval per item (cannot be split)
+    AssertInvariants,
+}
+
+impl BatchOperation {
+    /// Compute units budgeted before any items
+    pub fn base_compute_units(&self) -> u32 {
+        match self {
+            Self::VerifySignatures => Config::VERIFY_SIGNATURES_BASE_CU,
+            Self::AssertInvariants => Config::ASSERT_INVARIANTS_BASE_CU,
+        }
+    }
+
+    /// Compute units budgeted per item
+    pub fn compute_units_per_item(&self) -> u32 {
+        match self {
+            Self::VerifySignatures => Config::VERIFY_SIGNATURES_CU_PER_APPROVAL,
+            Self::AssertInvariants => Config::ASSERT_INVARIANTS_CU_PER_APPROVAL,
+        }
+    }
+
+    /// Accounts the instruction takes besides the per-item accounts
+    fn fixed_accounts(&self) -> usize {
+        match self {
+            Self::VerifySignatures => 1,
+            Self::AssertInvariants => 3,
+        }
+    }
+
+    /// Compute units budgeted for a batch of `items`
+    pub fn compute_units(&self, items: usize) -> u32 {
+        let items = u32::try_from(items).unwrap_or(u32::MAX);
+        self.base_compute_units()
+            .saturating_add(self.compute_units_per_item().saturating_mul(items))
+    }
+
+    /// Most items that fit in one transaction by both compute and size limits
+    pub fn max_items_per_transaction(&self) -> usize {
+        let by_compute = (Config::MAX_TRANSACTION_CU - self.base_compute_units())
+            / self.compute_units_per_item();
+        let by_size = (MAX_TRANSACTION_BYTES - RESERVED_TRANSACTION_BYTES) / BYTES_PER_ACCOUNT
+            - self.fixed_accounts();
+        (by_compute as usize).min(by_size)
+    }
+
+    /// Whether a batch of `items` fits in a single transaction
+    pub fn fits_in_transaction(&self, items: usize) -> bool {
+        items <= self.max_items_per_transaction()
+    }
+
+    /// Compute budget instruction requesting the budget for a batch of `items`
+    pub fn compute_budget_instruction(&self, items: usize) -> Instruction {
+        let units = self.compute_units(items).min(Config::MAX_TRANSACTION_CU);
+        ComputeBudgetInstruction::set_compute_unit_limit(units)
+    }
+}
+
+/// Split `items` into transaction-sized batches for `operation`
+///
+/// Returns a single batch for operations that must see every item at once;
+/// check those with [`BatchOperation::fits_in_transaction`] first.
+pub fn split_batch<T>(items: &[T], operation: BatchOperation) -> Vec<&[T]> {
+    if items.is_empty() {
+        return Vec::new();
+    }
+    match operation {
+        BatchOperation::VerifySignatures => items
+            .chunks(operation.max_items_per_transaction())
+            .collect(),
+        BatchOperation::AssertInvariants => vec![items],
+    }
+}
@@ -0,0 +1,585 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anchor_client::solana_account_decoder::UiAccountEncoding;
+use anchor_client::solana_client::nonblocking::rpc_client::RpcClient;
+use anchor_client::solana_client::rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use anchor_client::solana_client::rpc_filter::{Memcmp, RpcFilterType};
+use anchor_client::solana_sdk::account::Account;
+use anchor_client::solana_sdk::commitment_config::CommitmentConfig;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AccountDeserialize, Discriminator};
+use sign_document_contract::interface::{ApprovalRecord, DocumentSigningProgram};
+use sign_document_contract::merkle;
+
+use crate::error::SdkError;
+use crate::pda;
+use crate::proof::{Attestation, ProofBundle};
+use crate::{
+    AccessGrant, Annotation, ApprovalChain, ApprovalTally, Checkpoint, CounterSignature,
+    FormApproval, FormObserver, FormRejection, HashIndex, MirrorAttestation, SearchKey,
+    VerificationStats, VerifierRecord, WebhookManifest, PROGRAM_ID,
+};
+
+/// Result of checking a form approval against chain state
+#[derive(Clone)]
+pub struct ChainVerification {
+    /// Form ID that was looked up
+    pub form_id: String,
+
+    /// Address of the form approval PDA
+    pub approval_address: Pubkey,
+
+    /// Slot at which the account was read
+    pub slot: u64,
+
+    /// The approval account, if one exists
+    pub approval: Option<FormApproval>,
+
+    /// Whether the approval exists and matches the expected hash
+    pub is_valid: bool,
+}
+
+/// Result of checking an approval through the shared signing interface
+#[derive(Clone)]
+pub struct InterfaceVerification {
+    /// Program that holds the approval
+    pub program_id: Pubkey,
+
+    /// Address of the approval record
+    pub approval_address: Pubkey,
+
+    /// Slot at which the account was read
+    pub slot: u64,
+
+    /// The decoded approval record, if one exists
+    pub record: Option<ApprovalRecord>,
+
+    /// Whether the approval exists and matches the expected hash
+    pub is_valid: bool,
+}
+
+/// Result of replaying the approval hash chain over current approval accounts
+#[derive(Clone, Debug)]
+pub struct ChainAudit {
+    /// Number of approvals the on-chain chain head has linked
+    pub length: u64,
+
+    /// Chain positions with no approval account (re-approved or removed records)
+    pub missing_indices: Vec<u64>,
+
+    /// Positions whose recorded previous hash does not match the preceding approval
+    pub broken_links: Vec<u64>,
+
+    /// Whether the last approval's link reproduces the on-chain head
+    pub head_matches: bool,
+}
+
+impl ChainAudit {
+    /// Whether every position is present, every link matches and the head is reproduced
+    pub fn is_intact(&self) -> bool {
+        self.missing_indices.is_empty() && self.broken_links.is_empty() && self.head_matches
+    }
+}
+
+/// Read-only client for the sign-document-contract program
+pub struct SignDocumentClient {
+    rpc: RpcClient,
+    program_id: Pubkey,
+}
+
+impl SignDocumentClient {
+    /// Create a client for the deployed program at the given RPC endpoint
+    pub fn new(rpc_url: impl ToString) -> Self {
+        Self::with_program_id(
+            RpcClient::new_with_commitment(rpc_url.to_string(), CommitmentConfig::confirmed()),
+            PROGRAM_ID,
+        )
+    }
+
+    /// Create a client for a custom program deployment
+    pub fn with_program_id(rpc: RpcClient, program_id: Pubkey) -> Self {
+        Self { rpc, program_id }
+    }
+
+    /// Program ID this client reads from
+    pub fn program_id(&self) -> Pubkey {
+        self.program_id
+    }
+
+    /// Underlying RPC client
+    pub fn rpc(&self) -> &RpcClient {
+        &self.rpc
+    }
+
+    /// Fetch a program-owned account without deserializing it, returning the read slot
+    pub async fn fetch_raw(&self, address: &Pubkey) -> Result<(u64, Option<Account>), SdkError> {
+        let response = self
+            .rpc
+            .get_account_with_commitment(address, self.rpc.commitment())
+            .await?;
+
+        if let Some(account) = &response.value {
+            if account.owner != self.program_id {
+                return Err(SdkError::InvalidOwner(*address));
+            }
+        }
+        Ok((response.context.slot, response.value))
+    }
+
+    /// Fetch and deserialize a program-owned account, returning the read slot
+    pub async fn fetch_account<T: AccountDeserialize>(
+        &self,
+        address: &Pubkey,
+    ) -> Result<(u64, Option<T>), SdkError> {
+        let (slot, account) = self.fetch_raw(address).await?;
+        let Some(account) = account else {
+            return Ok((slot, None));
+        };
+
+        let parsed = T::try_deserialize(&mut account.data.as_slice())
+            .map_err(|err| SdkError::Deserialize(*address, err.to_string()))?;
+        Ok((slot, Some(parsed)))
+    }
+
+    /// Fetch every account of a given type owned by the program
+    pub async fn fetch_all<T: AccountDeserialize + Discriminator>(
+        &self,
+    ) -> Result<Vec<(Pubkey, T)>, SdkError> {
+        let config = RpcProgramAccountsConfig {
+            filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+                0,
+                T::DISCRIMINATOR.to_vec(),
+            ))]),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                commitment: Some(self.rpc.commitment()),
+                ..RpcAccountInfoConfig::default()
+            },
+            ..RpcProgramAccountsConfig::default()
+        };
+
+        let accounts = self
+            .rpc
+            .get_program_accounts_with_config(&self.program_id, config)
+            .await?;
+
+        // Accounts still on a legacy layout are skipped until migrated
+        Ok(accounts
+            .into_iter()
+            .filter_map(|(address, account)| {
+                T::try_deserialize(&mut account.data.as_slice())
+                    .ok()
+                    .map(|parsed| (address, parsed))
+            })
+            .collect())
+    }
+
+    /// Checkpoint leaves for approvals created in `[period_start, period_end)`, in canonical order
+    pub async fn checkpoint_leaves(
+        &self,
+        period_start: i64,
+        period_end: i64,
+    ) -> Result<Vec<(Pubkey, [u8; 32])>, SdkError> {
+        let mut approvals: Vec<_> = self
+            .fetch_all::<FormApproval>()
+            .await?
+            .into_iter()
+            .filter(|(_, approval)| {
+                approval.approved_at >= period_start && approval.approved_at < period_end
+            })
+            .collect();
+        approvals.sort_by_key(|(address, approval)| (approval.approved_at, *address));
+
+        Ok(approvals
+            .iter()
+            .map(|(address, approval)| (*address, approval.checkpoint_leaf(address)))
+            .collect())
+    }
+
+    /// Replay the approval hash chain and report missing or reordered approvals
+    pub async fn audit_approval_chain(&self) -> Result<ChainAudit, SdkError> {
+        let (chain_address, _) = pda::approval_chain_address(&self.program_id);
+        let chain: Option<ApprovalChain> = self.fetch_account(&chain_address).await?.1;
+        let (head, length) = chain.map_or(([0u8; 32], 0), |chain| (chain.head, chain.length));
+
+        let mut links: Vec<(u64, [u8; 32], [u8; 32])> = self
+            .fetch_all::<FormApproval>()
+            .await?
+            .into_iter()
+            .filter(|(_, approval)| approval.chain_index > 0)
+            .map(|(address, approval)| {
+                let link = merkle::chain_link(
+                    &approval.prev_chain_hash,
+                    &approval.checkpoint_leaf(&address),
+                );
+                (approval.chain_index, approval.prev_chain_hash, link)
+            })
+            .collect();
+        links.sort_by_key(|(index, _, _)| *index);
+
+        let present: Vec<u64> = links.iter().map(|(index, _, _)| *index).collect();
+        let missing_indices = (1..=length)
+            .filter(|index| present.binary_search(index).is_err())
+            .collect();
+
+        let mut broken_links = Vec::new();
+        let mut previous: Option<(u64, [u8; 32])> = None;
+        for (index, prev_chain_hash, link) in &links {
+            let expected = match previous {
+                Some((previous_index, previous_link)) if previous_index + 1 == *index => {
+                    Some(previous_link)
+                }
+                None if *index == 1 => Some([0u8; 32]),
+                _ => None,
+            };
+            if expected.is_some_and(|expected| expected != *prev_chain_hash) {
+                broken_links.push(*index);
+            }
+            previous = Some((*index, *link));
+        }
+
+        Ok(ChainAudit {
+            length,
+            missing_indices,
+            broken_links,
+            head_matches: previous
+                .map_or(length == 0, |(index, link)| index == length && link == head),
+        })
+    }
+
+    /// Build an offline proof bundle for a form approval covered by a checkpoint
+    pub async fn export_proof(
+        &self,
+        form_id: &str,
+        checkpoint_id: u64,
+        attestations: Vec<Attestation>,
+    ) -> Result<ProofBundle, SdkError> {
+        let (approval_address, _) = pda::form_approval_address(form_id, &self.program_id);
+        let (checkpoint_address, _) = pda::checkpoint_address(checkpoint_id, &self.program_id);
+
+        let approval_account = self
+            .fetch_raw(&approval_address)
+            .await?
+            .1
+            .ok_or(SdkError::AccountNotFound(approval_address))?;
+        let checkpoint_account = self
+            .fetch_raw(&checkpoint_address)
+            .await?
+            .1
+            .ok_or(SdkError::AccountNotFound(checkpoint_address))?;
+        let checkpoint = Checkpoint::try_deserialize(&mut checkpoint_account.data.as_slice())
+            .map_err(|err| SdkError::Deserialize(checkpoint_address, err.to_string()))?;
+
+        let leaves = self
+            .checkpoint_leaves(checkpoint.period_start, checkpoint.period_end)
+            .await?;
+        let index = leaves
+            .iter()
+            .position(|(address, _)| *address == approval_address)
+            .ok_or(SdkError::NotInCheckpoint(approval_address))?;
+        let leaves: Vec<[u8; 32]> = leaves.into_iter().map(|(_, leaf)| leaf).collect();
+
+        Ok(ProofBundle::new(
+            &self.program_id,
+            &approval_address,
+            &approval_account.data,
+            &checkpoint_address,
+            &checkpoint_account.data,
+            &merkle::compute_proof(&leaves, index),
+            attestations,
+        ))
+    }
+
+    /// Fetch the approval record for a form ID
+    pub async fn fetch_form_approval(
+        &self,
+        form_id: &str,
+    ) -> Result<Option<FormApproval>, SdkError> {
+        let (address, _) = pda::form_approval_address(form_id, &self.program_id);
+        Ok(self.fetch_account(&address).await?.1)
+    }
+
+    /// Fetch the rejection record for a form ID, if its submission was declined
+    pub async fn fetch_form_rejection(
+        &self,
+        form_id: &str,
+    ) -> Result<Option<FormRejection>, SdkError> {
+        let (address, _) = pda::form_rejection_address(form_id, &self.program_id);
+        Ok(self.fetch_account(&address).await?.1)
+    }
+
+    /// Fetch the signature tally for a form ID
+    pub async fn fetch_approval_tally(
+        &self,
+        form_id: &str,
+    ) -> Result<Option<ApprovalTally>, SdkError> {
+        let (address, _) = pda::approval_tally_address(form_id, &self.program_id);
+        Ok(self.fetch_account(&address).await?.1)
+    }
+
+    /// Fetch every per-signer approval record for a form ID
+    pub async fn fetch_signer_approvals(
+        &self,
+        form_id: &str,
+    ) -> Result<Vec<(Pubkey, FormApproval)>, SdkError> {
+        Ok(self
+            .fetch_all::<FormApproval>()
+            .await?
+            .into_iter()
+            .filter(|(address, approval)| {
+                approval.form_id == form_id
+                    && pda::signer_approval_address(form_id, &approval.signer, &self.program_id).0
+                        == *address
+            })
+            .collect())
+    }
+
+    /// Fetch every observer registered for a form ID in the global namespace
+    ///
+    /// Pass these as remaining accounts to state-changing instructions so
+    /// each observer receives an `ObserverNotified` event.
+    pub async fn fetch_form_observers(
+        &self,
+        form_id: &str,
+    ) -> Result<Vec<(Pubkey, FormObserver)>, SdkError> {
+        self.fetch_org_form_observers(&Pubkey::default(), form_id)
+            .await
+    }
+
+    /// Fetch every observer registered for a form ID in an organization's namespace
+    pub async fn fetch_org_form_observers(
+        &self,
+        organization: &Pubkey,
+        form_id: &str,
+    ) -> Result<Vec<(Pubkey, FormObserver)>, SdkError> {
+        Ok(self
+            .fetch_all::<FormObserver>()
+            .await?
+            .into_iter()
+            .filter(|(_, observer)| {
+                observer.form_id == form_id && observer.organization == *organization
+            })
+            .collect())
+    }
+
+    /// Fetch a grantee's access grant on a form's approval, if it exists and has not expired
+    pub async fn fetch_active_access_grant(
+        &self,
+        form_id: &str,
+        grantee: &Pubkey,
+    ) -> Result<Option<AccessGrant>, SdkError> {
+        let (approval, _) = pda::form_approval_address(form_id, &self.program_id);
+        let (address, _) = pda::access_grant_address(&approval, grantee, &self.program_id);
+        let grant: Option<AccessGrant> = self.fetch_account(&address).await?.1;
+        Ok(grant.filter(|grant| grant.is_active(unix_now())))
+    }
+
+    /// Fetch the annotations attached to an approval, in the order they were added
+    pub async fn fetch_annotations(&self, approval: &Pubkey) -> Result<Vec<Annotation>, SdkError> {
+        let mut annotations: Vec<Annotation> = self
+            .fetch_all::<Annotation>()
+            .await?
+            .into_iter()
+            .map(|(_, annotation)| annotation)
+            .filter(|annotation| annotation.approval == *approval)
+            .collect();
+        annotations.sort_by_key(|annotation| annotation.index);
+        Ok(annotations)
+    }
+
+    /// Fetch the counter-signatures attached to a form, with their addresses
+    pub async fn fetch_counter_signatures(
+        &self,
+        form_id: &str,
+    ) -> Result<Vec<(Pubkey, CounterSignature)>, SdkError> {
+        Ok(self
+            .fetch_all::<CounterSignature>()
+            .await?
+            .into_iter()
+            .filter(|(_, signature)| signature.form_id == form_id)
+            .collect())
+    }
+
+    /// Fetch the attested mirrors of a form's approval, with their addresses
+    pub async fn fetch_mirror_attestations(
+        &self,
+        form_id: &str,
+    ) -> Result<Vec<(Pubkey, MirrorAttestation)>, SdkError> {
+        let (approval, _) = pda::form_approval_address(form_id, &self.program_id);
+        Ok(self
+            .fetch_all::<MirrorAttestation>()
+            .await?
+            .into_iter()
+            .filter(|(_, attestation)| attestation.approval == approval)
+            .collect())
+    }
+
+    /// Fetch the recorded verification activity on a form's approval, with each verifier's record
+    pub async fn fetch_verification_activity(
+        &self,
+        form_id: &str,
+    ) -> Result<(Option<VerificationStats>, Vec<VerifierRecord>), SdkError> {
+        let (approval, _) = pda::form_approval_address(form_id, &self.program_id);
+        let (address, _) = pda::verification_stats_address(&approval, &self.program_id);
+        let stats: Option<VerificationStats> = self.fetch_account(&address).await?.1;
+        let records = self
+            .fetch_all::<VerifierRecord>()
+            .await?
+            .into_iter()
+            .map(|(_, record)| record)
+            .filter(|record| record.approval == approval)
+            .collect();
+        Ok((stats, records))
+    }
+
+    /// Check a notifier's endpoint manifest against the one published on-chain.
+    ///
+    /// Returns the published version when `manifest` hashes to the current
+    /// manifest hash, and `None` when it is stale or nothing is published.
+    pub async fn verify_webhook_manifest(&self, manifest: &[u8]) -> Result<Option<u32>, SdkError> {
+        let (address, _) = pda::webhook_manifest_address(&self.program_id);
+        let published: Option<WebhookManifest> = self.fetch_account(&address).await?.1;
+        let manifest_hash = anchor_lang::solana_program::hash::hash(manifest).to_bytes();
+        Ok(published
+            .filter(|published| published.manifest_hash == manifest_hash)
+            .map(|published| published.version))
+    }
+
+    /// Fetch the first approval signed over a document digest, via its hash index
+    pub async fn find_by_form_hash(
+        &self,
+        form_hash: &[u8; 32],
+    ) -> Result<Option<(Pubkey, FormApproval)>, SdkError> {
+        let (address, _) = pda::hash_index_address(form_hash, &self.program_id);
+        let Some(hash_index) = self.fetch_account::<HashIndex>(&address).await?.1 else {
+            return Ok(None);
+        };
+
+        Ok(self
+            .fetch_account::<FormApproval>(&hash_index.approval)
+            .await?
+            .1
+            .map(|approval| (hash_index.approval, approval)))
+    }
+
+    /// Fetch the approval indexed under the hash of an external reference
+    pub async fn find_by_search_key(
+        &self,
+        key_hash: &[u8; 32],
+    ) -> Result<Option<(Pubkey, FormApproval)>, SdkError> {
+        let (address, _) = pda::search_key_address(key_hash, &self.program_id);
+        let Some(search_key) = self.fetch_account::<SearchKey>(&address).await?.1 else {
+            return Ok(None);
+        };
+
+        Ok(self
+            .fetch_account::<FormApproval>(&search_key.approval)
+            .await?
+            .1
+            .map(|approval| (search_key.approval, approval)))
+    }
+
+    /// Fetch every approval record signed in a given locale
+    pub async fn fetch_approvals_by_locale(
+        &self,
+        locale: &str,
+    ) -> Result<Vec<(Pubkey, FormApproval)>, SdkError> {
+        Ok(self
+            .fetch_all::<FormApproval>()
+            .await?
+            .into_iter()
+            .filter(|(_, approval)| approval.locale == locale)
+            .collect())
+    }
+
+    /// Check that a form approval exists and matches the expected hash
+    pub async fn verify_form(
+        &self,
+        form_id: &str,
+        expected_hash: &[u8; 32],
+    ) -> Result<ChainVerification, SdkError> {
+        let (approval_address, _) = pda::form_approval_address(form_id, &self.program_id);
+        self.verify_at(form_id, approval_address, expected_hash)
+            .await
+    }
+
+    /// Check that a form approval in an organization's namespace exists and matches the expected hash
+    pub async fn verify_org_form(
+        &self,
+        organization: &Pubkey,
+        form_id: &str,
+        expected_hash: &[u8; 32],
+    ) -> Result<ChainVerification, SdkError> {
+        let (approval_address, _) =
+            pda::org_form_approval_address(organization, form_id, &self.program_id);
+        self.verify_at(form_id, approval_address, expected_hash)
+            .await
+    }
+
+    /// Read the approval at `approval_address` and check it against the expected hash
+    async fn verify_at(
+        &self,
+        form_id: &str,
+        approval_address: Pubkey,
+        expected_hash: &[u8; 32],
+    ) -> Result<ChainVerification, SdkError> {
+        let (slot, approval) = self
+            .fetch_account::<FormApproval>(&approval_address)
+            .await?;
+        let is_valid = approval
+            .as_ref()
+            .is_some_and(|approval| approval.is_valid_for(expected_hash, unix_now()));
+
+        Ok(ChainVerification {
+            form_id: form_id.to_string(),
+            approval_address,
+            slot,
+            approval,
+            is_valid,
+        })
+    }
+
+    /// Check an approval held by any program implementing the signing interface
+    pub async fn verify_with_program(
+        &self,
+        program: &impl DocumentSigningProgram,
+        form_id: &str,
+        expected_hash: &[u8; 32],
+    ) -> Result<InterfaceVerification, SdkError> {
+        let approval_address = program.approval_address(form_id);
+        let response = self
+            .rpc
+            .get_account_with_commitment(&approval_address, self.rpc.commitment())
+            .await?;
+
+        let record = match response.value {
+            Some(account) if account.owner != program.program_id() => {
+                return Err(SdkError::InvalidOwner(approval_address));
+            }
+            Some(account) => Some(
+                program
+                    .decode_approval(&account.data)
+                    .map_err(|err| SdkError::Deserialize(approval_address, err.to_string()))?,
+            ),
+            None => None,
+        };
+        let is_valid = record
+            .as_ref()
+            .is_some_and(|record| record.form_hash == *expected_hash);
+
+        Ok(InterfaceVerification {
+            program_id: program.program_id(),
+            approval_address,
+            slot: response.context.slot,
+            record,
+            is_valid,
+        })
+    }
+}
+
+/// Current unix time on this machine, for checking approval expiry
+fn unix_now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() as i64)
+}
@@ -0,0 +1,55 @@
+use anchor_client::solana_client::client_error::ClientError;
+use anchor_client::solana_sdk::instruction::InstructionError;
+use anchor_client::solana_sdk::transaction::TransactionError;
+use anchor_lang::prelude::Pubkey;
+use thiserror::Error;
+
+use crate::program::{error_from_code, FormApprovalError};
+
+/// Errors returned by the SDK
+#[derive(Debug, Error)]
+pub enum SdkError {
+    #[error("RPC request failed: {0}")]
+    Rpc(Box<ClientError>),
+
+    #[error("Account {0} is not owned by the program")]
+    InvalidOwner(Pubkey),
+
+    #[error("Failed to deserialize account {0}: {1}")]
+    Deserialize(Pubkey, String),
+
+    #[error("Account {0} does not exist")]
+    AccountNotFound(Pubkey),
+
+    #[error("Approval {0} is not covered by the checkpoint")]
+    NotInCheckpoint(Pubkey),
+
+    #[error("Simulated view failed: {0}")]
+    Simulation(TransactionError),
+
+    #[error("Simulated view returned no data")]
+    MissingReturnData,
+}
+
+impl From<ClientError> for SdkError {
+    fn from(err: ClientError) -> Self {
+        Self::Rpc(Box::new(err))
+    }
+}
+
+impl SdkError {
+    /// The program error that made the transaction fail, if any
+    pub fn program_error(&self) -> Option<FormApprovalError> {
+        let error = match self {
+            Self::Rpc(err) => err.get_transaction_error()?,
+            Self::Simulation(err) => err.clone(),
+            _ => return None,
+        };
+        match error {
+            TransactionError::InstructionError(_, InstructionError::Custom(code)) => {
+                error_from_code(code)
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,38 @@
+//! Off-chain client SDK for the sign-document-contract program.
+//!
+//! Wraps PDA derivation and account reads so services can verify form
+//! approvals without re-implementing the on-chain layout.
+
+pub mod account_update;
+pub mod batch;
+pub mod client;
+pub mod error;
+pub mod lookup_table;
+pub mod pda;
+pub mod program;
+pub mod proof;
+#[cfg(feature = "test-vectors")]
+pub mod test_vectors;
+pub mod transaction;
+pub mod views;
+
+pub use account_update::{AccountChange, AccountUpdate, RawAccountUpdate};
+pub use anchor_client::{solana_client, solana_sdk};
+pub use batch::{split_batch, BatchOperation};
+pub use client::{ChainAudit, ChainVerification, InterfaceVerification, SignDocumentClient};
+pub use error::SdkError;
+pub use program::{AccountKind, FormApprovalError, ProgramAccount, ProgramEvent};
+pub use proof::{Attestation, ProofBundle, ProofError, VerifiedProof};
+pub use sign_document_contract::interface;
+pub use sign_document_contract::merkle;
+pub use sign_document_contract::state::{
+    AbuseCounters, AccessGrant, AdminConfig, AdminRecord, Annotation, AnnotationLog, ApprovalChain,
+    ApprovalStatus, ApprovalTally, ArchiveBundle, ArchiveRecord, BrandingConfig, CategoryPolicy,
+    Checkpoint, ConsentRecord, CounterSignature, DocumentUpload, Escrow, FeatureGate, FeatureGates,
+    FormAction, FormApproval, FormApprovalDetails, FormObserver, FormRejection, HashAlgorithm,
+    HashIndex, MirrorAttestation, MirrorLocation, NotificationPrefs, PolicyCatalog, ProgramConfig,
+    RegisteredApp, SearchKey, SemVer, SignOptions, SigningProgress, TallyStatus, TemplateVersion,
+    ThresholdPolicy, VerificationStats, VerifierRecord, WebhookManifest,
+};
+pub use sign_document_contract::ID as PROGRAM_ID;
+pub use transaction::SigningTransactionBuilder;
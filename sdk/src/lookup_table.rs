@@ -0,0 +1,55 @@
+//! Address lookup table bootstrap for the program's hot accounts.
+//!
+//! Putting the accounts most instructions touch into one lookup table lets
+//! batch and multi-account transactions fit in a single v0 transaction.
+
+use anchor_client::solana_sdk::instruction::Instruction;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::system_program;
+use solana_address_lookup_table_interface::instruction::{
+    create_lookup_table, extend_lookup_table,
+};
+
+use crate::pda;
+
+/// Addresses per extend instruction, keeping each extension in one legacy transaction
+pub const ADDRESSES_PER_EXTEND: usize = 20;
+
+/// Accounts referenced by most program instructions, plus the given category policies
+pub fn hot_accounts(program_id: &Pubkey, categories: &[&str]) -> Vec<Pubkey> {
+    let mut addresses = vec![
+        *program_id,
+        system_program::ID,
+        pda::admin_config_address(program_id).0,
+        pda::program_config_address(program_id).0,
+        pda::feature_gates_address(program_id).0,
+        pda::branding_config_address(program_id).0,
+    ];
+    addresses.extend(
+        categories
+            .iter()
+            .map(|category| pda::category_policy_address(category, program_id).0),
+    );
+    addresses
+}
+
+/// Instructions creating a lookup table and extending it with `addresses`
+///
+/// The first instruction creates the table; later ones each add up to
+/// [`ADDRESSES_PER_EXTEND`] addresses and should be sent in separate
+/// transactions. `recent_slot` must be a recent finalized slot.
+pub fn bootstrap_lookup_table(
+    authority: Pubkey,
+    payer: Pubkey,
+    recent_slot: u64,
+    addresses: &[Pubkey],
+) -> (Pubkey, Vec<Instruction>) {
+    let (create, table) = create_lookup_table(authority, payer, recent_slot);
+    let mut instructions = vec![create];
+    instructions.extend(
+        addresses
+            .chunks(ADDRESSES_PER_EXTEND)
+            .map(|chunk| extend_lookup_table(table, authority, Some(payer), chunk.to_vec())),
+    );
+    (table, instructions)
+}
@@ -0,0 +1,373 @@
+use anchor_lang::prelude::Pubkey;
+use sign_document_contract::config::Config;
+use sign_document_contract::state::FormApproval;
+
+/// Derive the admin config PDA
+pub fn admin_config_address(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[Config::ADMIN_CONFIG_SEED], program_id)
+}
+
+/// Derive the program config PDA
+pub fn program_config_address(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[Config::PROGRAM_CONFIG_SEED], program_id)
+}
+
+/// Derive the issuer branding PDA
+pub fn branding_config_address(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[Config::BRANDING_CONFIG_SEED], program_id)
+}
+
+/// Derive the PDA counting an approval's recorded verifications
+pub fn verification_stats_address(approval: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[Config::VERIFICATION_STATS_SEED, approval.as_ref()],
+        program_id,
+    )
+}
+
+/// Derive the PDA counting one verifier's recorded verifications of an approval
+pub fn verifier_record_address(
+    approval: &Pubkey,
+    verifier: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            Config::VERIFIER_RECORD_SEED,
+            approval.as_ref(),
+            verifier.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// Derive the webhook manifest PDA
+pub fn webhook_manifest_address(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[Config::WEBHOOK_MANIFEST_SEED], program_id)
+}
+
+/// Derive the policy catalog PDA
+pub fn policy_catalog_address(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[Config::POLICY_CATALOG_SEED], program_id)
+}
+
+/// Derive the PDA indexing the first approval anchored over a form hash
+pub fn hash_index_address(form_hash: &[u8; 32], program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[Config::HASH_INDEX_SEED, form_hash], program_id)
+}
+
+/// Derive the PDA numbering every event the program emits
+pub fn event_sequence_address(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[Config::EVENT_SEQUENCE_SEED], program_id)
+}
+
+/// Derive the authority signing self-CPI events in `event-cpi` builds
+pub fn event_authority_address(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"__event_authority"], program_id)
+}
+
+/// Derive the PDA recording the history of changes to an approval
+pub fn form_history_address(approval: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[Config::FORM_HISTORY_SEED, approval.as_ref()], program_id)
+}
+
+/// Derive the PDA of an organization
+pub fn organization_address(org_id: &str, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[Config::ORGANIZATION_SEED, org_id.as_bytes()], program_id)
+}
+
+/// Derive the PDA of a form approval in an organization's namespace
+pub fn org_form_approval_address(
+    organization: &Pubkey,
+    form_id: &str,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            Config::ORG_FORM_APPROVAL_SEED,
+            organization.as_ref(),
+            form_id.as_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// Derive the address an approval belongs at: its organization's namespace if it has one, else the global one
+pub fn approval_record_address(approval: &FormApproval, program_id: &Pubkey) -> (Pubkey, u8) {
+    if approval.organization == Pubkey::default() {
+        form_approval_address(&approval.form_id, program_id)
+    } else {
+        org_form_approval_address(&approval.organization, &approval.form_id, program_id)
+    }
+}
+
+/// Derive the PDA buffering a document uploaded by `uploader` for on-chain hashing
+pub fn document_upload_address(
+    form_id: &str,
+    uploader: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            Config::DOCUMENT_UPLOAD_SEED,
+            form_id.as_bytes(),
+            uploader.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// Derive the abuse counters PDA
+pub fn abuse_counters_address(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[Config::ABUSE_COUNTERS_SEED], program_id)
+}
+
+/// Derive the feature gates PDA
+pub fn feature_gates_address(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[Config::FEATURE_GATES_SEED], program_id)
+}
+
+/// Derive the PDA of a checkpoint by sequential ID
+pub fn checkpoint_address(checkpoint_id: u64, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[Config::CHECKPOINT_SEED, &checkpoint_id.to_le_bytes()],
+        program_id,
+    )
+}
+
+/// Derive the form approval PDA for a form ID
+pub fn form_approval_address(form_id: &str, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[Config::FORM_APPROVAL_SEED, form_id.as_bytes()],
+        program_id,
+    )
+}
+
+/// Derive a signer's own approval PDA for a form ID
+pub fn signer_approval_address(
+    form_id: &str,
+    signer: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            Config::SIGNER_APPROVAL_SEED,
+            form_id.as_bytes(),
+            signer.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// Derive the approval tally PDA for a form ID
+pub fn approval_tally_address(form_id: &str, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[Config::APPROVAL_TALLY_SEED, form_id.as_bytes()],
+        program_id,
+    )
+}
+
+/// Derive the threshold policy PDA for a form category
+pub fn category_policy_address(category: &str, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[Config::CATEGORY_POLICY_SEED, category.as_bytes()],
+        program_id,
+    )
+}
+
+/// Derive the PDA of a registered application by its app key
+pub fn registered_app_address(app_key: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[Config::REGISTERED_APP_SEED, app_key.as_ref()], program_id)
+}
+
+/// Derive the PDA registering an admin beyond the fixed admin list
+pub fn admin_record_address(admin: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[Config::ADMIN_RECORD_SEED, admin.as_ref()], program_id)
+}
+
+/// Derive the PDA of a pending admin invitation
+pub fn admin_invitation_address(invitee: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[Config::ADMIN_INVITATION_SEED, invitee.as_ref()],
+        program_id,
+    )
+}
+
+/// Derive the PDA of a scheduled admin removal
+pub fn admin_removal_address(admin: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[Config::ADMIN_REMOVAL_SEED, admin.as_ref()], program_id)
+}
+
+/// Derive the PDA of a published template version
+pub fn template_version_address(
+    template_id: &str,
+    version: u32,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            Config::TEMPLATE_VERSION_SEED,
+            template_id.as_bytes(),
+            &version.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// Derive the PDA of a search key from the hash of an external reference
+pub fn search_key_address(key_hash: &[u8; 32], program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[Config::SEARCH_KEY_SEED, key_hash.as_ref()], program_id)
+}
+
+/// Derive the PDA of a signer's consent record for a form
+pub fn consent_record_address(form_id: &str, signer: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[Config::CONSENT_SEED, form_id.as_bytes(), signer.as_ref()],
+        program_id,
+    )
+}
+
+/// Derive the PDA of an approval's archive record
+pub fn archive_record_address(approval: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[Config::ARCHIVE_RECORD_SEED, approval.as_ref()],
+        program_id,
+    )
+}
+
+/// Derive the PDA of an Arweave archive bundle record
+pub fn archive_bundle_address(bundle_id: &[u8; 32], program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[Config::ARCHIVE_BUNDLE_SEED, bundle_id], program_id)
+}
+
+/// Derive the PDA of a mirror attestation for an approval and remote reference
+pub fn mirror_attestation_address(
+    approval: &Pubkey,
+    remote_tx_hash: &[u8; 32],
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            Config::MIRROR_ATTESTATION_SEED,
+            approval.as_ref(),
+            remote_tx_hash,
+        ],
+        program_id,
+    )
+}
+
+/// Derive the PDA of a form's rejection record
+pub fn form_rejection_address(form_id: &str, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[Config::FORM_REJECTION_SEED, form_id.as_bytes()],
+        program_id,
+    )
+}
+
+/// Derive the PDA of a grantee's access grant on an approval
+pub fn access_grant_address(
+    approval: &Pubkey,
+    grantee: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            Config::ACCESS_GRANT_SEED,
+            approval.as_ref(),
+            grantee.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// Derive the PDA counting an approval's annotations
+pub fn annotation_log_address(approval: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[Config::ANNOTATION_LOG_SEED, approval.as_ref()],
+        program_id,
+    )
+}
+
+/// Derive the PDA of an approval's annotation at `index`
+pub fn annotation_address(approval: &Pubkey, index: u16, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            Config::ANNOTATION_SEED,
+            approval.as_ref(),
+            &index.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// Derive the PDA of an admin's notification preferences
+pub fn notification_prefs_address(admin: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[Config::NOTIFICATION_PREFS_SEED, admin.as_ref()],
+        program_id,
+    )
+}
+
+/// Derive the PDA of a counterparty's counter-signature on a form
+pub fn counter_signature_address(
+    form_id: &str,
+    signer: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            Config::COUNTER_SIGNATURE_SEED,
+            form_id.as_bytes(),
+            signer.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// Derive the PDA of a depositor's escrow for a form
+pub fn escrow_address(form_id: &str, depositor: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[Config::ESCROW_SEED, form_id.as_bytes(), depositor.as_ref()],
+        program_id,
+    )
+}
+
+/// Derive the token vault PDA of an SPL escrow
+pub fn escrow_vault_address(escrow: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[Config::ESCROW_VAULT_SEED, escrow.as_ref()], program_id)
+}
+
+/// Derive the PDA of a wallet's observer registration for a form
+pub fn form_observer_address(
+    form_id: &str,
+    observer: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[Config::OBSERVER_SEED, form_id.as_bytes(), observer.as_ref()],
+        program_id,
+    )
+}
+
+/// Derive the PDA of a wallet's observer registration for a form in an organization's namespace
+pub fn org_form_observer_address(
+    organization: &Pubkey,
+    form_id: &str,
+    observer: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            Config::ORG_OBSERVER_SEED,
+            organization.as_ref(),
+            form_id.as_bytes(),
+            observer.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// Derive the approval chain PDA
+pub fn approval_chain_address(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[Config::APPROVAL_CHAIN_SEED], program_id)
+}
@@ -0,0 +1,52 @@
+//! Typed mirrors of the program's errors, events and account discriminators.
+//!
+//! The variant lists are generated by `build.rs` from the program sources, so
+//! off-chain services can match on these instead of raw numbers and bytes.
+
+use anchor_lang::{AccountDeserialize, AnchorDeserialize, Discriminator};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+pub use sign_document_contract::config::FormApprovalError;
+
+include!(concat!(env!("OUT_DIR"), "/generated.rs"));
+
+/// Prefix Anchor uses when logging event data
+const EVENT_LOG_PREFIX: &str = "Program data: ";
+
+/// Map a custom program error code (as seen in a failed transaction) to its variant
+pub fn error_from_code(code: u32) -> Option<FormApprovalError> {
+    PROGRAM_ERRORS
+        .iter()
+        .copied()
+        .find(|error| u32::from(*error) == code)
+}
+
+impl ProgramEvent {
+    /// Decode every program event found in a transaction's log messages
+    pub fn from_logs(logs: &[String]) -> Vec<Self> {
+        logs.iter()
+            .filter_map(|line| line.strip_prefix(EVENT_LOG_PREFIX))
+            .filter_map(|encoded| STANDARD.decode(encoded).ok())
+            .filter_map(|data| Self::decode(&data))
+            .collect()
+    }
+
+    /// Decode the data of a self-CPI event instruction, as emitted by `event-cpi` builds
+    ///
+    /// Pass the data of each inner instruction the transaction made to the
+    /// program; instructions other than events decode to `None`.
+    pub fn from_cpi_data(data: &[u8]) -> Option<Self> {
+        data.strip_prefix(anchor_lang::event::EVENT_IX_TAG_LE)
+            .and_then(Self::decode)
+    }
+}
+
+impl AccountKind {
+    /// Identify a program account from its raw data
+    pub fn from_data(data: &[u8]) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|kind| data.starts_with(kind.discriminator()))
+    }
+}
@@ -0,0 +1,274 @@
+//! Self-contained approval proofs that can be verified without RPC access.
+//!
+//! A bundle carries the approval and checkpoint account data, the Merkle path
+//! from the approval leaf to the checkpoint root, and detached admin
+//! signatures over the checkpoint root.
+
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use anchor_client::solana_sdk::signature::{Keypair, Signature, Signer};
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::AccountDeserialize;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use sign_document_contract::config::Config;
+use sign_document_contract::merkle;
+use thiserror::Error;
+
+use crate::pda;
+use crate::{ApprovalStatus, Checkpoint, FormApproval};
+
+/// Current proof bundle format
+pub const PROOF_FORMAT_VERSION: u8 = 1;
+
+/// Domain separator for checkpoint attestation messages
+const ATTESTATION_DOMAIN: &[u8] = b"sign-document-contract:checkpoint:v1";
+
+/// Errors returned when a proof bundle fails verification
+#[derive(Debug, Error)]
+pub enum ProofError {
+    #[error("Unsupported proof format version {0}")]
+    UnsupportedFormat(u8),
+
+    #[error("Bundle schema {0} is incompatible with program schema {1}")]
+    IncompatibleSchema(String, String),
+
+    #[error("Malformed bundle field `{0}`")]
+    Malformed(&'static str),
+
+    #[error("{0} address does not match its PDA derivation")]
+    AddressMismatch(&'static str),
+
+    #[error("Merkle path does not lead to the checkpoint root")]
+    NotInCheckpoint,
+
+    #[error("Approval time is outside the checkpoint period")]
+    OutsidePeriod,
+
+    #[error("Approved hash does not match the expected hash")]
+    HashMismatch,
+
+    #[error("Approval was revoked")]
+    Revoked,
+
+    #[error("Approval is still collecting co-signatures")]
+    Pending,
+
+    #[error("Approval is {0:?} and no longer in force")]
+    NotInForce(ApprovalStatus),
+
+    #[error("Only {valid} of {required} required attestations are valid")]
+    InsufficientAttestations { valid: usize, required: usize },
+}
+
+/// Detached admin signature over a checkpoint attestation message
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Attestation {
+    pub signer: String,
+    pub signature: String,
+}
+
+/// Portable proof that an approval is committed to by a quorum-signed checkpoint
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ProofBundle {
+    pub format_version: u8,
+    /// Program version whose account layouts the embedded data follows
+    pub schema_version: String,
+    pub program_id: String,
+    pub approval_address: String,
+    /// Base64 encoded approval account data
+    pub approval_data: String,
+    pub checkpoint_address: String,
+    /// Base64 encoded checkpoint account data
+    pub checkpoint_data: String,
+    /// Hex encoded sibling hashes from the approval leaf up to the root
+    pub merkle_proof: Vec<String>,
+    pub attestations: Vec<Attestation>,
+}
+
+/// Decoded contents of a bundle that passed verification
+pub struct VerifiedProof {
+    pub approval: FormApproval,
+    pub checkpoint: Checkpoint,
+    /// Distinct admins with valid attestations
+    pub attested_by: Vec<Pubkey>,
+}
+
+/// Message an admin signs to attest a checkpoint root off-chain
+pub fn attestation_message(
+    program_id: &Pubkey,
+    checkpoint_id: u64,
+    merkle_root: &[u8; 32],
+) -> Vec<u8> {
+    [
+        ATTESTATION_DOMAIN,
+        program_id.as_ref(),
+        &checkpoint_id.to_le_bytes(),
+        merkle_root,
+    ]
+    .concat()
+}
+
+/// Sign a checkpoint root with an admin keypair
+pub fn sign_attestation(
+    keypair: &Keypair,
+    program_id: &Pubkey,
+    checkpoint: &Checkpoint,
+) -> Attestation {
+    let message = attestation_message(
+        program_id,
+        checkpoint.checkpoint_id,
+        &checkpoint.merkle_root,
+    );
+    Attestation {
+        signer: keypair.pubkey().to_string(),
+        signature: keypair.sign_message(&message).to_string(),
+    }
+}
+
+impl ProofBundle {
+    /// Assemble a bundle from raw account data and a Merkle path
+    pub fn new(
+        program_id: &Pubkey,
+        approval_address: &Pubkey,
+        approval_data: &[u8],
+        checkpoint_address: &Pubkey,
+        checkpoint_data: &[u8],
+        merkle_proof: &[[u8; 32]],
+        attestations: Vec<Attestation>,
+    ) -> Self {
+        Self {
+            format_version: PROOF_FORMAT_VERSION,
+            schema_version: Config::PROGRAM_VERSION.to_string(),
+            program_id: program_id.to_string(),
+            approval_address: approval_address.to_string(),
+            approval_data: BASE64.encode(approval_data),
+            checkpoint_address: checkpoint_address.to_string(),
+            checkpoint_data: BASE64.encode(checkpoint_data),
+            merkle_proof: merkle_proof.iter().map(hex::encode).collect(),
+            attestations,
+        }
+    }
+
+    /// Verify the bundle offline, optionally against an expected document hash
+    pub fn verify(&self, expected_hash: Option<&[u8; 32]>) -> Result<VerifiedProof, ProofError> {
+        if self.format_version != PROOF_FORMAT_VERSION {
+            return Err(ProofError::UnsupportedFormat(self.format_version));
+        }
+
+        if !schema_compatible(&self.schema_version) {
+            return Err(ProofError::IncompatibleSchema(
+                self.schema_version.clone(),
+                Config::PROGRAM_VERSION.to_string(),
+            ));
+        }
+
+        let program_id = parse_pubkey(&self.program_id, "program_id")?;
+        let approval_address = parse_pubkey(&self.approval_address, "approval_address")?;
+        let checkpoint_address = parse_pubkey(&self.checkpoint_address, "checkpoint_address")?;
+        let approval: FormApproval = decode_account(&self.approval_data, "approval_data")?;
+        let checkpoint: Checkpoint = decode_account(&self.checkpoint_data, "checkpoint_data")?;
+
+        if pda::approval_record_address(&approval, &program_id).0 != approval_address {
+            return Err(ProofError::AddressMismatch("Approval"));
+        }
+        if pda::checkpoint_address(checkpoint.checkpoint_id, &program_id).0 != checkpoint_address {
+            return Err(ProofError::AddressMismatch("Checkpoint"));
+        }
+
+        let proof = self
+            .merkle_proof
+            .iter()
+            .map(|node| {
+                hex::decode(node)
+                    .ok()
+                    .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+                    .ok_or(ProofError::Malformed("merkle_proof"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let leaf = approval.checkpoint_leaf(&approval_address);
+        if !merkle::verify_proof(&leaf, &proof, &checkpoint.merkle_root) {
+            return Err(ProofError::NotInCheckpoint);
+        }
+        if approval.approved_at < checkpoint.period_start
+            || approval.approved_at >= checkpoint.period_end
+        {
+            return Err(ProofError::OutsidePeriod);
+        }
+        if expected_hash.is_some_and(|hash| *hash != approval.form_hash) {
+            return Err(ProofError::HashMismatch);
+        }
+        if approval.is_revoked() {
+            return Err(ProofError::Revoked);
+        }
+        match approval.status {
+            ApprovalStatus::Approved => {}
+            ApprovalStatus::Pending => return Err(ProofError::Pending),
+            status => return Err(ProofError::NotInForce(status)),
+        }
+
+        let attested_by = self.valid_attesters(&program_id, &checkpoint);
+        let required = checkpoint.required_attestations as usize;
+        if attested_by.len() < required {
+            return Err(ProofError::InsufficientAttestations {
+                valid: attested_by.len(),
+                required,
+            });
+        }
+
+        Ok(VerifiedProof {
+            approval,
+            checkpoint,
+            attested_by,
+        })
+    }
+
+    /// Distinct checkpoint attesters whose detached signatures verify
+    fn valid_attesters(&self, program_id: &Pubkey, checkpoint: &Checkpoint) -> Vec<Pubkey> {
+        let message = attestation_message(
+            program_id,
+            checkpoint.checkpoint_id,
+            &checkpoint.merkle_root,
+        );
+        let mut seen = HashSet::new();
+
+        self.attestations
+            .iter()
+            .filter_map(|attestation| {
+                let signer = Pubkey::from_str(&attestation.signer).ok()?;
+                let signature = Signature::from_str(&attestation.signature).ok()?;
+                let valid = checkpoint.attesters.contains(&signer)
+                    && signature.verify(signer.as_ref(), &message);
+                (valid && seen.insert(signer)).then_some(signer)
+            })
+            .collect()
+    }
+}
+
+/// Whether a bundle schema can be decoded with this SDK's account layouts
+///
+/// Follows semver: the major version must match, and the minor version too
+/// while the program is pre-1.0.
+fn schema_compatible(schema_version: &str) -> bool {
+    let mut parts = schema_version.split('.').map(str::parse::<u16>);
+    let (Some(Ok(major)), Some(Ok(minor))) = (parts.next(), parts.next()) else {
+        return false;
+    };
+
+    let current = Config::PROGRAM_VERSION;
+    major == current.major && (major > 0 || minor == current.minor)
+}
+
+fn parse_pubkey(value: &str, field: &'static str) -> Result<Pubkey, ProofError> {
+    Pubkey::from_str(value).map_err(|_| ProofError::Malformed(field))
+}
+
+fn decode_account<T: AccountDeserialize>(data: &str, field: &'static str) -> Result<T, ProofError> {
+    let bytes = BASE64
+        .decode(data)
+        .map_err(|_| ProofError::Malformed(field))?;
+    T::try_deserialize(&mut bytes.as_slice()).map_err(|_| ProofError::Malformed(field))
+}
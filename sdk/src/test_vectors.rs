@@ -0,0 +1,332 @@
+//! Deterministic test vectors for SDKs in other languages.
+//!
+//! Every vector is built from fixed inputs, so the output only changes when
+//! the on-chain layout, a seed or an event does. Accounts are serialized with
+//! their discriminator and without the trailing zero padding of the allocated
+//! account. Compiled with the `test-vectors` feature.
+
+use std::collections::BTreeMap;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AccountSerialize, Event};
+use serde::Serialize;
+use sign_document_contract::state::{FormAction, FormApprovalV1, ReasonCode, SemVer};
+use sign_document_contract::{merkle, FormApproved, FormRevoked, ObserverNotified};
+
+use crate::{pda, ApprovalChain, ConsentRecord, FormApproval, PROGRAM_ID};
+
+/// Form ID used by every vector
+pub const FORM_ID: &str = "form_123";
+
+/// Kind of value a vector pins down
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum VectorKind {
+    /// Program-derived address; `output` is the base58 address
+    Pda,
+
+    /// Serialized program account; `output` is hex
+    Account,
+
+    /// Serialized event as logged after `Program data: `; `output` is hex
+    Event,
+
+    /// Derived hash; `output` is hex
+    Hash,
+}
+
+/// A fixed input and the bytes the program produces for it
+#[derive(Serialize, Clone, Debug)]
+pub struct TestVector {
+    pub kind: VectorKind,
+    pub name: &'static str,
+    pub inputs: BTreeMap<&'static str, String>,
+    pub output: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bump: Option<u8>,
+}
+
+/// Fixed signer key used by the vectors
+pub fn signer() -> Pubkey {
+    Pubkey::new_from_array([1u8; 32])
+}
+
+/// Fixed document hash used by the vectors
+pub fn form_hash() -> [u8; 32] {
+    [0xab; 32]
+}
+
+/// Every test vector, in a stable order
+pub fn generate() -> Vec<TestVector> {
+    let mut vectors = pda_vectors();
+    vectors.extend(account_vectors());
+    vectors.extend(event_vectors());
+    vectors.extend(hash_vectors());
+    vectors
+}
+
+fn pda_vectors() -> Vec<TestVector> {
+    let program_id = PROGRAM_ID;
+    let signer = signer();
+    let (approval, _) = pda::form_approval_address(FORM_ID, &program_id);
+
+    vec![
+        pda_vector("admin_config", &[], pda::admin_config_address(&program_id)),
+        pda_vector(
+            "program_config",
+            &[],
+            pda::program_config_address(&program_id),
+        ),
+        pda_vector(
+            "approval_chain",
+            &[],
+            pda::approval_chain_address(&program_id),
+        ),
+        pda_vector(
+            "form_approval",
+            &[("form_id", FORM_ID.to_string())],
+            pda::form_approval_address(FORM_ID, &program_id),
+        ),
+        pda_vector(
+            "signer_approval",
+            &[
+                ("form_id", FORM_ID.to_string()),
+                ("signer", signer.to_string()),
+            ],
+            pda::signer_approval_address(FORM_ID, &signer, &program_id),
+        ),
+        pda_vector(
+            "approval_tally",
+            &[("form_id", FORM_ID.to_string())],
+            pda::approval_tally_address(FORM_ID, &program_id),
+        ),
+        pda_vector(
+            "checkpoint",
+            &[("checkpoint_id", "0".to_string())],
+            pda::checkpoint_address(0, &program_id),
+        ),
+        pda_vector(
+            "consent_record",
+            &[
+                ("form_id", FORM_ID.to_string()),
+                ("signer", signer.to_string()),
+            ],
+            pda::consent_record_address(FORM_ID, &signer, &program_id),
+        ),
+        pda_vector(
+            "archive_record",
+            &[("approval", approval.to_string())],
+            pda::archive_record_address(&approval, &program_id),
+        ),
+    ]
+}
+
+fn account_vectors() -> Vec<TestVector> {
+    let approval = sample_approval();
+    let chain = ApprovalChain {
+        head: merkle::chain_link(&[0u8; 32], &form_hash()),
+        length: 1,
+        bump: 255,
+    };
+    let consent = ConsentRecord {
+        signer: signer(),
+        form_id: FORM_ID.to_string(),
+        disclosure_hash: [0xcd; 32],
+        consented_at: 1_700_000_000,
+        bump: 254,
+    };
+
+    vec![
+        TestVector {
+            kind: VectorKind::Account,
+            name: "form_approval",
+            inputs: inputs(&[
+                ("form_id", FORM_ID.to_string()),
+                ("form_hash", hex::encode(form_hash())),
+                ("signer", signer().to_string()),
+                ("approved_at", "1700000000".to_string()),
+                ("metadata", "invoice".to_string()),
+                ("bump", "253".to_string()),
+                ("program_version", "0.1.0".to_string()),
+                ("approved_slot", "42".to_string()),
+                ("locale", "fr-FR".to_string()),
+                ("jurisdiction", "FR".to_string()),
+            ]),
+            output: hex::encode(serialize_account(&approval)),
+            bump: None,
+        },
+        TestVector {
+            kind: VectorKind::Account,
+            name: "approval_chain",
+            inputs: inputs(&[
+                ("head", hex::encode(chain.head)),
+                ("length", "1".to_string()),
+                ("bump", "255".to_string()),
+            ]),
+            output: hex::encode(serialize_account(&chain)),
+            bump: None,
+        },
+        TestVector {
+            kind: VectorKind::Account,
+            name: "consent_record",
+            inputs: inputs(&[
+                ("signer", signer().to_string()),
+                ("form_id", FORM_ID.to_string()),
+                ("disclosure_hash", hex::encode(consent.disclosure_hash)),
+                ("consented_at", "1700000000".to_string()),
+                ("bump", "254".to_string()),
+            ]),
+            output: hex::encode(serialize_account(&consent)),
+            bump: None,
+        },
+    ]
+}
+
+fn event_vectors() -> Vec<TestVector> {
+    let approved = FormApproved {
+        form_id: FORM_ID.to_string(),
+        form_hash: form_hash(),
+        signer: signer(),
+        approved_at: 1_700_000_000,
+        reason_code: ReasonCode::Approved,
+        event_sequence: 1,
+    };
+    let revoked = FormRevoked {
+        form_id: FORM_ID.to_string(),
+        signer: signer(),
+        revoked_at: 1_700_003_600,
+        reason: "superseded".to_string(),
+        revoked_by: signer(),
+        event_sequence: 2,
+    };
+    let notified = ObserverNotified {
+        observer: Pubkey::new_from_array([2u8; 32]),
+        form_id: FORM_ID.to_string(),
+        action: FormAction::Approved,
+        event_sequence: 3,
+    };
+
+    vec![
+        TestVector {
+            kind: VectorKind::Event,
+            name: "FormApproved",
+            inputs: inputs(&[
+                ("form_id", FORM_ID.to_string()),
+                ("form_hash", hex::encode(form_hash())),
+                ("signer", signer().to_string()),
+                ("approved_at", "1700000000".to_string()),
+                ("reason_code", "Approved".to_string()),
+                ("event_sequence", "1".to_string()),
+            ]),
+            output: hex::encode(approved.data()),
+            bump: None,
+        },
+        TestVector {
+            kind: VectorKind::Event,
+            name: "FormRevoked",
+            inputs: inputs(&[
+                ("form_id", FORM_ID.to_string()),
+                ("signer", signer().to_string()),
+                ("revoked_at", "1700003600".to_string()),
+                ("reason", "superseded".to_string()),
+                ("revoked_by", signer().to_string()),
+                ("event_sequence", "2".to_string()),
+            ]),
+            output: hex::encode(revoked.data()),
+            bump: None,
+        },
+        TestVector {
+            kind: VectorKind::Event,
+            name: "ObserverNotified",
+            inputs: inputs(&[
+                ("observer", notified.observer.to_string()),
+                ("form_id", FORM_ID.to_string()),
+                ("action", "Approved".to_string()),
+                ("event_sequence", "3".to_string()),
+            ]),
+            output: hex::encode(notified.data()),
+            bump: None,
+        },
+    ]
+}
+
+fn hash_vectors() -> Vec<TestVector> {
+    let (approval_address, _) = pda::form_approval_address(FORM_ID, &PROGRAM_ID);
+    let approval = sample_approval();
+    let leaf = approval.checkpoint_leaf(&approval_address);
+
+    vec![
+        TestVector {
+            kind: VectorKind::Hash,
+            name: "approval_leaf",
+            inputs: inputs(&[
+                ("approval_address", approval_address.to_string()),
+                ("form_hash", hex::encode(form_hash())),
+                ("signer", signer().to_string()),
+                ("approved_at", "1700000000".to_string()),
+                ("approved_slot", "42".to_string()),
+            ]),
+            output: hex::encode(leaf),
+            bump: None,
+        },
+        TestVector {
+            kind: VectorKind::Hash,
+            name: "chain_link",
+            inputs: inputs(&[
+                ("prev", hex::encode([0u8; 32])),
+                ("leaf", hex::encode(leaf)),
+            ]),
+            output: hex::encode(merkle::chain_link(&[0u8; 32], &leaf)),
+            bump: None,
+        },
+    ]
+}
+
+/// Approval with fixed values in every field the vectors describe
+fn sample_approval() -> FormApproval {
+    let mut approval = FormApproval::from(FormApprovalV1 {
+        form_id: FORM_ID.to_string(),
+        form_hash: form_hash(),
+        signer: signer(),
+        approved_at: 1_700_000_000,
+        metadata: "invoice".to_string(),
+        bump: 253,
+    });
+    approval.program_version = SemVer {
+        major: 0,
+        minor: 1,
+        patch: 0,
+    };
+    approval.approved_slot = 42;
+    approval.locale = "fr-FR".to_string();
+    approval.jurisdiction = "FR".to_string();
+    approval
+}
+
+fn pda_vector(
+    name: &'static str,
+    seed_inputs: &[(&'static str, String)],
+    (address, bump): (Pubkey, u8),
+) -> TestVector {
+    let mut inputs = inputs(seed_inputs);
+    inputs.insert("program_id", PROGRAM_ID.to_string());
+    TestVector {
+        kind: VectorKind::Pda,
+        name,
+        inputs,
+        output: address.to_string(),
+        bump: Some(bump),
+    }
+}
+
+fn inputs(pairs: &[(&'static str, String)]) -> BTreeMap<&'static str, String> {
+    pairs.iter().cloned().collect()
+}
+
+fn serialize_account<T: AccountSerialize>(account: &T) -> Vec<u8> {
+    let mut data = Vec::new();
+    account
+        .try_serialize(&mut data)
+        .expect("account serializes into a vector");
+    data
+}
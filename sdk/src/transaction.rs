@@ -0,0 +1,241 @@
+//! Builder composing the instructions of a full signing transaction.
+//!
+//! Signing flows usually send more than `sign_form_submission`: a compute
+//! budget, a fee transfer, a memo and a native ed25519 check of an
+//! off-chain signature. The builder resolves the program's accounts and
+//! emits every instruction in the order the flow needs.
+
+use anchor_client::solana_sdk::compute_budget::ComputeBudgetInstruction;
+use anchor_client::solana_sdk::instruction::{AccountMeta, Instruction};
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{system_program, InstructionData, ToAccountMetas};
+use sign_document_contract::state::SignOptions;
+use solana_system_interface::instruction as system_instruction;
+
+use crate::pda;
+
+/// Builder for a transaction signing one form with `sign_form_submission`
+///
+/// Instructions are emitted as compute budget, fee transfer, memo, ed25519
+/// verification and finally the signature, so the ed25519 check and fee
+/// have both succeeded by the time the approval is recorded.
+#[derive(Debug, Clone)]
+pub struct SigningTransactionBuilder {
+    program_id: Pubkey,
+    signer: Pubkey,
+    form_id: String,
+    form_hash: [u8; 32],
+    metadata: Option<String>,
+    options: Option<SignOptions>,
+    compute_unit_limit: Option<u32>,
+    compute_unit_price: Option<u64>,
+    fee: Option<(Pubkey, u64)>,
+    memo: Option<String>,
+    ed25519_verifications: Vec<Instruction>,
+    admin_record: bool,
+    consent_record: bool,
+    registered_app: Option<Pubkey>,
+    template_version: Option<Pubkey>,
+}
+
+impl SigningTransactionBuilder {
+    /// Start a transaction in which `signer` (the admin, also paying rent) signs `form_id`
+    pub fn new(program_id: Pubkey, signer: Pubkey, form_id: &str, form_hash: [u8; 32]) -> Self {
+        Self {
+            program_id,
+            signer,
+            form_id: form_id.to_string(),
+            form_hash,
+            metadata: None,
+            options: None,
+            compute_unit_limit: None,
+            compute_unit_price: None,
+            fee: None,
+            memo: None,
+            ed25519_verifications: Vec::new(),
+            admin_record: false,
+            consent_record: false,
+            registered_app: None,
+            template_version: None,
+        }
+    }
+
+    /// Metadata to record on the approval
+    pub fn metadata(mut self, metadata: &str) -> Self {
+        self.metadata = Some(metadata.to_string());
+        self
+    }
+
+    /// Signing options; any `depends_on` approvals are passed as remaining accounts
+    pub fn options(mut self, options: SignOptions) -> Self {
+        self.options = Some(options);
+        self
+    }
+
+    /// Request a compute unit limit and, optionally, a priority fee per unit
+    pub fn compute_budget(mut self, units: u32, micro_lamports_per_unit: Option<u64>) -> Self {
+        self.compute_unit_limit = Some(units);
+        self.compute_unit_price = micro_lamports_per_unit;
+        self
+    }
+
+    /// Pay a service fee of `lamports` from the signer to `recipient`
+    pub fn fee(mut self, recipient: Pubkey, lamports: u64) -> Self {
+        self.fee = Some((recipient, lamports));
+        self
+    }
+
+    /// Attach a memo, signed by the signer, e.g. a case or invoice reference
+    pub fn memo(mut self, memo: &str) -> Self {
+        self.memo = Some(memo.to_string());
+        self
+    }
+
+    /// Verify `signature` by `pubkey` over `message` with the native ed25519 program
+    ///
+    /// The transaction fails unless the signature is valid, so an off-chain
+    /// signature (e.g. the signer's consent over the document) is checked in
+    /// the same transaction that records the approval.
+    pub fn ed25519_verification(
+        mut self,
+        pubkey: &Pubkey,
+        message: &[u8],
+        signature: &[u8; 64],
+    ) -> Self {
+        self.ed25519_verifications.push(
+            solana_ed25519_program::new_ed25519_instruction_with_signature(
+                message,
+                signature,
+                &pubkey.to_bytes(),
+            ),
+        );
+        self
+    }
+
+    /// Pass the signer's `AdminRecord`, for admins granted through a record
+    pub fn with_admin_record(mut self) -> Self {
+        self.admin_record = true;
+        self
+    }
+
+    /// Pass the signer's `ConsentRecord` for the form, required in strict-consent mode
+    pub fn with_consent_record(mut self) -> Self {
+        self.consent_record = true;
+        self
+    }
+
+    /// Attribute the signature to a registered application, which must co-sign
+    pub fn registered_app(mut self, app_signer: Pubkey) -> Self {
+        self.registered_app = Some(app_signer);
+        self
+    }
+
+    /// Pin the template version the form was filled from
+    pub fn template_version(mut self, template_id: &str, version: u32) -> Self {
+        self.template_version =
+            Some(pda::template_version_address(template_id, version, &self.program_id).0);
+        self
+    }
+
+    /// `sign_form_submission` with its accounts resolved
+    pub fn sign_instruction(&self) -> Instruction {
+        let program_id = &self.program_id;
+        let accounts = sign_document_contract::accounts::SignFormSubmission {
+            form_approval: pda::form_approval_address(&self.form_id, program_id).0,
+            approval_chain: pda::approval_chain_address(program_id).0,
+            hash_index: pda::hash_index_address(&self.form_hash, program_id).0,
+            admin_config: pda::admin_config_address(program_id).0,
+            admin_record: self
+                .admin_record
+                .then(|| pda::admin_record_address(&self.signer, program_id).0),
+            program_config: pda::program_config_address(program_id).0,
+            registered_app: self
+                .registered_app
+                .map(|app_signer| pda::registered_app_address(&app_signer, program_id).0),
+            app_signer: self.registered_app,
+            template_version: self.template_version,
+            consent_record: self
+                .consent_record
+                .then(|| pda::consent_record_address(&self.form_id, &self.signer, program_id).0),
+            feature_gates: self
+                .collects_co_signatures()
+                .then(|| pda::feature_gates_address(program_id).0),
+            admin: self.signer,
+            event_sequence: pda::event_sequence_address(program_id).0,
+            system_program: system_program::ID,
+            #[cfg(feature = "event-cpi")]
+            event_authority: pda::event_authority_address(program_id).0,
+            #[cfg(feature = "event-cpi")]
+            program: *program_id,
+        };
+        let mut account_metas = accounts.to_account_metas(None);
+        let dependencies = self
+            .options
+            .as_ref()
+            .and_then(|options| options.depends_on.as_deref())
+            .unwrap_or_default();
+        account_metas.extend(
+            dependencies
+                .iter()
+                .map(|dependency| AccountMeta::new_readonly(*dependency, false)),
+        );
+
+        Instruction {
+            program_id: *program_id,
+            accounts: account_metas,
+            data: sign_document_contract::instruction::SignFormSubmission {
+                form_id: self.form_id.clone(),
+                form_hash: self.form_hash,
+                metadata: self.metadata.clone(),
+                options: self.options.clone(),
+            }
+            .data(),
+        }
+    }
+
+    /// Whether the options ask for more than the signer's own signature, which needs the feature gates
+    fn collects_co_signatures(&self) -> bool {
+        self.options.as_ref().is_some_and(|options| {
+            options
+                .required_signatures
+                .is_some_and(|required| required > 1)
+                || options
+                    .required_signers
+                    .as_ref()
+                    .is_some_and(|signers| !signers.is_empty())
+        })
+    }
+
+    /// Every instruction of the transaction, in order
+    pub fn build(&self) -> Vec<Instruction> {
+        let mut instructions = Vec::new();
+        if let Some(units) = self.compute_unit_limit {
+            instructions.push(ComputeBudgetInstruction::set_compute_unit_limit(units));
+        }
+        if let Some(micro_lamports) = self.compute_unit_price {
+            instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+                micro_lamports,
+            ));
+        }
+        if let Some((recipient, lamports)) = self.fee {
+            instructions.push(system_instruction::transfer(
+                &self.signer,
+                &recipient,
+                lamports,
+            ));
+        }
+        if let Some(memo) = &self.memo {
+            instructions.push(spl_memo::build_memo(memo.as_bytes(), &[&self.signer]));
+        }
+        instructions.extend(self.ed25519_verifications.iter().cloned());
+        instructions.push(self.sign_instruction());
+        instructions
+    }
+
+    /// Signers the transaction needs besides the fee payer
+    pub fn required_signers(&self) -> Vec<Pubkey> {
+        let mut signers = vec![self.signer];
+        signers.extend(self.registered_app);
+        signers
+    }
+}
@@ -0,0 +1,175 @@
+//! Read-only instructions executed through transaction simulation.
+//!
+//! `verify_form_approval`, `get_form_approval_details` and
+//! `get_signing_progress` set their result as return data. Simulating them
+//! lets clients get the program's own answer, including expiry and status
+//! rules, typed with the program crate's definitions instead of re-parsing
+//! the approval account.
+
+use anchor_client::solana_client::rpc_config::RpcSimulateTransactionConfig;
+use anchor_client::solana_sdk::instruction::Instruction;
+use anchor_client::solana_sdk::transaction::Transaction;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AnchorDeserialize, InstructionData, ToAccountMetas};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use sign_document_contract::{accounts, instruction};
+
+use crate::{
+    pda, FormApprovalDetails, HashAlgorithm, SdkError, SignDocumentClient, SigningProgress,
+};
+
+impl SignDocumentClient {
+    /// Simulate `verify_form_approval`: whether the approval is in force for `expected_hash`
+    pub async fn view_verify_form_approval(
+        &self,
+        form_id: &str,
+        expected_hash: &[u8; 32],
+        payer: &Pubkey,
+    ) -> Result<bool, SdkError> {
+        let data = instruction::VerifyFormApproval {
+            _form_id: form_id.to_string(),
+            expected_hash: *expected_hash,
+        };
+        self.simulate_view(self.approval_view(form_id, data), payer)
+            .await
+    }
+
+    /// Simulate `verify_org_form_approval`: like `view_verify_form_approval` in an organization's namespace
+    pub async fn view_verify_org_form_approval(
+        &self,
+        org_id: &str,
+        form_id: &str,
+        expected_hash: &[u8; 32],
+        payer: &Pubkey,
+    ) -> Result<bool, SdkError> {
+        let program_id = self.program_id();
+        let (organization, _) = pda::organization_address(org_id, &program_id);
+        let (form_approval, _) =
+            pda::org_form_approval_address(&organization, form_id, &program_id);
+        let view = Instruction {
+            program_id,
+            accounts: accounts::VerifyOrgFormApproval {
+                form_approval,
+                organization,
+            }
+            .to_account_metas(None),
+            data: instruction::VerifyOrgFormApproval {
+                _form_id: form_id.to_string(),
+                expected_hash: *expected_hash,
+            }
+            .data(),
+        };
+        self.simulate_view(view, payer).await
+    }
+
+    /// Simulate `verify_form_digest`: like `view_verify_form_approval` over the full digest from `algorithm`
+    pub async fn view_verify_form_digest(
+        &self,
+        form_id: &str,
+        algorithm: HashAlgorithm,
+        expected_digest: &[u8],
+        payer: &Pubkey,
+    ) -> Result<bool, SdkError> {
+        let data = instruction::VerifyFormDigest {
+            _form_id: form_id.to_string(),
+            algorithm,
+            expected_digest: expected_digest.to_vec(),
+        };
+        self.simulate_view(self.approval_view(form_id, data), payer)
+            .await
+    }
+
+    /// Simulate `verify_page_proof`: whether the page at `page_index` belongs to the approved document
+    ///
+    /// Build `proof` from the document's page hashes with `merkle::page_proof`.
+    pub async fn view_verify_page_proof(
+        &self,
+        form_id: &str,
+        page_index: u32,
+        page_hash: &[u8; 32],
+        proof: Vec<[u8; 32]>,
+        payer: &Pubkey,
+    ) -> Result<bool, SdkError> {
+        let data = instruction::VerifyPageProof {
+            _form_id: form_id.to_string(),
+            page_index,
+            page_hash: *page_hash,
+            proof,
+        };
+        self.simulate_view(self.approval_view(form_id, data), payer)
+            .await
+    }
+
+    /// Simulate `get_form_approval_details`: form ID, hash, signer, approval time and metadata
+    pub async fn view_form_approval_details(
+        &self,
+        form_id: &str,
+        payer: &Pubkey,
+    ) -> Result<FormApprovalDetails, SdkError> {
+        let data = instruction::GetFormApprovalDetails {
+            _form_id: form_id.to_string(),
+        };
+        self.simulate_view(self.approval_view(form_id, data), payer)
+            .await
+    }
+
+    /// Simulate `get_signing_progress`: required signers who have and have not signed
+    pub async fn view_signing_progress(
+        &self,
+        form_id: &str,
+        payer: &Pubkey,
+    ) -> Result<SigningProgress, SdkError> {
+        let data = instruction::GetSigningProgress {
+            _form_id: form_id.to_string(),
+        };
+        self.simulate_view(self.approval_view(form_id, data), payer)
+            .await
+    }
+
+    /// Simulate a single instruction and decode its return data.
+    ///
+    /// Nothing is signed or sent; `payer` only needs to exist so the
+    /// simulated transaction has a fee payer.
+    pub async fn simulate_view<T: AnchorDeserialize>(
+        &self,
+        instruction: Instruction,
+        payer: &Pubkey,
+    ) -> Result<T, SdkError> {
+        let transaction = Transaction::new_with_payer(&[instruction], Some(payer));
+        let config = RpcSimulateTransactionConfig {
+            sig_verify: false,
+            replace_recent_blockhash: true,
+            commitment: Some(self.rpc().commitment()),
+            ..RpcSimulateTransactionConfig::default()
+        };
+        let result = self
+            .rpc()
+            .simulate_transaction_with_config(&transaction, config)
+            .await?
+            .value;
+
+        if let Some(err) = result.err {
+            return Err(SdkError::Simulation(err));
+        }
+        let return_data = result
+            .return_data
+            .filter(|return_data| return_data.program_id == self.program_id().to_string())
+            .ok_or(SdkError::MissingReturnData)?;
+        let bytes = STANDARD
+            .decode(&return_data.data.0)
+            .map_err(|_| SdkError::MissingReturnData)?;
+        T::deserialize(&mut &bytes[..])
+            .map_err(|err| SdkError::Deserialize(self.program_id(), err.to_string()))
+    }
+
+    /// Instruction for a view that reads only the form's approval
+    fn approval_view(&self, form_id: &str, data: impl InstructionData) -> Instruction {
+        let (form_approval, _) = pda::form_approval_address(form_id, &self.program_id());
+        Instruction {
+            program_id: self.program_id(),
+            accounts: accounts::VerifyFormApproval { form_approval }.to_account_metas(None),
+            data: data.data(),
+        }
+    }
+}
@@ -24,9 +24,24 @@ describe('sign-document-contract', () => {
     .digest();
   const testMetadata = 'Test form metadata';
 
+  // Record address of a key, checked when adding it to the fixed list
+  const adminRecordAddress = (key: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('admin_record'), key.toBuffer()],
+      program.programId
+    )[0];
+  const adminRecordAccounts = (...keys: PublicKey[]) =>
+    keys.map((key) => ({
+      pubkey: adminRecordAddress(key),
+      isSigner: false,
+      isWritable: false,
+    }));
+
   // PDAs
   let adminConfigPda: PublicKey;
   let adminConfigBump: number;
+  let programConfigPda: PublicKey;
+  let featureGatesPda: PublicKey;
   let formApprovalPda: PublicKey;
   let formApprovalBump: number;
 
@@ -37,6 +52,16 @@ describe('sign-document-contract', () => {
       program.programId
     );
 
+    [programConfigPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('program_config')],
+      program.programId
+    );
+
+    [featureGatesPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('feature_gates')],
+      program.programId
+    );
+
     [formApprovalPda, formApprovalBump] = PublicKey.findProgramAddressSync(
       [Buffer.from('form_approval'), Buffer.from(testFormId)],
       program.programId
@@ -76,13 +101,63 @@ describe('sign-document-contract', () => {
       expect(adminConfig.bump).to.equal(adminConfigBump);
     });
 
+    it('Initializes program config with the program version', async () => {
+      await program.methods
+        .initializeProgramConfig()
+        .accounts({
+          programConfig: programConfigPda,
+          adminConfig: adminConfigPda,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const programConfig = await program.account.programConfig.fetch(
+        programConfigPda
+      );
+      expect(programConfig.programVersion).to.deep.equal({
+        major: 0,
+        minor: 1,
+        patch: 0,
+      });
+      expect(programConfig.minClientVersion).to.deep.equal({
+        major: 0,
+        minor: 0,
+        patch: 0,
+      });
+    });
+
+    it('Initializes the event sequence', async () => {
+      const [eventSequencePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('event_sequence')],
+        program.programId
+      );
+
+      await program.methods
+        .initializeEventSequence()
+        .accounts({
+          eventSequence: eventSequencePda,
+          adminConfig: adminConfigPda,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const eventSequence = await program.account.eventSequence.fetch(
+        eventSequencePda
+      );
+      expect(eventSequence.last.toNumber()).to.equal(0);
+    });
+
     it('Adds a new admin successfully', async () => {
       await program.methods
         .addAdmin(admin1.publicKey)
         .accounts({
           adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
           authority: authority.publicKey,
         })
+        .remainingAccounts(adminRecordAccounts(admin1.publicKey))
         .rpc();
 
       const adminConfig = await program.account.adminConfig.fetch(
@@ -104,8 +179,10 @@ describe('sign-document-contract', () => {
           .addAdmin(admin2.publicKey)
           .accounts({
             adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
             authority: unauthorizedUser.publicKey,
           })
+          .remainingAccounts(adminRecordAccounts(admin2.publicKey))
           .signers([unauthorizedUser])
           .rpc();
 
@@ -121,8 +198,10 @@ describe('sign-document-contract', () => {
           .addAdmin(admin1.publicKey)
           .accounts({
             adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
             authority: authority.publicKey,
           })
+          .remainingAccounts(adminRecordAccounts(admin1.publicKey))
           .rpc();
 
         expect.fail('Should have thrown an error');
@@ -137,8 +216,10 @@ describe('sign-document-contract', () => {
         .addAdmin(admin2.publicKey)
         .accounts({
           adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
           authority: authority.publicKey,
         })
+        .remainingAccounts(adminRecordAccounts(admin2.publicKey))
         .rpc();
 
       // Then remove admin1
@@ -146,6 +227,7 @@ describe('sign-document-contract', () => {
         .removeAdmin(admin1.publicKey)
         .accounts({
           adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
           authority: authority.publicKey,
         })
         .rpc();
@@ -171,6 +253,7 @@ describe('sign-document-contract', () => {
         .removeAdmin(admin2.publicKey)
         .accounts({
           adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
           authority: authority.publicKey,
         })
         .rpc();
@@ -181,6 +264,7 @@ describe('sign-document-contract', () => {
           .removeAdmin(authority.publicKey)
           .accounts({
             adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
             authority: authority.publicKey,
           })
           .rpc();
@@ -190,208 +274,5065 @@ describe('sign-document-contract', () => {
         expect(error.toString()).to.include('CannotRemoveLastAdmin');
       }
     });
-  });
 
-  describe('Form Approval', () => {
-    before(async () => {
-      // Re-add admin1 for form approval tests
+    it('Adds an invited admin only after they accept', async () => {
+      const [invitationPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('admin_invitation'), admin2.publicKey.toBuffer()],
+        program.programId
+      );
+
       await program.methods
-        .addAdmin(admin1.publicKey)
+        .inviteAdmin(admin2.publicKey)
         .accounts({
+          adminInvitation: invitationPda,
           adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
           authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
         })
         .rpc();
-    });
 
-    it('Signs a form submission successfully', async () => {
-      const beforeTimestamp = Math.floor(Date.now() / 1000);
+      let adminConfig = await program.account.adminConfig.fetch(
+        adminConfigPda
+      );
+      expect(adminConfig.adminCount).to.equal(1);
+      const invitation = await program.account.adminInvitation.fetch(
+        invitationPda
+      );
+      expect(
+        invitation.expiresAt.sub(invitation.invitedAt).toNumber()
+      ).to.equal(7 * 24 * 3600);
 
       await program.methods
-        .signFormSubmission(testFormId, Array.from(testFormHash), testMetadata)
+        .acceptAdminInvitation()
         .accounts({
-          formApproval: formApprovalPda,
+          adminInvitation: invitationPda,
           adminConfig: adminConfigPda,
-          admin: admin1.publicKey,
-          systemProgram: SystemProgram.programId,
+          programConfig: programConfigPda,
+          invitedBy: authority.publicKey,
+          invitee: admin2.publicKey,
         })
-        .signers([admin1])
+        .signers([admin2])
         .rpc();
 
-      // Verify the form approval was created
-      const formApproval = await program.account.formApproval.fetch(
-        formApprovalPda
-      );
-      expect(formApproval.formId).to.equal(testFormId);
-      expect(Buffer.from(formApproval.formHash)).to.deep.equal(testFormHash);
-      expect(formApproval.signer.toString()).to.equal(
-        admin1.publicKey.toString()
-      );
-      expect(formApproval.approvedAt.toNumber()).to.be.greaterThanOrEqual(
-        beforeTimestamp
+      adminConfig = await program.account.adminConfig.fetch(adminConfigPda);
+      expect(adminConfig.adminCount).to.equal(2);
+      expect(adminConfig.admins[1].toString()).to.equal(
+        admin2.publicKey.toString()
       );
-      expect(formApproval.metadata).to.equal(testMetadata);
-      expect(formApproval.bump).to.equal(formApprovalBump);
+      expect(await provider.connection.getAccountInfo(invitationPda)).to.be
+        .null;
+
+      await program.methods
+        .removeAdmin(admin2.publicKey)
+        .accounts({
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
     });
 
-    it('Prevents unauthorized users from signing forms', async () => {
-      const unauthorizedFormId = 'unauthorized_form';
-      const [unauthorizedFormPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from('form_approval'), Buffer.from(unauthorizedFormId)],
+    it('Prevents accepting an invitation sent to another key', async () => {
+      const [invitationPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('admin_invitation'), admin2.publicKey.toBuffer()],
         program.programId
       );
 
+      await program.methods
+        .inviteAdmin(admin2.publicKey)
+        .accounts({
+          adminInvitation: invitationPda,
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
       try {
         await program.methods
-          .signFormSubmission(
-            unauthorizedFormId,
-            Array.from(testFormHash),
-            null
-          )
+          .acceptAdminInvitation()
           .accounts({
-            formApproval: unauthorizedFormPda,
+            adminInvitation: invitationPda,
             adminConfig: adminConfigPda,
-            admin: unauthorizedUser.publicKey,
-            systemProgram: SystemProgram.programId,
+            programConfig: programConfigPda,
+            invitedBy: authority.publicKey,
+            invitee: unauthorizedUser.publicKey,
           })
           .signers([unauthorizedUser])
           .rpc();
 
         expect.fail('Should have thrown an error');
       } catch (error) {
-        expect(error.toString()).to.include('UnauthorizedAdmin');
+        expect(error.toString()).to.include('ConstraintSeeds');
       }
+
+      await program.methods
+        .cancelAdminInvitation(admin2.publicKey)
+        .accounts({
+          adminInvitation: invitationPda,
+          adminConfig: adminConfigPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+      expect(await provider.connection.getAccountInfo(invitationPda)).to.be
+        .null;
     });
 
-    it('Prevents signing forms with invalid hash', async () => {
-      const invalidFormId = 'invalid_form';
-      const [invalidFormPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from('form_approval'), Buffer.from(invalidFormId)],
+    it('Keeps a scheduled removal reversible until it is effective', async () => {
+      const [removalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('admin_removal'), admin2.publicKey.toBuffer()],
         program.programId
       );
-      const invalidHash = new Array(32).fill(0);
+      const effectiveAt = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+
+      await program.methods
+        .addAdmin(admin2.publicKey)
+        .accounts({
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          authority: authority.publicKey,
+        })
+        .remainingAccounts(adminRecordAccounts(admin2.publicKey))
+        .rpc();
+
+      await program.methods
+        .scheduleAdminRemoval(admin2.publicKey, effectiveAt)
+        .accounts({
+          adminRemoval: removalPda,
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
 
       try {
         await program.methods
-          .signFormSubmission(invalidFormId, invalidHash, null)
+          .executeAdminRemoval(admin2.publicKey)
           .accounts({
-            formApproval: invalidFormPda,
+            adminRemoval: removalPda,
             adminConfig: adminConfigPda,
-            admin: admin1.publicKey,
-            systemProgram: SystemProgram.programId,
+            scheduledBy: authority.publicKey,
           })
-          .signers([admin1])
           .rpc();
 
         expect.fail('Should have thrown an error');
       } catch (error) {
-        expect(error.toString()).to.include('InvalidFormHash');
+        expect(error.toString()).to.include('RemovalNotYetEffective');
       }
+
+      await program.methods
+        .cancelAdminRemoval(admin2.publicKey)
+        .accounts({
+          adminRemoval: removalPda,
+          adminConfig: adminConfigPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+
+      const adminConfig = await program.account.adminConfig.fetch(
+        adminConfigPda
+      );
+      expect(adminConfig.adminCount).to.equal(2);
+      expect(await provider.connection.getAccountInfo(removalPda)).to.be.null;
+
+      await program.methods
+        .removeAdmin(admin2.publicKey)
+        .accounts({
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
     });
 
-    it('Prevents form ID that is too long', async () => {
-      const longFormId = 'a'.repeat(65); // Exceeds MAX_FORM_ID_LENGTH (64)
-      const [longFormPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from('form_approval'), Buffer.from(longFormId)],
-        program.programId
+    it('Registers admins who prove inclusion in the import root', async () => {
+      const leaf = (key: PublicKey) =>
+        crypto
+          .createHash('sha256')
+          .update(Buffer.concat([Buffer.from([0]), key.toBuffer()]))
+          .digest();
+      const admin2Leaf = leaf(admin2.publicKey);
+      const otherLeaf = leaf(unauthorizedUser.publicKey);
+      const [low, high] =
+        Buffer.compare(admin2Leaf, otherLeaf) <= 0
+          ? [admin2Leaf, otherLeaf]
+          : [otherLeaf, admin2Leaf];
+      const root = crypto
+        .createHash('sha256')
+        .update(Buffer.concat([Buffer.from([1]), low, high]))
+        .digest();
+
+      const setImportRoot = (importRoot: number[]) =>
+        program.methods
+          .setAdminImportRoot(importRoot)
+          .accounts({
+            programConfig: programConfigPda,
+            adminConfig: adminConfigPda,
+            authority: authority.publicKey,
+          })
+          .rpc();
+
+      await setImportRoot(Array.from(root));
+
+      await program.methods
+        .registerAdminWithProof([Array.from(otherLeaf)])
+        .accounts({
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          candidate: admin2.publicKey,
+        })
+        .signers([admin2])
+        .rpc();
+
+      const adminConfig = await program.account.adminConfig.fetch(
+        adminConfigPda
+      );
+      expect(adminConfig.admins[1].toString()).to.equal(
+        admin2.publicKey.toString()
       );
 
       try {
         await program.methods
-          .signFormSubmission(longFormId, Array.from(testFormHash), null)
+          .registerAdminWithProof([Array.from(otherLeaf)])
           .accounts({
-            formApproval: longFormPda,
             adminConfig: adminConfigPda,
-            admin: admin1.publicKey,
-            systemProgram: SystemProgram.programId,
+            programConfig: programConfigPda,
+            candidate: admin1.publicKey,
           })
           .signers([admin1])
           .rpc();
 
         expect.fail('Should have thrown an error');
       } catch (error) {
-        expect(error.toString()).to.include('FormIdTooLong');
+        expect(error.toString()).to.include('InvalidAdminProof');
       }
+
+      await setImportRoot(Array(32).fill(0));
+      await program.methods
+        .removeAdmin(admin2.publicKey)
+        .accounts({
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
     });
 
-    it('Updates form approval metadata successfully', async () => {
-      const newMetadata = 'Updated metadata';
+    it('Replaces an admin in place', async () => {
+      await program.methods
+        .addAdmin(admin1.publicKey)
+        .accounts({
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          authority: authority.publicKey,
+        })
+        .remainingAccounts(adminRecordAccounts(admin1.publicKey))
+        .rpc();
 
       await program.methods
-        .updateFormApproval(testFormId, newMetadata)
+        .replaceAdmin(admin1.publicKey, admin2.publicKey)
         .accounts({
-          formApproval: formApprovalPda,
           adminConfig: adminConfigPda,
-          admin: admin1.publicKey,
+          programConfig: programConfigPda,
+          authority: authority.publicKey,
         })
-        .signers([admin1])
         .rpc();
 
-      const formApproval = await program.account.formApproval.fetch(
-        formApprovalPda
+      const adminConfig = await program.account.adminConfig.fetch(
+        adminConfigPda
+      );
+      expect(adminConfig.adminCount).to.equal(2);
+      expect(adminConfig.admins[1].toString()).to.equal(
+        admin2.publicKey.toString()
       );
-      expect(formApproval.metadata).to.equal(newMetadata);
-    });
 
-    it('Prevents unauthorized users from updating metadata', async () => {
       try {
         await program.methods
-          .updateFormApproval(testFormId, 'Unauthorized update')
+          .replaceAdmin(admin2.publicKey, authority.publicKey)
           .accounts({
-            formApproval: formApprovalPda,
             adminConfig: adminConfigPda,
-            admin: unauthorizedUser.publicKey,
+            programConfig: programConfigPda,
+            authority: authority.publicKey,
           })
-          .signers([unauthorizedUser])
           .rpc();
 
         expect.fail('Should have thrown an error');
       } catch (error) {
-        expect(error.toString()).to.include('UnauthorizedAdmin');
+        expect(error.toString()).to.include('AdminAlreadyExists');
       }
-    });
 
-    it('Verifies form approval successfully', async () => {
-      const result = await program.methods
-        .verifyFormApproval(testFormId, Array.from(testFormHash))
+      await program.methods
+        .removeAdmin(admin2.publicKey)
         .accounts({
-          formApproval: formApprovalPda,
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          authority: authority.publicKey,
         })
-        .view();
-
-      expect(result).to.be.true;
+        .rpc();
     });
 
-    it('Returns false for incorrect hash verification', async () => {
-      const wrongHash = crypto
-        .createHash('sha256')
-        .update('wrong data')
-        .digest();
+    it('Restricts admins to their permission flags', async () => {
+      const operatorFormId = 'operator_form';
+      const [operatorFormPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('form_approval'), Buffer.from(operatorFormId)],
+        program.programId
+      );
 
-      const result = await program.methods
-        .verifyFormApproval(testFormId, Array.from(wrongHash))
+      await program.methods
+        .addAdmin(admin1.publicKey)
         .accounts({
-          formApproval: formApprovalPda,
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          authority: authority.publicKey,
         })
-        .view();
+        .remainingAccounts(adminRecordAccounts(admin1.publicKey))
+        .rpc();
 
-      expect(result).to.be.false;
-    });
+      let adminConfig = await program.account.adminConfig.fetch(adminConfigPda);
+      expect(adminConfig.permissions[1]).to.equal(0b0111);
 
-    it('Gets form approval details successfully', async () => {
-      const [formId, formHash, signer, approvedAt, metadata] =
+      // CAN_UPDATE only
+      await program.methods
+        .setAdminPermissions(admin1.publicKey, 0b0010)
+        .accounts({
+          adminConfig: adminConfigPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+
+      try {
         await program.methods
-          .getFormApprovalDetails(testFormId)
-          .accounts({
+          .signFormSubmission(
+            operatorFormId,
+            Array.from(testFormHash),
+            null,
+            {
+              clientVersion: null,
+              reasonCode: null,
+              timestampTokenHash: null,
+              locale: null,
+              jurisdiction: null,
+              compliance: null,
+              clientTimestamp: null,
+              requiredSignatures: null,
+              expiresAt: null,
+              counterparties: null,
+              requiredSigners: null,
+              notBefore: null,
+              notAfter: null,
+              dependsOn: null,
+              documentUri: null,
+              contentType: null,
+              hashAlgorithm: null,
+              formHashTail: null,
+              pageCount: null,
+              allowDuplicateHash: null,
+            }
+          )
+          .accounts({
+            formApproval: operatorFormPda,
+            adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
+            admin: admin1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin1])
+          .rpc();
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('MissingPermission');
+      }
+
+      try {
+        await program.methods
+          .setAdminPermissions(admin1.publicKey, 0b10000)
+          .accounts({
+            adminConfig: adminConfigPda,
+            authority: authority.publicKey,
+          })
+          .rpc();
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('InvalidPermissions');
+      }
+
+      // CAN_MANAGE_ADMINS lets the admin add others alongside the authority
+      await program.methods
+        .setAdminPermissions(admin1.publicKey, 0b1000)
+        .accounts({
+          adminConfig: adminConfigPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+      await program.methods
+        .addAdmin(admin2.publicKey)
+        .accounts({
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          authority: admin1.publicKey,
+        })
+        .remainingAccounts(adminRecordAccounts(admin2.publicKey))
+        .signers([admin1])
+        .rpc();
+
+      adminConfig = await program.account.adminConfig.fetch(adminConfigPda);
+      expect(adminConfig.adminCount).to.equal(3);
+
+      for (const admin of [admin2, admin1]) {
+        await program.methods
+          .removeAdmin(admin.publicKey)
+          .accounts({
+            adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
+            authority: authority.publicKey,
+          })
+          .rpc();
+      }
+    });
+
+    it('Lapses temporary admin rights at their expiry', async () => {
+      const tempFormId = 'temporary_admin_form';
+      const [tempFormPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('form_approval'), Buffer.from(tempFormId)],
+        program.programId
+      );
+      const setExpiry = (expiresAt: number) =>
+        program.methods
+          .setAdminExpiry(admin1.publicKey, new anchor.BN(expiresAt))
+          .accounts({
+            adminConfig: adminConfigPda,
+            authority: authority.publicKey,
+          })
+          .rpc();
+
+      await program.methods
+        .addAdmin(admin1.publicKey)
+        .accounts({
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          authority: authority.publicKey,
+        })
+        .remainingAccounts(adminRecordAccounts(admin1.publicKey))
+        .rpc();
+
+      const now = Math.floor(Date.now() / 1000);
+      try {
+        await setExpiry(now - 60);
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('InvalidAdminExpiry');
+      }
+
+      await setExpiry(now + 2);
+      await new Promise((resolve) => setTimeout(resolve, 3000));
+
+      try {
+        await program.methods
+          .signFormSubmission(tempFormId, Array.from(testFormHash), null, {
+            clientVersion: null,
+            reasonCode: null,
+            timestampTokenHash: null,
+            locale: null,
+            jurisdiction: null,
+            compliance: null,
+            clientTimestamp: null,
+            requiredSignatures: null,
+            expiresAt: null,
+            counterparties: null,
+            requiredSigners: null,
+            notBefore: null,
+            notAfter: null,
+            dependsOn: null,
+            documentUri: null,
+            contentType: null,
+            hashAlgorithm: null,
+            formHashTail: null,
+            pageCount: null,
+            allowDuplicateHash: null,
+          })
+          .accounts({
+            formApproval: tempFormPda,
+            adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
+            admin: admin1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin1])
+          .rpc();
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('UnauthorizedAdmin');
+      }
+
+      await setExpiry(0);
+      const adminConfig = await program.account.adminConfig.fetch(
+        adminConfigPda
+      );
+      expect(adminConfig.expiresAt[1].toNumber()).to.equal(0);
+
+      await program.methods
+        .removeAdmin(admin1.publicKey)
+        .accounts({
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+    });
+
+    it('Registers admins beyond the fixed list as records', async () => {
+      const recordFormId = 'admin_record_form';
+      const [recordFormPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('form_approval'), Buffer.from(recordFormId)],
+        program.programId
+      );
+      const [adminRecordPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('admin_record'), admin2.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .addAdminRecord(admin2.publicKey)
+        .accounts({
+          adminRecord: adminRecordPda,
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      let adminConfig = await program.account.adminConfig.fetch(adminConfigPda);
+      expect(adminConfig.recordCount).to.equal(1);
+
+      await program.methods
+        .signFormSubmission(recordFormId, Array.from(testFormHash), null, {
+          clientVersion: null,
+          reasonCode: null,
+          timestampTokenHash: null,
+          locale: null,
+          jurisdiction: null,
+          compliance: null,
+          clientTimestamp: null,
+          requiredSignatures: null,
+          expiresAt: null,
+          counterparties: null,
+          requiredSigners: null,
+          notBefore: null,
+          notAfter: null,
+          dependsOn: null,
+          documentUri: null,
+          contentType: null,
+          hashAlgorithm: null,
+          formHashTail: null,
+          pageCount: null,
+          allowDuplicateHash: null,
+        })
+        .accounts({
+          formApproval: recordFormPda,
+          adminConfig: adminConfigPda,
+          adminRecord: adminRecordPda,
+          programConfig: programConfigPda,
+          admin: admin2.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin2])
+        .rpc();
+
+      const recordApproval = await program.account.formApproval.fetch(
+        recordFormPda
+      );
+      expect(recordApproval.signer.toString()).to.equal(
+        admin2.publicKey.toString()
+      );
+
+      try {
+        await program.methods
+          .addAdmin(admin2.publicKey)
+          .accounts({
+            adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
+            authority: authority.publicKey,
+          })
+          .remainingAccounts(adminRecordAccounts(admin2.publicKey))
+          .rpc();
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('AdminAlreadyExists');
+      }
+
+      await program.methods
+        .removeAdminRecord(admin2.publicKey)
+        .accounts({
+          adminRecord: adminRecordPda,
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+
+      adminConfig = await program.account.adminConfig.fetch(adminConfigPda);
+      expect(adminConfig.recordCount).to.equal(0);
+    });
+
+    it('Adds and removes admins in batches', async () => {
+      const batch = [admin1.publicKey, admin2.publicKey];
+
+      await program.methods
+        .addAdmins(batch)
+        .accounts({
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          authority: authority.publicKey,
+        })
+        .remainingAccounts(adminRecordAccounts(...batch))
+        .rpc();
+
+      let adminConfig = await program.account.adminConfig.fetch(adminConfigPda);
+      expect(adminConfig.adminCount).to.equal(3);
+
+      try {
+        await program.methods
+          .addAdmins([])
+          .accounts({
+            adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
+            authority: authority.publicKey,
+          })
+          .rpc();
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('InvalidAdminBatch');
+      }
+
+      await program.methods
+        .removeAdmins(batch)
+        .accounts({
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+
+      adminConfig = await program.account.adminConfig.fetch(adminConfigPda);
+      expect(adminConfig.adminCount).to.equal(1);
+    });
+
+    it('Transfers the authority only once the new key accepts', async () => {
+      const propose = (newAuthority: PublicKey, signer?: Keypair) =>
+        program.methods
+          .proposeAuthorityTransfer(newAuthority)
+          .accounts({
+            adminConfig: adminConfigPda,
+            authority: signer ? signer.publicKey : authority.publicKey,
+          })
+          .signers(signer ? [signer] : [])
+          .rpc();
+      const accept = (signer?: Keypair) =>
+        program.methods
+          .acceptAuthorityTransfer()
+          .accounts({
+            adminConfig: adminConfigPda,
+            newAuthority: signer ? signer.publicKey : authority.publicKey,
+          })
+          .signers(signer ? [signer] : [])
+          .rpc();
+
+      await propose(admin1.publicKey);
+      let adminConfig = await program.account.adminConfig.fetch(adminConfigPda);
+      expect(adminConfig.authority.toString()).to.equal(
+        authority.publicKey.toString()
+      );
+      expect(adminConfig.pendingAuthority.toString()).to.equal(
+        admin1.publicKey.toString()
+      );
+
+      try {
+        await accept(admin2);
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('NotPendingAuthority');
+      }
+
+      await accept(admin1);
+      adminConfig = await program.account.adminConfig.fetch(adminConfigPda);
+      expect(adminConfig.authority.toString()).to.equal(
+        admin1.publicKey.toString()
+      );
+      expect(adminConfig.pendingAuthority.toString()).to.equal(
+        PublicKey.default.toString()
+      );
+
+      // Hand the authority back for the remaining tests
+      await propose(authority.publicKey, admin1);
+      await accept();
+    });
+  });
+
+  describe('Program Configuration', () => {
+    it('Rejects clients older than the minimum version', async () => {
+      const gatedFormId = 'version_gated_form';
+      const [gatedFormPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('form_approval'), Buffer.from(gatedFormId)],
+        program.programId
+      );
+
+      await program.methods
+        .setMinClientVersion({ major: 0, minor: 2, patch: 0 })
+        .accounts({
+          programConfig: programConfigPda,
+          adminConfig: adminConfigPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+
+      for (const [clientVersion, expectedError] of [
+        [null, 'ClientVersionRequired'],
+        [{ major: 0, minor: 1, patch: 9 }, 'ClientVersionTooOld'],
+      ]) {
+        try {
+          await program.methods
+            .signFormSubmission(
+              gatedFormId,
+              Array.from(testFormHash),
+              null,
+              {
+                clientVersion,
+                reasonCode: null,
+                timestampTokenHash: null,
+                locale: null,
+                jurisdiction: null,
+                compliance: null,
+                clientTimestamp: null,
+                requiredSignatures: null,
+                expiresAt: null,
+                counterparties: null,
+                requiredSigners: null,
+                notBefore: null,
+                notAfter: null,
+                dependsOn: null,
+                documentUri: null,
+                contentType: null,
+                hashAlgorithm: null,
+                formHashTail: null,
+                pageCount: null,
+                allowDuplicateHash: null,
+              }
+            )
+            .accounts({
+              formApproval: gatedFormPda,
+              adminConfig: adminConfigPda,
+              programConfig: programConfigPda,
+              admin: authority.publicKey,
+              systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+          expect.fail('Should have thrown an error');
+        } catch (error) {
+          expect(error.toString()).to.include(expectedError);
+        }
+      }
+
+      await program.methods
+        .signFormSubmission(gatedFormId, Array.from(testFormHash), null, {
+          clientVersion: { major: 0, minor: 2, patch: 0 },
+          reasonCode: null,
+          timestampTokenHash: null,
+          locale: null,
+          jurisdiction: null,
+          compliance: null,
+          clientTimestamp: null,
+          requiredSignatures: null,
+          expiresAt: null,
+          counterparties: null,
+          requiredSigners: null,
+          notBefore: null,
+          notAfter: null,
+          dependsOn: null,
+          documentUri: null,
+          contentType: null,
+          hashAlgorithm: null,
+          formHashTail: null,
+          pageCount: null,
+          allowDuplicateHash: null,
+        })
+        .accounts({
+          formApproval: gatedFormPda,
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          admin: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const formApproval = await program.account.formApproval.fetch(
+        gatedFormPda
+      );
+      expect(formApproval.programVersion).to.deep.equal({
+        major: 0,
+        minor: 1,
+        patch: 0,
+      });
+
+      // Restore the default so later tests can sign without a version
+      await program.methods
+        .setMinClientVersion({ major: 0, minor: 0, patch: 0 })
+        .accounts({
+          programConfig: programConfigPda,
+          adminConfig: adminConfigPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+    });
+
+    it('Rejects deprecated instructions', async () => {
+      const retiredFormId = 'retired_instruction_form';
+      const [retiredFormPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('form_approval'), Buffer.from(retiredFormId)],
+        program.programId
+      );
+
+      await program.methods
+        .setInstructionDeprecated({ signFormSubmission: {} }, true)
+        .accounts({
+          programConfig: programConfigPda,
+          adminConfig: adminConfigPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+
+      try {
+        await program.methods
+          .signFormSubmission(
+            retiredFormId,
+            Array.from(testFormHash),
+            null,
+            null
+          )
+          .accounts({
+            formApproval: retiredFormPda,
+            adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
+            admin: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('InstructionDeprecated');
+      }
+
+      await program.methods
+        .setInstructionDeprecated({ signFormSubmission: {} }, false)
+        .accounts({
+          programConfig: programConfigPda,
+          adminConfig: adminConfigPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+
+      const programConfig = await program.account.programConfig.fetch(
+        programConfigPda
+      );
+      expect(programConfig.deprecatedInstructions.toNumber()).to.equal(0);
+    });
+
+    it('Manages the CPI allow-list', async () => {
+      const callerProgram = Keypair.generate().publicKey;
+
+      await program.methods
+        .allowCpiProgram(callerProgram)
+        .accounts({
+          programConfig: programConfigPda,
+          adminConfig: adminConfigPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+
+      let programConfig = await program.account.programConfig.fetch(
+        programConfigPda
+      );
+      expect(programConfig.cpiAllowList.map((key) => key.toString())).to.include(
+        callerProgram.toString()
+      );
+
+      try {
+        await program.methods
+          .allowCpiProgram(callerProgram)
+          .accounts({
+            programConfig: programConfigPda,
+            adminConfig: adminConfigPda,
+            authority: authority.publicKey,
+          })
+          .rpc();
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('CpiProgramAlreadyAllowed');
+      }
+
+      await program.methods
+        .disallowCpiProgram(callerProgram)
+        .accounts({
+          programConfig: programConfigPda,
+          adminConfig: adminConfigPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+
+      programConfig = await program.account.programConfig.fetch(
+        programConfigPda
+      );
+      expect(programConfig.cpiAllowList).to.have.length(0);
+    });
+
+    it('Sequences configuration changes', async () => {
+      const before = await program.account.programConfig.fetch(
+        programConfigPda
+      );
+
+      let changed = null;
+      const listener = program.addEventListener('configChanged', (event) => {
+        changed = event;
+      });
+      await program.methods
+        .setCheckpointQuorum(1)
+        .accounts({
+          programConfig: programConfigPda,
+          adminConfig: adminConfigPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      await program.removeEventListener(listener);
+
+      const after = await program.account.programConfig.fetch(
+        programConfigPda
+      );
+      expect(after.configSequence.toNumber()).to.equal(
+        before.configSequence.toNumber() + 1
+      );
+      expect(changed).to.not.be.null;
+      expect(changed.setting).to.equal('checkpoint_quorum');
+      expect(changed.changedBy.toString()).to.equal(
+        authority.publicKey.toString()
+      );
+      expect(changed.priorHash).to.not.deep.equal(changed.newHash);
+    });
+
+    it('Stores the issuer branding shown on certificates', async () => {
+      const [brandingConfigPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('branding_config')],
+        program.programId
+      );
+      const hash = (value: string) =>
+        Array.from(crypto.createHash('sha256').update(value).digest());
+
+      await program.methods
+        .setBranding(
+          hash('https://example.edu/logo.png'),
+          'Example University Registrar',
+          hash('registrar@example.edu')
+        )
+        .accounts({
+          brandingConfig: brandingConfigPda,
+          adminConfig: adminConfigPda,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const brandingConfig = await program.account.brandingConfig.fetch(
+        brandingConfigPda
+      );
+      expect(brandingConfig.issuerDisplayName).to.equal(
+        'Example University Registrar'
+      );
+      expect(brandingConfig.updatedBy.toString()).to.equal(
+        authority.publicKey.toString()
+      );
+    });
+
+    it('Versions the webhook endpoint manifest', async () => {
+      const [webhookManifestPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('webhook_manifest')],
+        program.programId
+      );
+      const publish = (manifest: string) =>
+        program.methods
+          .setWebhookManifest(
+            Array.from(crypto.createHash('sha256').update(manifest).digest())
+          )
+          .accounts({
+            webhookManifest: webhookManifestPda,
+            adminConfig: adminConfigPda,
+            authority: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+
+      await publish('{"endpoints":["https://hooks.example.com/a"]}');
+      await publish('{"endpoints":["https://hooks.example.com/b"]}');
+
+      const webhookManifest = await program.account.webhookManifest.fetch(
+        webhookManifestPda
+      );
+      expect(webhookManifest.version).to.equal(2);
+    });
+
+    it('Flags counters that fell below the last health report', async () => {
+      const programConfig = await program.account.programConfig.fetch(
+        programConfigPda
+      );
+      const assertInvariants = (configSequence: anchor.BN) =>
+        program.methods
+          .assertInvariants('', {
+            configSequence,
+            checkpointCount: programConfig.checkpointCount,
+          })
+          .accounts({
+            adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
+            approvalTally: null,
+          })
+          .view();
+
+      expect(await assertInvariants(programConfig.configSequence)).to.be.true;
+      expect(
+        await assertInvariants(programConfig.configSequence.addn(1))
+      ).to.be.false;
+    });
+
+    it('Applies queued admin changes once finalized', async () => {
+      const queue = async (
+        change: object,
+        adminRecord: PublicKey | null = null
+      ) => {
+        const { adminChangeCount } = await program.account.programConfig.fetch(
+          programConfigPda
+        );
+        const [pendingAdminChangePda] = PublicKey.findProgramAddressSync(
+          [
+            Buffer.from('admin_change'),
+            adminChangeCount.toArrayLike(Buffer, 'le', 8),
+          ],
+          program.programId
+        );
+        await program.methods
+          .queueAdminChange(change)
+          .accounts({
+            pendingAdminChange: pendingAdminChangePda,
+            adminConfig: adminConfigPda,
+            adminRecord,
+            programConfig: programConfigPda,
+            authority: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        return { id: adminChangeCount, pendingAdminChangePda, adminRecord };
+      };
+      const finalize = ({ id, pendingAdminChangePda, adminRecord }) =>
+        program.methods
+          .finalizeAdminChange(id)
+          .accounts({
+            pendingAdminChange: pendingAdminChangePda,
+            adminConfig: adminConfigPda,
+            adminRecord,
+            programConfig: programConfigPda,
+            queuedBy: authority.publicKey,
+            payer: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+
+      const cancelled = await queue(
+        { add: { admin: admin1.publicKey } },
+        adminRecordAddress(admin1.publicKey)
+      );
+      await program.methods
+        .cancelAdminChange(cancelled.id)
+        .accounts({
+          pendingAdminChange: cancelled.pendingAdminChangePda,
+          adminConfig: adminConfigPda,
+          queuedBy: authority.publicKey,
+          admin: authority.publicKey,
+        })
+        .rpc();
+      expect(
+        await provider.connection.getAccountInfo(
+          cancelled.pendingAdminChangePda
+        )
+      ).to.be.null;
+
+      // With no delay configured, a queued change is effective immediately
+      await finalize(
+        await queue(
+          { add: { admin: admin1.publicKey } },
+          adminRecordAddress(admin1.publicKey)
+        )
+      );
+      let adminConfig = await program.account.adminConfig.fetch(adminConfigPda);
+      expect(adminConfig.admins[1].toString()).to.equal(
+        admin1.publicKey.toString()
+      );
+
+      await finalize(await queue({ remove: { admin: admin1.publicKey } }));
+      adminConfig = await program.account.adminConfig.fetch(adminConfigPda);
+      expect(adminConfig.adminCount).to.equal(1);
+
+      const recordAddress = adminRecordAddress(admin2.publicKey);
+      await finalize(
+        await queue({ addRecord: { admin: admin2.publicKey } }, recordAddress)
+      );
+      const adminRecord = await program.account.adminRecord.fetch(
+        recordAddress
+      );
+      expect(adminRecord.admin.toString()).to.equal(
+        admin2.publicKey.toString()
+      );
+      adminConfig = await program.account.adminConfig.fetch(adminConfigPda);
+      expect(adminConfig.recordCount).to.equal(1);
+
+      await finalize(
+        await queue(
+          { removeRecord: { admin: admin2.publicKey } },
+          recordAddress
+        )
+      );
+      expect(await provider.connection.getAccountInfo(recordAddress)).to.be
+        .null;
+      adminConfig = await program.account.adminConfig.fetch(adminConfigPda);
+      expect(adminConfig.recordCount).to.equal(0);
+    });
+
+    it('Rejects signing while the program is paused', async () => {
+      const pausedFormId = 'paused_form';
+      const [pausedFormPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('form_approval'), Buffer.from(pausedFormId)],
+        program.programId
+      );
+      const setPause = (paused: boolean) =>
+        program.methods
+          .setPause(paused)
+          .accounts({
+            programConfig: programConfigPda,
+            adminConfig: adminConfigPda,
+            authority: authority.publicKey,
+          })
+          .rpc();
+
+      await setPause(true);
+      try {
+        await program.methods
+          .signFormSubmission(pausedFormId, Array.from(testFormHash), null, {
+            clientVersion: null,
+            reasonCode: null,
+            timestampTokenHash: null,
+            locale: null,
+            jurisdiction: null,
+            compliance: null,
+            clientTimestamp: null,
+            requiredSignatures: null,
+            expiresAt: null,
+            counterparties: null,
+            requiredSigners: null,
+            notBefore: null,
+            notAfter: null,
+            dependsOn: null,
+            documentUri: null,
+            contentType: null,
+            hashAlgorithm: null,
+            formHashTail: null,
+            pageCount: null,
+            allowDuplicateHash: null,
+          })
+          .accounts({
+            formApproval: pausedFormPda,
+            adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
+            admin: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('ProgramPaused');
+      }
+      await setPause(false);
+
+      const programConfig = await program.account.programConfig.fetch(
+        programConfigPda
+      );
+      expect(programConfig.paused).to.be.false;
+    });
+  });
+
+  describe('Feature Gates', () => {
+    it('Initializes feature gates with everything disabled', async () => {
+      await program.methods
+        .initializeFeatureGates()
+        .accounts({
+          featureGates: featureGatesPda,
+          adminConfig: adminConfigPda,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const featureGates = await program.account.featureGates.fetch(
+        featureGatesPda
+      );
+      expect(featureGates.multiSignEnabled).to.be.false;
+    });
+
+    it('Toggles a feature gate', async () => {
+      await program.methods
+        .setFeatureGate({ multiSign: {} }, true)
+        .accounts({
+          featureGates: featureGatesPda,
+          adminConfig: adminConfigPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+
+      const featureGates = await program.account.featureGates.fetch(
+        featureGatesPda
+      );
+      expect(featureGates.multiSignEnabled).to.be.true;
+    });
+
+    it('Prevents unauthorized users from toggling features', async () => {
+      try {
+        await program.methods
+          .setFeatureGate({ multiSign: {} }, false)
+          .accounts({
+            featureGates: featureGatesPda,
+            adminConfig: adminConfigPda,
+            authority: unauthorizedUser.publicKey,
+          })
+          .signers([unauthorizedUser])
+          .rpc();
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('UnauthorizedAdmin');
+      }
+    });
+  });
+
+  describe('Form Approval', () => {
+    before(async () => {
+      // Re-add admin1 for form approval tests
+      await program.methods
+        .addAdmin(admin1.publicKey)
+        .accounts({
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          authority: authority.publicKey,
+        })
+        .remainingAccounts(adminRecordAccounts(admin1.publicKey))
+        .rpc();
+    });
+
+    it('Signs a form submission successfully', async () => {
+      const beforeTimestamp = Math.floor(Date.now() / 1000);
+
+      await program.methods
+        .signFormSubmission(
+          testFormId,
+          Array.from(testFormHash),
+          testMetadata,
+          null
+        )
+        .accounts({
+          formApproval: formApprovalPda,
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          admin: admin1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin1])
+        .rpc();
+
+      // Verify the form approval was created
+      const formApproval = await program.account.formApproval.fetch(
+        formApprovalPda
+      );
+      expect(formApproval.formId).to.equal(testFormId);
+      expect(Buffer.from(formApproval.formHash)).to.deep.equal(testFormHash);
+      expect(formApproval.signer.toString()).to.equal(
+        admin1.publicKey.toString()
+      );
+      expect(formApproval.approvedAt.toNumber()).to.be.greaterThanOrEqual(
+        beforeTimestamp
+      );
+      expect(formApproval.metadata).to.equal(testMetadata);
+      expect(formApproval.bump).to.equal(formApprovalBump);
+      expect(formApproval.approvedSlot.toNumber()).to.be.greaterThan(0);
+      expect(formApproval.reasonCode).to.deep.equal({ approved: {} });
+    });
+
+    it('Tracks signing activity per admin', async () => {
+      const adminConfig = await program.account.adminConfig.fetch(
+        adminConfigPda
+      );
+      const slot = adminConfig.admins.findIndex((admin) =>
+        admin.equals(admin1.publicKey)
+      );
+      const formApproval = await program.account.formApproval.fetch(
+        formApprovalPda
+      );
+
+      expect(adminConfig.signaturesCount[slot]).to.equal(1);
+      expect(adminConfig.lastSignedAt[slot].toNumber()).to.equal(
+        formApproval.approvedAt.toNumber()
+      );
+    });
+
+    it('Links new approvals into the approval hash chain', async () => {
+      const chainedFormId = 'chained_form';
+      const [chainedFormPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('form_approval'), Buffer.from(chainedFormId)],
+        program.programId
+      );
+      const [approvalChainPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('approval_chain')],
+        program.programId
+      );
+      const before = await program.account.approvalChain.fetch(
+        approvalChainPda
+      );
+
+      await program.methods
+        .signFormSubmission(chainedFormId, Array.from(testFormHash), null, null)
+        .accounts({
+          formApproval: chainedFormPda,
+          approvalChain: approvalChainPda,
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          admin: admin1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin1])
+        .rpc();
+
+      const approval = await program.account.formApproval.fetch(
+        chainedFormPda
+      );
+      const after = await program.account.approvalChain.fetch(
+        approvalChainPda
+      );
+      expect(after.length.toNumber()).to.equal(before.length.toNumber() + 1);
+      expect(approval.chainIndex.toNumber()).to.equal(after.length.toNumber());
+      expect(approval.prevChainHash).to.deep.equal(before.head);
+      expect(after.head).to.not.deep.equal(before.head);
+    });
+
+    it('Records a standardized reason code', async () => {
+      const reasonFormId = 'reason_code_form';
+      const [reasonFormPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('form_approval'), Buffer.from(reasonFormId)],
+        program.programId
+      );
+      const sign = (reasonCode: object) =>
+        program.methods
+          .signFormSubmission(reasonFormId, Array.from(testFormHash), null, {
+            clientVersion: null,
+            reasonCode,
+            timestampTokenHash: null,
+            locale: null,
+            jurisdiction: null,
+            compliance: null,
+            clientTimestamp: null,
+            requiredSignatures: null,
+            expiresAt: null,
+            counterparties: null,
+            requiredSigners: null,
+            notBefore: null,
+            notAfter: null,
+            dependsOn: null,
+            documentUri: null,
+            contentType: null,
+            hashAlgorithm: null,
+            formHashTail: null,
+            pageCount: null,
+            allowDuplicateHash: null,
+          })
+          .accounts({
+            formApproval: reasonFormPda,
+            adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
+            admin: admin1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin1])
+          .rpc();
+
+      try {
+        await sign({ rejectedPolicy: {} });
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('InvalidReasonCode');
+      }
+
+      await sign({ approvedWithChanges: {} });
+
+      const formApproval = await program.account.formApproval.fetch(
+        reasonFormPda
+      );
+      expect(formApproval.reasonCode).to.deep.equal({
+        approvedWithChanges: {},
+      });
+    });
+
+    it('Stores an external timestamp token hash', async () => {
+      const stampedFormId = 'timestamped_form';
+      const [stampedFormPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('form_approval'), Buffer.from(stampedFormId)],
+        program.programId
+      );
+      const tokenHash = crypto
+        .createHash('sha256')
+        .update('rfc3161 timestamp token')
+        .digest();
+
+      await program.methods
+        .signFormSubmission(stampedFormId, Array.from(testFormHash), null, {
+          clientVersion: null,
+          reasonCode: null,
+          timestampTokenHash: Array.from(tokenHash),
+          locale: null,
+          jurisdiction: null,
+          compliance: null,
+          clientTimestamp: null,
+          requiredSignatures: null,
+          expiresAt: null,
+          counterparties: null,
+          requiredSigners: null,
+          notBefore: null,
+          notAfter: null,
+          dependsOn: null,
+          documentUri: null,
+          contentType: null,
+          hashAlgorithm: null,
+          formHashTail: null,
+          pageCount: null,
+          allowDuplicateHash: null,
+        })
+        .accounts({
+          formApproval: stampedFormPda,
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          admin: admin1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin1])
+        .rpc();
+
+      const formApproval = await program.account.formApproval.fetch(
+        stampedFormPda
+      );
+      expect(Buffer.from(formApproval.timestampTokenHash)).to.deep.equal(
+        tokenHash
+      );
+    });
+
+    it('Records a client signing time within the clock skew', async () => {
+      const clientTimedFormId = 'client_timed_form';
+      const [clientTimedFormPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('form_approval'), Buffer.from(clientTimedFormId)],
+        program.programId
+      );
+      const sign = (clientTimestamp: number) =>
+        program.methods
+          .signFormSubmission(
+            clientTimedFormId,
+            Array.from(testFormHash),
+            null,
+            {
+              clientVersion: null,
+              reasonCode: null,
+              timestampTokenHash: null,
+              locale: null,
+              jurisdiction: null,
+              compliance: null,
+              clientTimestamp: new anchor.BN(clientTimestamp),
+              requiredSignatures: null,
+              expiresAt: null,
+              counterparties: null,
+              requiredSigners: null,
+              notBefore: null,
+              notAfter: null,
+              dependsOn: null,
+              documentUri: null,
+              contentType: null,
+              hashAlgorithm: null,
+              formHashTail: null,
+              pageCount: null,
+              allowDuplicateHash: null,
+            }
+          )
+          .accounts({
+            formApproval: clientTimedFormPda,
+            adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
+            admin: admin1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin1])
+          .rpc();
+      const now = Math.floor(Date.now() / 1000);
+
+      try {
+        await sign(now - 3600);
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('ClientTimestampOutOfRange');
+      }
+
+      await sign(now - 5);
+      const formApproval = await program.account.formApproval.fetch(
+        clientTimedFormPda
+      );
+      expect(formApproval.clientSignedAt.toNumber()).to.equal(now - 5);
+      expect(formApproval.approvedAt.toNumber()).to.be.greaterThan(0);
+    });
+
+    it('Keeps an M-of-N approval pending until co-signed', async () => {
+      const quorumFormId = 'quorum_form';
+      const [quorumFormPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('form_approval'), Buffer.from(quorumFormId)],
+        program.programId
+      );
+      const verifyQuorumForm = () =>
+        program.methods
+          .verifyFormApproval(quorumFormId, Array.from(testFormHash))
+          .accounts({ formApproval: quorumFormPda })
+          .view();
+
+      await program.methods
+        .signFormSubmission(quorumFormId, Array.from(testFormHash), null, {
+          clientVersion: null,
+          reasonCode: null,
+          timestampTokenHash: null,
+          locale: null,
+          jurisdiction: null,
+          compliance: null,
+          clientTimestamp: null,
+          requiredSignatures: 2,
+          expiresAt: null,
+          counterparties: null,
+          requiredSigners: null,
+          notBefore: null,
+          notAfter: null,
+          dependsOn: null,
+          documentUri: null,
+          contentType: null,
+          hashAlgorithm: null,
+          formHashTail: null,
+          pageCount: null,
+          allowDuplicateHash: null,
+        })
+        .accounts({
+          formApproval: quorumFormPda,
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          featureGates: featureGatesPda,
+          admin: admin1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin1])
+        .rpc();
+
+      let formApproval = await program.account.formApproval.fetch(
+        quorumFormPda
+      );
+      expect(formApproval.status).to.deep.equal({ pending: {} });
+      expect(await verifyQuorumForm()).to.be.false;
+
+      await program.methods
+        .coSignForm(quorumFormId)
+        .accounts({
+          formApproval: quorumFormPda,
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          featureGates: featureGatesPda,
+          admin: authority.publicKey,
+        })
+        .rpc();
+
+      formApproval = await program.account.formApproval.fetch(quorumFormPda);
+      expect(formApproval.status).to.deep.equal({ approved: {} });
+      expect(
+        formApproval.coSigners.map((key) => key.toString())
+      ).to.deep.equal([authority.publicKey.toString()]);
+      expect(await verifyQuorumForm()).to.be.true;
+    });
+
+    it('Refuses multi-signature requests while the gate is off', async () => {
+      const gatedFormId = 'gated_quorum_form';
+      const [gatedFormPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('form_approval'), Buffer.from(gatedFormId)],
+        program.programId
+      );
+      const setMultiSign = (enabled: boolean) =>
+        program.methods
+          .setFeatureGate({ multiSign: {} }, enabled)
+          .accounts({
+            featureGates: featureGatesPda,
+            adminConfig: adminConfigPda,
+            authority: authority.publicKey,
+          })
+          .rpc();
+
+      await setMultiSign(false);
+      try {
+        await program.methods
+          .signFormSubmission(gatedFormId, Array.from(testFormHash), null, {
+            clientVersion: null,
+            reasonCode: null,
+            timestampTokenHash: null,
+            locale: null,
+            jurisdiction: null,
+            compliance: null,
+            clientTimestamp: null,
+            requiredSignatures: 2,
+            expiresAt: null,
+            counterparties: null,
+            requiredSigners: null,
+            notBefore: null,
+            notAfter: null,
+            dependsOn: null,
+            documentUri: null,
+            contentType: null,
+            hashAlgorithm: null,
+            formHashTail: null,
+            pageCount: null,
+            allowDuplicateHash: null,
+          })
+          .accounts({
+            formApproval: gatedFormPda,
+            adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
+            featureGates: featureGatesPda,
+            admin: admin1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin1])
+          .rpc();
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('FeatureDisabled');
+      }
+      await setMultiSign(true);
+    });
+
+    it('Refuses signatures outside the signing window', async () => {
+      const windowFormId = 'window_form';
+      const [windowFormPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('form_approval'), Buffer.from(windowFormId)],
+        program.programId
+      );
+      const blockTime = await provider.connection.getBlockTime(
+        await provider.connection.getSlot()
+      );
+      const signWindowForm = (notBefore: number, notAfter: number) =>
+        program.methods
+          .signFormSubmission(windowFormId, Array.from(testFormHash), null, {
+            clientVersion: null,
+            reasonCode: null,
+            timestampTokenHash: null,
+            locale: null,
+            jurisdiction: null,
+            compliance: null,
+            clientTimestamp: null,
+            requiredSignatures: 2,
+            expiresAt: null,
+            counterparties: null,
+            requiredSigners: null,
+            notBefore: new anchor.BN(notBefore),
+            notAfter: new anchor.BN(notAfter),
+            dependsOn: null,
+            documentUri: null,
+            contentType: null,
+            hashAlgorithm: null,
+            formHashTail: null,
+            pageCount: null,
+            allowDuplicateHash: null,
+          })
+          .accounts({
+            formApproval: windowFormPda,
+            adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
+            featureGates: featureGatesPda,
+            admin: admin1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin1])
+          .rpc();
+
+      try {
+        await signWindowForm(blockTime + 3600, blockTime + 7200);
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('SigningWindowNotOpen');
+      }
+
+      await signWindowForm(blockTime - 60, blockTime + 3);
+
+      const formApproval = await program.account.formApproval.fetch(
+        windowFormPda
+      );
+      expect(formApproval.notBefore.toNumber()).to.equal(blockTime - 60);
+
+      await new Promise((resolve) => setTimeout(resolve, 5000));
+
+      try {
+        await program.methods
+          .coSignForm(windowFormId)
+          .accounts({
+            formApproval: windowFormPda,
+            adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
+            featureGates: featureGatesPda,
+            admin: authority.publicKey,
+          })
+          .rpc();
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('SigningWindowClosed');
+      }
+    });
+
+    it('Requires dependency approvals to be in force', async () => {
+      const dependentFormId = 'dependent_form';
+      const [dependentFormPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('form_approval'), Buffer.from(dependentFormId)],
+        program.programId
+      );
+      const signDependent = (remainingAccounts: PublicKey[]) =>
+        program.methods
+          .signFormSubmission(dependentFormId, Array.from(testFormHash), null, {
+            clientVersion: null,
+            reasonCode: null,
+            timestampTokenHash: null,
+            locale: null,
+            jurisdiction: null,
+            compliance: null,
+            clientTimestamp: null,
+            requiredSignatures: null,
+            expiresAt: null,
+            counterparties: null,
+            requiredSigners: null,
+            notBefore: null,
+            notAfter: null,
+            dependsOn: [formApprovalPda],
+            documentUri: null,
+            contentType: null,
+            hashAlgorithm: null,
+            formHashTail: null,
+            pageCount: null,
+            allowDuplicateHash: null,
+          })
+          .accounts({
+            formApproval: dependentFormPda,
+            adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
+            admin: admin1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .remainingAccounts(
+            remainingAccounts.map((pubkey) => ({
+              pubkey,
+              isSigner: false,
+              isWritable: false,
+            }))
+          )
+          .signers([admin1])
+          .rpc();
+
+      try {
+        await signDependent([]);
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('MissingDependency');
+      }
+
+      await signDependent([formApprovalPda]);
+      const formApproval = await program.account.formApproval.fetch(
+        dependentFormPda
+      );
+      expect(
+        formApproval.dependencies.map((key) => key.toString())
+      ).to.deep.equal([formApprovalPda.toString()]);
+    });
+
+    it('Throttles signature reminders by admin preference', async () => {
+      const remindedFormId = 'reminded_form';
+      const [remindedFormPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('form_approval'), Buffer.from(remindedFormId)],
+        program.programId
+      );
+      const [notificationPrefsPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('notification_prefs'), authority.publicKey.toBuffer()],
+        program.programId
+      );
+      const remind = () =>
+        program.methods
+          .sendSignatureReminder(remindedFormId)
+          .accounts({
+            formApproval: remindedFormPda,
+            notificationPrefs: notificationPrefsPda,
+            adminConfig: adminConfigPda,
+          })
+          .rpc();
+
+      await program.methods
+        .signFormSubmission(remindedFormId, Array.from(testFormHash), null, {
+          clientVersion: null,
+          reasonCode: null,
+          timestampTokenHash: null,
+          locale: null,
+          jurisdiction: null,
+          compliance: null,
+          clientTimestamp: null,
+          requiredSignatures: 2,
+          expiresAt: null,
+          counterparties: null,
+          requiredSigners: null,
+          notBefore: null,
+          notAfter: null,
+          dependsOn: null,
+          documentUri: null,
+          contentType: null,
+          hashAlgorithm: null,
+          formHashTail: null,
+          pageCount: null,
+          allowDuplicateHash: null,
+        })
+        .accounts({
+          formApproval: remindedFormPda,
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          featureGates: featureGatesPda,
+          admin: admin1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin1])
+        .rpc();
+      await program.methods
+        .setNotificationPrefs(new anchor.BN(3600), false, Array(32).fill(7))
+        .accounts({
+          notificationPrefs: notificationPrefsPda,
+          adminConfig: adminConfigPda,
+          admin: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      await remind();
+      try {
+        await remind();
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('ReminderNotDue');
+      }
+    });
+
+    it('Tracks counter-signatures from named parties', async () => {
+      const counterSignedFormId = 'counter_signed_form';
+      const counterparty = Keypair.generate();
+      const [counterSignedFormPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('form_approval'), Buffer.from(counterSignedFormId)],
+        program.programId
+      );
+      const counterSignaturePda = (signer: Keypair) =>
+        PublicKey.findProgramAddressSync(
+          [
+            Buffer.from('counter_signature'),
+            Buffer.from(counterSignedFormId),
+            signer.publicKey.toBuffer(),
+          ],
+          program.programId
+        )[0];
+      const counterSign = (signer: Keypair) =>
+        program.methods
+          .counterSignForm(counterSignedFormId, Array.from(testFormHash))
+          .accounts({
+            counterSignature: counterSignaturePda(signer),
+            formApproval: counterSignedFormPda,
+            programConfig: programConfigPda,
+            signer: signer.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([signer])
+          .rpc();
+
+      await provider.connection.requestAirdrop(counterparty.publicKey, 2e9);
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      await program.methods
+        .signFormSubmission(
+          counterSignedFormId,
+          Array.from(testFormHash),
+          null,
+          {
+            clientVersion: null,
+            reasonCode: null,
+            timestampTokenHash: null,
+            locale: null,
+            jurisdiction: null,
+            compliance: null,
+            clientTimestamp: null,
+            requiredSignatures: null,
+            expiresAt: null,
+            counterparties: [counterparty.publicKey],
+            requiredSigners: [admin1.publicKey, counterparty.publicKey],
+            notBefore: null,
+            notAfter: null,
+            dependsOn: null,
+            documentUri: null,
+            contentType: null,
+            hashAlgorithm: null,
+            formHashTail: null,
+            pageCount: null,
+            allowDuplicateHash: null,
+          }
+        )
+        .accounts({
+          formApproval: counterSignedFormPda,
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          featureGates: featureGatesPda,
+          admin: admin1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin1])
+        .rpc();
+
+      const signingProgress = () =>
+        program.methods
+          .getSigningProgress(counterSignedFormId)
+          .accounts({ formApproval: counterSignedFormPda })
+          .view();
+      let progress = await signingProgress();
+      expect(progress.outstanding.map((key) => key.toString())).to.deep.equal([
+        counterparty.publicKey.toString(),
+      ]);
+
+      try {
+        await counterSign(unauthorizedUser);
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('NotACounterparty');
+      }
+
+      await counterSign(counterparty);
+      const counterSignature = await program.account.counterSignature.fetch(
+        counterSignaturePda(counterparty)
+      );
+      expect(counterSignature.signer.toString()).to.equal(
+        counterparty.publicKey.toString()
+      );
+      progress = await signingProgress();
+      expect(progress.signed).to.have.length(2);
+      expect(progress.outstanding).to.be.empty;
+    });
+
+    it('Renews an expiring approval before it lapses', async () => {
+      const expiringFormId = 'expiring_form';
+      const [expiringFormPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('form_approval'), Buffer.from(expiringFormId)],
+        program.programId
+      );
+      const expiresAt = Math.floor(Date.now() / 1000) + 3600;
+      const renew = (until: number) =>
+        program.methods
+          .renewFormApproval(expiringFormId, new anchor.BN(until))
+          .accounts({
+            formApproval: expiringFormPda,
+            adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
+            admin: admin1.publicKey,
+          })
+          .signers([admin1])
+          .rpc();
+
+      await program.methods
+        .signFormSubmission(expiringFormId, Array.from(testFormHash), null, {
+          clientVersion: null,
+          reasonCode: null,
+          timestampTokenHash: null,
+          locale: null,
+          jurisdiction: null,
+          compliance: null,
+          clientTimestamp: null,
+          requiredSignatures: null,
+          expiresAt: new anchor.BN(expiresAt),
+          counterparties: null,
+          requiredSigners: null,
+          notBefore: null,
+          notAfter: null,
+          dependsOn: null,
+          documentUri: null,
+          contentType: null,
+          hashAlgorithm: null,
+          formHashTail: null,
+          pageCount: null,
+          allowDuplicateHash: null,
+        })
+        .accounts({
+          formApproval: expiringFormPda,
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          admin: admin1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin1])
+        .rpc();
+
+      try {
+        await renew(expiresAt - 60);
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('InvalidExpiry');
+      }
+
+      await renew(expiresAt + 3600);
+      const formApproval = await program.account.formApproval.fetch(
+        expiringFormPda
+      );
+      expect(formApproval.expiresAt.toNumber()).to.equal(expiresAt + 3600);
+      expect(
+        await program.methods
+          .verifyFormApproval(expiringFormId, Array.from(testFormHash))
+          .accounts({ formApproval: expiringFormPda })
+          .view()
+      ).to.be.true;
+    });
+
+    it('Enforces the approval lifecycle on status changes', async () => {
+      const rejectedFormId = 'rejected_form';
+      const [rejectedFormPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('form_approval'), Buffer.from(rejectedFormId)],
+        program.programId
+      );
+      const statusAccounts = {
+        formApproval: rejectedFormPda,
+        adminConfig: adminConfigPda,
+        programConfig: programConfigPda,
+        admin: authority.publicKey,
+      };
+      const setPause = (paused: boolean) =>
+        program.methods
+          .setPause(paused)
+          .accounts({
+            programConfig: programConfigPda,
+            adminConfig: adminConfigPda,
+            authority: authority.publicKey,
+          })
+          .rpc();
+
+      await program.methods
+        .signFormSubmission(rejectedFormId, Array.from(testFormHash), null, {
+          clientVersion: null,
+          reasonCode: null,
+          timestampTokenHash: null,
+          locale: null,
+          jurisdiction: null,
+          compliance: null,
+          clientTimestamp: null,
+          requiredSignatures: 2,
+          expiresAt: null,
+          counterparties: null,
+          requiredSigners: null,
+          notBefore: null,
+          notAfter: null,
+          dependsOn: null,
+          documentUri: null,
+          contentType: null,
+          hashAlgorithm: null,
+          formHashTail: null,
+          pageCount: null,
+          allowDuplicateHash: null,
+        })
+        .accounts({
+          formApproval: rejectedFormPda,
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          featureGates: featureGatesPda,
+          admin: admin1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin1])
+        .rpc();
+
+      await setPause(true);
+      try {
+        await program.methods
+          .transitionFormStatus(rejectedFormId, { rejected: {} })
+          .accounts(statusAccounts)
+          .rpc();
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('ProgramPaused');
+      }
+      await setPause(false);
+
+      await program.methods
+        .transitionFormStatus(rejectedFormId, { rejected: {} })
+        .accounts(statusAccounts)
+        .rpc();
+      const formApproval = await program.account.formApproval.fetch(
+        rejectedFormPda
+      );
+      expect(formApproval.status).to.deep.equal({ rejected: {} });
+
+      try {
+        await program.methods
+          .transitionFormStatus(rejectedFormId, { expired: {} })
+          .accounts(statusAccounts)
+          .rpc();
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('InvalidStatusTransition');
+      }
+
+      try {
+        await program.methods
+          .coSignForm(rejectedFormId)
+          .accounts({
+            formApproval: rejectedFormPda,
+            adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
+            featureGates: featureGatesPda,
+            admin: authority.publicKey,
+          })
+          .rpc();
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('FormNotPending');
+      }
+    });
+
+    it('Records e-signature compliance evidence', async () => {
+      const compliantFormId = 'compliant_form';
+      const [compliantFormPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('form_approval'), Buffer.from(compliantFormId)],
+        program.programId
+      );
+      const intentHash = crypto
+        .createHash('sha256')
+        .update('I intend to sign this agreement electronically')
+        .digest();
+      const sign = (consent: boolean) =>
+        program.methods
+          .signFormSubmission(
+            compliantFormId,
+            Array.from(testFormHash),
+            null,
+            {
+              clientVersion: null,
+              reasonCode: null,
+              timestampTokenHash: null,
+              locale: null,
+              jurisdiction: null,
+              compliance: {
+                consentToElectronicSignature: consent,
+                intentStatementHash: Array.from(intentHash),
+                authenticationMethod: { oneTimeCode: {} },
+              },
+              clientTimestamp: null,
+              requiredSignatures: null,
+              expiresAt: null,
+              counterparties: null,
+              requiredSigners: null,
+              notBefore: null,
+              notAfter: null,
+              dependsOn: null,
+              documentUri: null,
+              contentType: null,
+              hashAlgorithm: null,
+              formHashTail: null,
+              pageCount: null,
+              allowDuplicateHash: null,
+            }
+          )
+          .accounts({
+            formApproval: compliantFormPda,
+            adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
+            admin: admin1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin1])
+          .rpc();
+
+      try {
+        await sign(false);
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('ConsentRequired');
+      }
+
+      await sign(true);
+
+      const formApproval = await program.account.formApproval.fetch(
+        compliantFormPda
+      );
+      expect(formApproval.compliance.consentToElectronicSignature).to.be.true;
+      expect(
+        Buffer.from(formApproval.compliance.intentStatementHash)
+      ).to.deep.equal(intentHash);
+      expect(formApproval.compliance.authenticationMethod).to.deep.equal({
+        oneTimeCode: {},
+      });
+    });
+
+    it('Requires recorded consent in strict-consent mode', async () => {
+      const consentFormId = 'consent_form';
+      const [consentFormPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('form_approval'), Buffer.from(consentFormId)],
+        program.programId
+      );
+      const [consentRecordPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('consent'),
+          Buffer.from(consentFormId),
+          admin1.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+      const setStrictConsent = (enabled: boolean) =>
+        program.methods
+          .setStrictConsent(enabled)
+          .accounts({
+            programConfig: programConfigPda,
+            adminConfig: adminConfigPda,
+            authority: authority.publicKey,
+          })
+          .rpc();
+      const sign = (consentRecord: PublicKey | null) =>
+        program.methods
+          .signFormSubmission(
+            consentFormId,
+            Array.from(testFormHash),
+            null,
+            null
+          )
+          .accounts({
+            formApproval: consentFormPda,
+            adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
+            consentRecord,
+            admin: admin1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin1])
+          .rpc();
+
+      await setStrictConsent(true);
+
+      try {
+        await sign(null);
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('ConsentNotRecorded');
+      }
+
+      await program.methods
+        .recordConsent(
+          consentFormId,
+          Array.from(
+            crypto.createHash('sha256').update('e-sign disclosure').digest()
+          )
+        )
+        .accounts({
+          consentRecord: consentRecordPda,
+          signer: admin1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin1])
+        .rpc();
+      await sign(consentRecordPda);
+      await setStrictConsent(false);
+
+      const formApproval = await program.account.formApproval.fetch(
+        consentFormPda
+      );
+      expect(formApproval.formId).to.equal(consentFormId);
+    });
+
+    it('Leaves current approvals unchanged when migrating', async () => {
+      const before = await program.account.formApproval.fetch(formApprovalPda);
+
+      await program.methods
+        .migrateFormApprovalV1(testFormId)
+        .accounts({
+          formApproval: formApprovalPda,
+          payer: unauthorizedUser.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([unauthorizedUser])
+        .rpc();
+
+      const after = await program.account.formApproval.fetch(formApprovalPda);
+      expect(after.formId).to.equal(before.formId);
+      expect(after.metadata).to.equal(before.metadata);
+      expect(after.programVersion).to.deep.equal(before.programVersion);
+    });
+
+    it('Prevents unauthorized users from signing forms', async () => {
+      const unauthorizedFormId = 'unauthorized_form';
+      const [unauthorizedFormPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('form_approval'), Buffer.from(unauthorizedFormId)],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .signFormSubmission(
+            unauthorizedFormId,
+            Array.from(testFormHash),
+            null,
+            null
+          )
+          .accounts({
+            formApproval: unauthorizedFormPda,
+            adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
+            admin: unauthorizedUser.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([unauthorizedUser])
+          .rpc();
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('UnauthorizedAdmin');
+      }
+    });
+
+    it('Prevents signing forms with invalid hash', async () => {
+      const invalidFormId = 'invalid_form';
+      const [invalidFormPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('form_approval'), Buffer.from(invalidFormId)],
+        program.programId
+      );
+      const invalidHash = new Array(32).fill(0);
+
+      try {
+        await program.methods
+          .signFormSubmission(invalidFormId, invalidHash, null, null)
+          .accounts({
+            formApproval: invalidFormPda,
+            adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
+            admin: admin1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin1])
+          .rpc();
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('InvalidFormHash');
+      }
+    });
+
+    it('Prevents form ID that is too long', async () => {
+      const longFormId = 'a'.repeat(65); // Exceeds MAX_FORM_ID_LENGTH (64)
+      const [longFormPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('form_approval'), Buffer.from(longFormId)],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .signFormSubmission(longFormId, Array.from(testFormHash), null, null)
+          .accounts({
+            formApproval: longFormPda,
+            adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
+            admin: admin1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin1])
+          .rpc();
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('FormIdTooLong');
+      }
+    });
+
+    it('Indexes the approval and detects duplicate hashes', async () => {
+      const indexedFormId = 'indexed_form';
+      const [indexedFormPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('form_approval'), Buffer.from(indexedFormId)],
+        program.programId
+      );
+      const indexedFormHash = crypto
+        .createHash('sha256')
+        .update('indexed form data')
+        .digest();
+      const [hashIndexPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('hash_index'), indexedFormHash],
+        program.programId
+      );
+
+      await program.methods
+        .signFormSubmission(
+          indexedFormId,
+          Array.from(indexedFormHash),
+          null,
+          null
+        )
+        .accounts({
+          formApproval: indexedFormPda,
+          hashIndex: hashIndexPda,
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          admin: admin1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin1])
+        .rpc();
+
+      const hashIndex = await program.account.hashIndex.fetch(hashIndexPda);
+      expect(hashIndex.formId).to.equal(indexedFormId);
+      expect(hashIndex.approval.toString()).to.equal(
+        indexedFormPda.toString()
+      );
+
+      const duplicateFormId = 'duplicate_form';
+      const [duplicateFormPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('form_approval'), Buffer.from(duplicateFormId)],
+        program.programId
+      );
+      const setRejectDuplicates = (reject: boolean) =>
+        program.methods
+          .setRejectDuplicateHashes(reject)
+          .accounts({
+            programConfig: programConfigPda,
+            adminConfig: adminConfigPda,
+            authority: authority.publicKey,
+          })
+          .rpc();
+      const signDuplicate = (allowDuplicateHash: boolean | null) =>
+        program.methods
+          .signFormSubmission(
+            duplicateFormId,
+            Array.from(indexedFormHash),
+            null,
+            {
+              clientVersion: null,
+              reasonCode: null,
+              timestampTokenHash: null,
+              locale: null,
+              jurisdiction: null,
+              compliance: null,
+              clientTimestamp: null,
+              requiredSignatures: null,
+              expiresAt: null,
+              counterparties: null,
+              requiredSigners: null,
+              notBefore: null,
+              notAfter: null,
+              dependsOn: null,
+              documentUri: null,
+              contentType: null,
+              hashAlgorithm: null,
+              formHashTail: null,
+              pageCount: null,
+              allowDuplicateHash,
+            }
+          )
+          .accounts({
+            formApproval: duplicateFormPda,
+            hashIndex: hashIndexPda,
+            adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
+            admin: admin1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin1])
+          .rpc();
+
+      await setRejectDuplicates(true);
+      try {
+        await signDuplicate(null);
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('DuplicateFormHash');
+      }
+      await signDuplicate(true);
+      await setRejectDuplicates(false);
+
+      const duplicate = await program.account.formApproval.fetch(
+        duplicateFormPda
+      );
+      expect(duplicate.duplicateOf.toString()).to.equal(
+        indexedFormPda.toString()
+      );
+      const updatedIndex = await program.account.hashIndex.fetch(hashIndexPda);
+      expect(updatedIndex.duplicateCount).to.equal(1);
+    });
+
+    it('Updates form approval metadata successfully', async () => {
+      const newMetadata = 'Updated metadata';
+
+      let updated = null;
+      const listener = program.addEventListener('formUpdated', (event) => {
+        updated = event;
+      });
+      await program.methods
+        .updateFormApproval(testFormId, newMetadata)
+        .accounts({
+          formApproval: formApprovalPda,
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          admin: admin1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin1])
+        .rpc();
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      await program.removeEventListener(listener);
+
+      const formApproval = await program.account.formApproval.fetch(
+        formApprovalPda
+      );
+      expect(formApproval.metadata).to.equal(newMetadata);
+      expect(updated).to.not.be.null;
+      expect(updated.formId).to.equal(testFormId);
+      expect(updated.updatedBy.toString()).to.equal(
+        admin1.publicKey.toString()
+      );
+
+      const [eventSequencePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('event_sequence')],
+        program.programId
+      );
+      const eventSequence = await program.account.eventSequence.fetch(
+        eventSequencePda
+      );
+      expect(updated.eventSequence.toNumber()).to.equal(
+        eventSequence.last.toNumber()
+      );
+    });
+
+    it('Records metadata updates in the form history', async () => {
+      const [formHistoryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('form_history'), formApprovalPda.toBuffer()],
+        program.programId
+      );
+
+      const formHistory = await program.account.formHistory.fetch(
+        formHistoryPda
+      );
+      const latest = formHistory.entries[formHistory.entries.length - 1];
+      expect(latest.action).to.deep.equal({ updated: {} });
+      expect(latest.actor.toString()).to.equal(admin1.publicKey.toString());
+      expect(Buffer.from(latest.summaryHash)).to.deep.equal(
+        crypto.createHash('sha256').update('Updated metadata').digest()
+      );
+      expect(formHistory.entryCount).to.equal(formHistory.entries.length);
+    });
+
+    it('Grows the approval account to fit longer metadata', async () => {
+      const longMetadata = 'm'.repeat(256);
+      const before = await provider.connection.getAccountInfo(formApprovalPda);
+      await program.methods
+        .updateFormApproval(testFormId, longMetadata)
+        .accounts({
+          formApproval: formApprovalPda,
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          admin: admin1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin1])
+        .rpc();
+
+      const after = await provider.connection.getAccountInfo(formApprovalPda);
+      expect(after.data.length).to.be.greaterThan(before.data.length);
+      const formApproval = await program.account.formApproval.fetch(
+        formApprovalPda
+      );
+      expect(formApproval.metadata).to.equal(longMetadata);
+    });
+
+    it('Updates where the signed document is located', async () => {
+      const updateLocation = (documentUri: string, contentType: string) =>
+        program.methods
+          .updateDocumentLocation(testFormId, documentUri, contentType)
+          .accounts({
+            formApproval: formApprovalPda,
+            adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
+            admin: admin1.publicKey,
+          })
+          .signers([admin1])
+          .rpc();
+
+      const documentUri = 'ar://bNbA3TEQVL60xlgCcqdz4ZPHFZ711cZ3hmkpGttDt_U';
+      await updateLocation(documentUri, 'application/pdf');
+      const formApproval = await program.account.formApproval.fetch(
+        formApprovalPda
+      );
+      expect(formApproval.documentUri).to.equal(documentUri);
+      expect(formApproval.contentType).to.equal('application/pdf');
+
+      try {
+        await updateLocation('ftp://files.example.com/nda.pdf', '');
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('InvalidDocumentUri');
+      }
+    });
+
+    it('Notifies registered observers of form changes', async () => {
+      const [formObserverPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('observer'),
+          Buffer.from(testFormId),
+          authority.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+      await program.methods
+        .registerObserver(testFormId)
+        .accounts({
+          formObserver: formObserverPda,
+          observer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      let notified = null;
+      const listener = program.addEventListener(
+        'observerNotified',
+        (event) => {
+          notified = event;
+        }
+      );
+      await program.methods
+        .updateFormApproval(testFormId, 'Updated metadata')
+        .accounts({
+          formApproval: formApprovalPda,
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          admin: admin1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          { pubkey: formObserverPda, isSigner: false, isWritable: false },
+        ])
+        .signers([admin1])
+        .rpc();
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      await program.removeEventListener(listener);
+
+      expect(notified).to.not.be.null;
+      expect(notified.observer.toString()).to.equal(
+        authority.publicKey.toString()
+      );
+      expect(notified.action).to.deep.equal({ updated: {} });
+
+      await program.methods
+        .unregisterObserver(testFormId)
+        .accounts({
+          formObserver: formObserverPda,
+          observer: authority.publicKey,
+        })
+        .rpc();
+    });
+
+    it('Prevents unauthorized users from updating metadata', async () => {
+      try {
+        await program.methods
+          .updateFormApproval(testFormId, 'Unauthorized update')
+          .accounts({
+            formApproval: formApprovalPda,
+            adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
+            admin: unauthorizedUser.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([unauthorizedUser])
+          .rpc();
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('UnauthorizedAdmin');
+      }
+    });
+
+    it('Blocks updates while the approval is frozen', async () => {
+      const setFrozen = (frozen: boolean) =>
+        (frozen
+          ? program.methods.freezeFormApproval(testFormId)
+          : program.methods.unfreezeFormApproval(testFormId)
+        )
+          .accounts({
+            formApproval: formApprovalPda,
+            adminConfig: adminConfigPda,
+            authority: authority.publicKey,
+          })
+          .rpc();
+
+      await setFrozen(true);
+      try {
+        await program.methods
+          .updateFormApproval(testFormId, 'Disputed update')
+          .accounts({
+            formApproval: formApprovalPda,
+            adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
+            admin: admin1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin1])
+          .rpc();
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('FormFrozen');
+      }
+
+      await setFrozen(false);
+      const formApproval = await program.account.formApproval.fetch(
+        formApprovalPda
+      );
+      expect(formApproval.frozen).to.be.false;
+      expect(formApproval.metadata).to.equal('Updated metadata');
+    });
+
+    it('Verifies form approval successfully', async () => {
+      const result = await program.methods
+        .verifyFormApproval(testFormId, Array.from(testFormHash))
+        .accounts({
+          formApproval: formApprovalPda,
+        })
+        .view();
+
+      expect(result).to.be.true;
+    });
+
+    it('Verifies the digest algorithm along with the hash', async () => {
+      const verifyDigest = (algorithm: object) =>
+        program.methods
+          .verifyFormDigest(testFormId, algorithm, testFormHash)
+          .accounts({
+            formApproval: formApprovalPda,
+          })
+          .view();
+
+      const formApproval = await program.account.formApproval.fetch(
+        formApprovalPda
+      );
+      expect(formApproval.hashAlgorithm).to.deep.equal({ sha256: {} });
+      expect(await verifyDigest({ sha256: {} })).to.be.true;
+      expect(await verifyDigest({ keccak256: {} })).to.be.false;
+    });
+
+    it('Records and verifies 64-byte digests', async () => {
+      const sha512FormId = 'sha512_form';
+      const [sha512FormPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('form_approval'), Buffer.from(sha512FormId)],
+        program.programId
+      );
+      const digest = crypto
+        .createHash('sha512')
+        .update('Compliance archive export')
+        .digest();
+      const signSha512 = (formHashTail: number[] | null) =>
+        program.methods
+          .signFormSubmission(
+            sha512FormId,
+            Array.from(digest.subarray(0, 32)),
+            null,
+            {
+              clientVersion: null,
+              reasonCode: null,
+              timestampTokenHash: null,
+              locale: null,
+              jurisdiction: null,
+              compliance: null,
+              clientTimestamp: null,
+              requiredSignatures: null,
+              expiresAt: null,
+              counterparties: null,
+              requiredSigners: null,
+              notBefore: null,
+              notAfter: null,
+              dependsOn: null,
+              documentUri: null,
+              contentType: null,
+              hashAlgorithm: { sha512: {} },
+              formHashTail,
+              pageCount: null,
+              allowDuplicateHash: null,
+            }
+          )
+          .accounts({
+            formApproval: sha512FormPda,
+            adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
+            admin: admin1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin1])
+          .rpc();
+
+      try {
+        await signSha512(null);
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('InvalidDigestLength');
+      }
+
+      await signSha512(Array.from(digest.subarray(32)));
+      const verifyDigest = (expectedDigest: Buffer) =>
+        program.methods
+          .verifyFormDigest(sha512FormId, { sha512: {} }, expectedDigest)
+          .accounts({
+            formApproval: sha512FormPda,
+          })
+          .view();
+      expect(await verifyDigest(digest)).to.be.true;
+      expect(await verifyDigest(digest.subarray(0, 32))).to.be.false;
+      expect(
+        await program.methods
+          .verifyFormApproval(sha512FormId, Array.from(digest.subarray(0, 32)))
+          .accounts({ formApproval: sha512FormPda })
+          .view()
+      ).to.be.false;
+    });
+
+    it('Proves a single page belongs to an approved document', async () => {
+      const pagedFormId = 'paged_form';
+      const [pagedFormPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('form_approval'), Buffer.from(pagedFormId)],
+        program.programId
+      );
+      const sha256 = (...parts: Buffer[]) =>
+        crypto.createHash('sha256').update(Buffer.concat(parts)).digest();
+      const pageHashes = ['cover page', 'terms'].map((page) =>
+        sha256(Buffer.from(page))
+      );
+      const leaves = pageHashes.map((pageHash, index) => {
+        const indexBytes = Buffer.alloc(4);
+        indexBytes.writeUInt32LE(index);
+        return sha256(Buffer.from([0]), indexBytes, pageHash);
+      });
+      const [left, right] = [...leaves].sort(Buffer.compare);
+      const root = sha256(Buffer.from([1]), left, right);
+
+      await program.methods
+        .signFormSubmission(pagedFormId, Array.from(root), null, {
+          clientVersion: null,
+          reasonCode: null,
+          timestampTokenHash: null,
+          locale: null,
+          jurisdiction: null,
+          compliance: null,
+          clientTimestamp: null,
+          requiredSignatures: null,
+          expiresAt: null,
+          counterparties: null,
+          requiredSigners: null,
+          notBefore: null,
+          notAfter: null,
+          dependsOn: null,
+          documentUri: null,
+          contentType: null,
+          hashAlgorithm: null,
+          formHashTail: null,
+          pageCount: 2,
+          allowDuplicateHash: null,
+        })
+        .accounts({
+          formApproval: pagedFormPda,
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          admin: admin1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin1])
+        .rpc();
+
+      const verifyPage = (index: number, pageHash: Buffer) =>
+        program.methods
+          .verifyPageProof(pagedFormId, index, Array.from(pageHash), [
+            Array.from(leaves[1 - index]),
+          ])
+          .accounts({
+            formApproval: pagedFormPda,
+          })
+          .view();
+      expect(await verifyPage(0, pageHashes[0])).to.be.true;
+      expect(await verifyPage(1, pageHashes[1])).to.be.true;
+      expect(await verifyPage(0, pageHashes[1])).to.be.false;
+    });
+
+    it('Verifies uploaded document content on-chain', async () => {
+      const content = Buffer.from('test form data');
+      const [documentUploadPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('document_upload'),
+          Buffer.from(testFormId),
+          admin2.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+      const appendChunk = (offset: number, chunk: Buffer) =>
+        program.methods
+          .appendChunk(testFormId, offset, chunk)
+          .accounts({
+            documentUpload: documentUploadPda,
+            uploader: admin2.publicKey,
+          })
+          .signers([admin2])
+          .rpc();
+
+      await program.methods
+        .beginDocumentUpload(testFormId, content.length)
+        .accounts({
+          documentUpload: documentUploadPda,
+          uploader: admin2.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin2])
+        .rpc();
+      await appendChunk(0, content.subarray(0, 10));
+      try {
+        await appendChunk(4, content.subarray(10));
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('UploadChunkOutOfOrder');
+      }
+      await appendChunk(10, content.subarray(10));
+
+      await program.methods
+        .finalizeDocument(testFormId)
+        .accounts({
+          documentUpload: documentUploadPda,
+          formApproval: formApprovalPda,
+          programConfig: programConfigPda,
+          uploader: admin2.publicKey,
+        })
+        .signers([admin2])
+        .rpc();
+
+      const formApproval = await program.account.formApproval.fetch(
+        formApprovalPda
+      );
+      expect(formApproval.contentVerified).to.be.true;
+      expect(await provider.connection.getAccountInfo(documentUploadPda)).to.be
+        .null;
+    });
+
+    it('Returns false for incorrect hash verification', async () => {
+      const wrongHash = crypto
+        .createHash('sha256')
+        .update('wrong data')
+        .digest();
+
+      const result = await program.methods
+        .verifyFormApproval(testFormId, Array.from(wrongHash))
+        .accounts({
+          formApproval: formApprovalPda,
+        })
+        .view();
+
+      expect(result).to.be.false;
+    });
+
+    it('Records verifications per approval and verifier', async () => {
+      const [verificationStatsPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('verification_stats'), formApprovalPda.toBuffer()],
+        program.programId
+      );
+      const [verifierRecordPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('verifier_record'),
+          formApprovalPda.toBuffer(),
+          authority.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+      const verifyAndRecord = () =>
+        program.methods
+          .verifyAndRecord(testFormId, Array.from(testFormHash))
+          .accounts({
+            formApproval: formApprovalPda,
+            verificationStats: verificationStatsPda,
+            verifierRecord: verifierRecordPda,
+            programConfig: programConfigPda,
+            verifier: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+
+      await verifyAndRecord();
+      await verifyAndRecord();
+
+      const verificationStats =
+        await program.account.verificationStats.fetch(verificationStatsPda);
+      expect(verificationStats.totalVerifications.toNumber()).to.equal(2);
+      expect(verificationStats.verifierCount).to.equal(1);
+
+      const setRateLimit = (limit: number) =>
+        program.methods
+          .setVerifierRateLimit(limit)
+          .accounts({
+            programConfig: programConfigPda,
+            adminConfig: adminConfigPda,
+            authority: authority.publicKey,
+          })
+          .rpc();
+      await setRateLimit(2);
+      try {
+        await verifyAndRecord();
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('VerificationRateLimited');
+      }
+      await setRateLimit(0);
+    });
+
+    it('Counts reported attempts by unauthorized keys', async () => {
+      const [abuseCountersPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('abuse_counters')],
+        program.programId
+      );
+      const evidenceHash = crypto
+        .createHash('sha256')
+        .update('Program log: Error: UnauthorizedAdmin')
+        .digest();
+      const report = (attemptedBy: PublicKey, reporter?: Keypair) =>
+        program.methods
+          .reportUnauthorizedAttempt(attemptedBy, Array.from(evidenceHash))
+          .accounts({
+            abuseCounters: abuseCountersPda,
+            adminConfig: adminConfigPda,
+            reporter: (reporter ?? authority).publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers(reporter ? [reporter] : [])
+          .rpc();
+
+      await report(unauthorizedUser.publicKey, admin1);
+      await report(unauthorizedUser.publicKey);
+      const abuseCounters = await program.account.abuseCounters.fetch(
+        abuseCountersPda
+      );
+      expect(abuseCounters.reportedAttempts.toNumber()).to.equal(2);
+      expect(abuseCounters.confirmedAttempts.toNumber()).to.equal(1);
+      expect(abuseCounters.lastAttemptedBy.toString()).to.equal(
+        unauthorizedUser.publicKey.toString()
+      );
+
+      try {
+        await report(admin1.publicKey, admin1);
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('ReportedKeyIsAdmin');
+      }
+    });
+
+    it('Imports a historical approval with its original timestamp', async () => {
+      const legacyFormId = 'legacy_form';
+      const [legacyFormPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('form_approval'), Buffer.from(legacyFormId)],
+        program.programId
+      );
+      const approvedAt = 1_500_000_000;
+
+      await program.methods
+        .importLegacyApproval(
+          legacyFormId,
+          Array.from(testFormHash),
+          admin2.publicKey,
+          new anchor.BN(approvedAt),
+          new anchor.BN(0),
+          'Migrated from paper archive'
+        )
+        .accounts({
+          formApproval: legacyFormPda,
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const legacyApproval = await program.account.formApproval.fetch(
+        legacyFormPda
+      );
+      expect(legacyApproval.imported).to.be.true;
+      expect(legacyApproval.approvedAt.toNumber()).to.equal(approvedAt);
+      expect(legacyApproval.signer.toString()).to.equal(
+        admin2.publicKey.toString()
+      );
+    });
+
+    it('Gets form approval details successfully', async () => {
+      const details = await program.methods
+        .getFormApprovalDetails(testFormId)
+        .accounts({
+          formApproval: formApprovalPda,
+        })
+        .view();
+
+      expect(details.formId).to.equal(testFormId);
+      expect(Buffer.from(details.formHash)).to.deep.equal(testFormHash);
+      expect(details.signer.toString()).to.equal(admin1.publicKey.toString());
+      expect(details.approvedAt.toNumber()).to.be.greaterThan(0);
+      // From previous test
+      expect(details.metadata).to.equal('Updated metadata');
+    });
+  });
+
+  describe('Checkpoints', () => {
+    const checkpointPda = (checkpointId: number) =>
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('checkpoint'),
+          new anchor.BN(checkpointId).toArrayLike(Buffer, 'le', 8),
+        ],
+        program.programId
+      )[0];
+
+    const now = Math.floor(Date.now() / 1000);
+
+    it('Records a checkpoint for a past period', async () => {
+      const merkleRoot = crypto.randomBytes(32);
+
+      await program.methods
+        .createCheckpoint(
+          new anchor.BN(now - 3600),
+          new anchor.BN(now - 60),
+          Array.from(merkleRoot),
+          3
+        )
+        .accounts({
+          programConfig: programConfigPda,
+          checkpoint: checkpointPda(0),
+          adminConfig: adminConfigPda,
+          admin: admin1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin1])
+        .rpc();
+
+      const checkpoint = await program.account.checkpoint.fetch(
+        checkpointPda(0)
+      );
+      expect(checkpoint.checkpointId.toNumber()).to.equal(0);
+      expect(Buffer.from(checkpoint.merkleRoot)).to.deep.equal(merkleRoot);
+      expect(checkpoint.approvalCount).to.equal(3);
+      expect(checkpoint.createdBy.toString()).to.equal(
+        admin1.publicKey.toString()
+      );
+    });
+
+    it('Prevents overlapping checkpoint periods', async () => {
+      try {
+        await program.methods
+          .createCheckpoint(
+            new anchor.BN(now - 120),
+            new anchor.BN(now - 30),
+            Array.from(crypto.randomBytes(32)),
+            1
+          )
+          .accounts({
+            programConfig: programConfigPda,
+            checkpoint: checkpointPda(1),
+            adminConfig: adminConfigPda,
+            admin: admin1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin1])
+          .rpc();
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('InvalidCheckpointPeriod');
+      }
+    });
+
+    it('Finalizes a checkpoint once the quorum attests', async () => {
+      await program.methods
+        .setCheckpointQuorum(2)
+        .accounts({
+          programConfig: programConfigPda,
+          adminConfig: adminConfigPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+
+      await program.methods
+        .createCheckpoint(
+          new anchor.BN(now - 60),
+          new anchor.BN(now - 10),
+          Array.from(crypto.randomBytes(32)),
+          1
+        )
+        .accounts({
+          programConfig: programConfigPda,
+          checkpoint: checkpointPda(1),
+          adminConfig: adminConfigPda,
+          admin: admin1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin1])
+        .rpc();
+
+      let checkpoint = await program.account.checkpoint.fetch(checkpointPda(1));
+      expect(checkpoint.requiredAttestations).to.equal(2);
+      expect(checkpoint.finalizedAt.toNumber()).to.equal(0);
+
+      try {
+        await program.methods
+          .attestCheckpoint(new anchor.BN(1))
+          .accounts({
+            checkpoint: checkpointPda(1),
+            adminConfig: adminConfigPda,
+            admin: admin1.publicKey,
+          })
+          .signers([admin1])
+          .rpc();
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('CheckpointAlreadyAttested');
+      }
+
+      await program.methods
+        .attestCheckpoint(new anchor.BN(1))
+        .accounts({
+          checkpoint: checkpointPda(1),
+          adminConfig: adminConfigPda,
+          admin: authority.publicKey,
+        })
+        .rpc();
+
+      checkpoint = await program.account.checkpoint.fetch(checkpointPda(1));
+      expect(checkpoint.attesters.length).to.equal(2);
+      expect(checkpoint.finalizedAt.toNumber()).to.be.greaterThan(0);
+
+      await program.methods
+        .setCheckpointQuorum(1)
+        .accounts({
+          programConfig: programConfigPda,
+          adminConfig: adminConfigPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+    });
+
+    it('Caps the checkpoint quorum at the admin count', async () => {
+      const { adminCount } = await program.account.adminConfig.fetch(
+        adminConfigPda
+      );
+
+      try {
+        await program.methods
+          .setCheckpointQuorum(adminCount + 1)
+          .accounts({
+            programConfig: programConfigPda,
+            adminConfig: adminConfigPda,
+            authority: authority.publicKey,
+          })
+          .rpc();
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('InvalidQuorum');
+      }
+    });
+  });
+
+  describe('Per-Signer Approvals', () => {
+    const multiFormId = 'multi_signer_form';
+    const multiFormHash = crypto
+      .createHash('sha256')
+      .update('multi signer document')
+      .digest();
+
+    const signerApprovalPda = (signer: PublicKey, formId = multiFormId) =>
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('signer_approval'),
+          Buffer.from(formId),
+          signer.toBuffer(),
+        ],
+        program.programId
+      )[0];
+
+    const approvalTallyPda = (formId = multiFormId) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from('approval_tally'), Buffer.from(formId)],
+        program.programId
+      )[0];
+
+    const signAsSigner = (
+      signer: Keypair,
+      formId = multiFormId,
+      categoryPolicy: PublicKey | null = null
+    ) =>
+      program.methods
+        .signFormAsSigner(formId, Array.from(multiFormHash), null, null)
+        .accounts({
+          formApproval: signerApprovalPda(signer.publicKey, formId),
+          approvalTally: approvalTallyPda(formId),
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          featureGates: featureGatesPda,
+          categoryPolicy,
+          admin: signer.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers(signer === admin1 ? [admin1] : [])
+        .rpc();
+
+    const setThresholdPolicy = (policy: object) =>
+      program.methods
+        .setThresholdPolicy(policy)
+        .accounts({
+          programConfig: programConfigPda,
+          adminConfig: adminConfigPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+
+    const tryFinalize = (formId = multiFormId) =>
+      program.methods
+        .tryFinalize(formId)
+        .accounts({
+          approvalTally: approvalTallyPda(formId),
+          programConfig: programConfigPda,
+        })
+        .rpc();
+
+    before(async () => {
+      await setThresholdPolicy({ weightedSum: { required: 2 } });
+    });
+
+    it('Lets each admin sign the same form', async () => {
+      await signAsSigner(authority);
+
+      let tally = await program.account.approvalTally.fetch(approvalTallyPda());
+      expect(tally.signatureCount).to.equal(1);
+      expect(tally.policy).to.deep.equal({ weightedSum: { required: 2 } });
+      expect(tally.status).to.deep.equal({ pending: {} });
+
+      await signAsSigner(admin1);
+
+      const approval = await program.account.formApproval.fetch(
+        signerApprovalPda(admin1.publicKey)
+      );
+      expect(approval.signer.toString()).to.equal(admin1.publicKey.toString());
+
+      await tryFinalize();
+
+      tally = await program.account.approvalTally.fetch(approvalTallyPda());
+      expect(tally.signatureCount).to.equal(2);
+      expect(tally.weightedSum).to.equal(2);
+      expect(tally.status).to.deep.equal({ approved: {} });
+      expect(tally.approvedAt.toNumber()).to.be.greaterThan(0);
+    });
+
+    it('Aggregates signatures across signers', async () => {
+      const count = await program.methods
+        .verifyFormSignatures(multiFormId, Array.from(multiFormHash))
+        .accounts({ adminConfig: adminConfigPda })
+        .remainingAccounts(
+          [authority.publicKey, admin1.publicKey].map((signer) => ({
+            pubkey: signerApprovalPda(signer),
+            isSigner: false,
+            isWritable: false,
+          }))
+        )
+        .view();
+      expect(count).to.equal(2);
+
+      const mismatched = await program.methods
+        .verifyFormSignatures(
+          multiFormId,
+          Array.from(crypto.randomBytes(32))
+        )
+        .accounts({ adminConfig: adminConfigPda })
+        .remainingAccounts([
+          {
+            pubkey: signerApprovalPda(admin1.publicKey),
+            isSigner: false,
+            isWritable: false,
+          },
+        ])
+        .view();
+      expect(mismatched).to.equal(0);
+    });
+
+    it('Leaves revoked and expired approvals out of the count', async () => {
+      const countValid = (formId: string, hash: Buffer, approval: PublicKey) =>
+        program.methods
+          .verifyFormSignatures(formId, Array.from(hash))
+          .accounts({ adminConfig: adminConfigPda })
+          .remainingAccounts([
+            { pubkey: approval, isSigner: false, isWritable: false },
+          ])
+          .view();
+
+      const revokedFormId = 'revoked_multi_form';
+      const revokedFormHash = crypto.randomBytes(32);
+      const [revokedFormPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('form_approval'), Buffer.from(revokedFormId)],
+        program.programId
+      );
+      await program.methods
+        .signFormSubmission(
+          revokedFormId,
+          Array.from(revokedFormHash),
+          null,
+          null
+        )
+        .accounts({
+          formApproval: revokedFormPda,
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          admin: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      expect(
+        await countValid(revokedFormId, revokedFormHash, revokedFormPda)
+      ).to.equal(1);
+      await program.methods
+        .revokeFormApproval(revokedFormId, 'Signed in error')
+        .accounts({
+          formApproval: revokedFormPda,
+          adminConfig: adminConfigPda,
+          admin: authority.publicKey,
+        })
+        .rpc();
+      expect(
+        await countValid(revokedFormId, revokedFormHash, revokedFormPda)
+      ).to.equal(0);
+
+      const expiringFormId = 'expiring_multi_form';
+      const blockTime = await provider.connection.getBlockTime(
+        await provider.connection.getSlot()
+      );
+      await program.methods
+        .signFormAsSigner(expiringFormId, Array.from(multiFormHash), null, {
+          clientVersion: null,
+          reasonCode: null,
+          timestampTokenHash: null,
+          locale: null,
+          jurisdiction: null,
+          compliance: null,
+          clientTimestamp: null,
+          requiredSignatures: null,
+          expiresAt: new anchor.BN(blockTime + 3),
+          counterparties: null,
+          requiredSigners: null,
+          notBefore: null,
+          notAfter: null,
+          dependsOn: null,
+          documentUri: null,
+          contentType: null,
+          hashAlgorithm: null,
+          formHashTail: null,
+          pageCount: null,
+          allowDuplicateHash: null,
+        })
+        .accounts({
+          formApproval: signerApprovalPda(authority.publicKey, expiringFormId),
+          approvalTally: approvalTallyPda(expiringFormId),
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          featureGates: featureGatesPda,
+          categoryPolicy: null,
+          admin: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      await new Promise((resolve) => setTimeout(resolve, 5000));
+
+      expect(
+        await countValid(
+          expiringFormId,
+          multiFormHash,
+          signerApprovalPda(authority.publicKey, expiringFormId)
+        )
+      ).to.equal(0);
+    });
+
+    it('Reports a healthy tally only with all its signer approvals', async () => {
+      const baseline = {
+        configSequence: new anchor.BN(0),
+        checkpointCount: new anchor.BN(0),
+      };
+      const assertInvariants = (signers: PublicKey[]) =>
+        program.methods
+          .assertInvariants(multiFormId, baseline)
+          .accounts({
+            adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
+            approvalTally: approvalTallyPda(),
+          })
+          .remainingAccounts(
+            signers.map((signer) => ({
+              pubkey: signerApprovalPda(signer),
+              isSigner: false,
+              isWritable: false,
+            }))
+          )
+          .view();
+
+      expect(await assertInvariants([authority.publicKey, admin1.publicKey]))
+        .to.be.true;
+      expect(await assertInvariants([authority.publicKey])).to.be.false;
+    });
+
+    it('Weights signatures by admin', async () => {
+      const weightedFormId = 'weighted_signer_form';
+      const setWeight = (weight: number) =>
+        program.methods
+          .setAdminWeight(authority.publicKey, weight)
+          .accounts({
+            adminConfig: adminConfigPda,
+            authority: authority.publicKey,
+          })
+          .rpc();
+
+      await setWeight(3);
+      await setThresholdPolicy({ weightedSum: { required: 4 } });
+
+      await signAsSigner(authority, weightedFormId);
+      let tally = await program.account.approvalTally.fetch(
+        approvalTallyPda(weightedFormId)
+      );
+      expect(tally.weightedSum).to.equal(3);
+      expect(tally.status).to.deep.equal({ pending: {} });
+
+      let finalized = null;
+      const listener = program.addEventListener(
+        'approvalFinalized',
+        (event) => {
+          finalized = event;
+        }
+      );
+      await signAsSigner(admin1, weightedFormId);
+      await tryFinalize(weightedFormId);
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+      await program.removeEventListener(listener);
+
+      tally = await program.account.approvalTally.fetch(
+        approvalTallyPda(weightedFormId)
+      );
+      expect(tally.signatureCount).to.equal(2);
+      expect(tally.weightedSum).to.equal(4);
+      expect(tally.status).to.deep.equal({ approved: {} });
+
+      expect(finalized).to.not.be.null;
+      expect(finalized.formId).to.equal(weightedFormId);
+      expect(finalized.signers.map((key) => key.toString())).to.deep.equal([
+        authority.publicKey.toString(),
+        admin1.publicKey.toString(),
+      ]);
+      expect(finalized.weights).to.deep.equal([3, 1]);
+
+      await setWeight(1);
+    });
+
+    it('Applies a category policy requiring a specific signer', async () => {
+      const category = 'contracts';
+      const categoryFormId = 'category_signer_form';
+      const [categoryPolicyPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('category_policy'), Buffer.from(category)],
+        program.programId
+      );
+
+      await program.methods
+        .setCategoryPolicy(category, {
+          requiredSignerPlusCount: {
+            requiredSigner: admin1.publicKey,
+            required: 1,
+          },
+        })
+        .accounts({
+          categoryPolicy: categoryPolicyPda,
+          adminConfig: adminConfigPda,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      await signAsSigner(authority, categoryFormId, categoryPolicyPda);
+      let tally = await program.account.approvalTally.fetch(
+        approvalTallyPda(categoryFormId)
+      );
+      expect(tally.status).to.deep.equal({ pending: {} });
+
+      try {
+        await tryFinalize(categoryFormId);
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('ThresholdNotMet');
+      }
+
+      await signAsSigner(admin1, categoryFormId);
+      await tryFinalize(categoryFormId);
+      tally = await program.account.approvalTally.fetch(
+        approvalTallyPda(categoryFormId)
+      );
+      expect(tally.signers.map((key) => key.toString())).to.include(
+        admin1.publicKey.toString()
+      );
+      expect(tally.status).to.deep.equal({ approved: {} });
+
+      try {
+        await tryFinalize(categoryFormId);
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('TallyAlreadyFinalized');
+      }
+    });
+
+    it('Lets a co-signer withdraw before finalization', async () => {
+      const withdrawFormId = 'withdrawn_signer_form';
+      const withdraw = (formId: string) =>
+        program.methods
+          .withdrawSignature(formId)
+          .accounts({
+            formApproval: signerApprovalPda(admin1.publicKey, formId),
+            approvalTally: approvalTallyPda(formId),
+            programConfig: programConfigPda,
+            signer: admin1.publicKey,
+          })
+          .signers([admin1])
+          .rpc();
+
+      await signAsSigner(admin1, withdrawFormId);
+      await withdraw(withdrawFormId);
+
+      const tally = await program.account.approvalTally.fetch(
+        approvalTallyPda(withdrawFormId)
+      );
+      expect(tally.signatureCount).to.equal(0);
+      expect(tally.weightedSum).to.equal(0);
+      expect(tally.signers).to.have.length(0);
+      expect(
+        await provider.connection.getAccountInfo(
+          signerApprovalPda(admin1.publicKey, withdrawFormId)
+        )
+      ).to.be.null;
+
+      try {
+        await withdraw(multiFormId);
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('TallyAlreadyFinalized');
+      }
+    });
+
+    it('Rejects policies the current admins cannot meet', async () => {
+      try {
+        await setThresholdPolicy({ count: { required: 11 } });
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('InvalidThresholdPolicy');
+      }
+    });
+
+    it('Caps the number of signers per form', async () => {
+      const cappedFormId = 'capped_signer_form';
+      const setMaxSigners = (maxSigners: number) =>
+        program.methods
+          .setMaxSignersPerForm(maxSigners)
+          .accounts({
+            programConfig: programConfigPda,
+            adminConfig: adminConfigPda,
+            authority: authority.publicKey,
+          })
+          .rpc();
+
+      try {
+        await setMaxSigners(0);
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('InvalidSignerLimit');
+      }
+
+      await setMaxSigners(1);
+      await signAsSigner(authority, cappedFormId);
+
+      try {
+        await signAsSigner(admin1, cappedFormId);
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('SignerLimitReached');
+      }
+
+      try {
+        await setThresholdPolicy({ count: { required: 2 } });
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('PolicyExceedsSignerLimit');
+      }
+
+      await setMaxSigners(10);
+    });
+
+    after(async () => {
+      await setThresholdPolicy({ weightedSum: { required: 1 } });
+    });
+  });
+
+  describe('Revocation and Re-approval', () => {
+    const revisedFormId = 'revised_form';
+    const correctedHash = crypto
+      .createHash('sha256')
+      .update('corrected document')
+      .digest();
+    const [revisedFormPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('form_approval'), Buffer.from(revisedFormId)],
+      program.programId
+    );
+
+    const verify = (hash: Buffer) =>
+      program.methods
+        .verifyFormApproval(revisedFormId, Array.from(hash))
+        .accounts({ formApproval: revisedFormPda })
+        .view();
+
+    const reapprove = () =>
+      program.methods
+        .reapproveFormSubmission(revisedFormId, Array.from(correctedHash))
+        .accounts({
+          formApproval: revisedFormPda,
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          admin: admin1.publicKey,
+        })
+        .signers([admin1])
+        .rpc();
+
+    it('Revokes an approval without deleting it', async () => {
+      await program.methods
+        .signFormSubmission(revisedFormId, Array.from(testFormHash), null, null)
+        .accounts({
+          formApproval: revisedFormPda,
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          admin: admin1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin1])
+        .rpc();
+
+      await program.methods
+        .revokeFormApproval(revisedFormId, 'Superseded by corrected filing')
+        .accounts({
+          formApproval: revisedFormPda,
+          adminConfig: adminConfigPda,
+          admin: admin1.publicKey,
+        })
+        .signers([admin1])
+        .rpc();
+
+      const approval = await program.account.formApproval.fetch(
+        revisedFormPda
+      );
+      expect(approval.revokedAt.toNumber()).to.be.greaterThan(0);
+      expect(approval.revocationReason).to.equal(
+        'Superseded by corrected filing'
+      );
+      expect(approval.revokedBy.toString()).to.equal(
+        admin1.publicKey.toString()
+      );
+      expect(await verify(testFormHash)).to.be.false;
+    });
+
+    it('Rejects re-approval while the window is disabled', async () => {
+      try {
+        await reapprove();
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('ReapprovalWindowClosed');
+      }
+    });
+
+    it('Re-approves a corrected document within the window', async () => {
+      await program.methods
+        .setReapprovalWindow(new anchor.BN(3600))
+        .accounts({
+          programConfig: programConfigPda,
+          adminConfig: adminConfigPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+
+      await reapprove();
+
+      const approval = await program.account.formApproval.fetch(
+        revisedFormPda
+      );
+      expect(approval.revision).to.equal(1);
+      expect(approval.revokedAt.toNumber()).to.equal(0);
+      expect(await verify(correctedHash)).to.be.true;
+
+      try {
+        await reapprove();
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('FormNotRevoked');
+      }
+    });
+  });
+
+  describe('Registered Applications', () => {
+    const appKey = Keypair.generate();
+    const [registeredAppPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('registered_app'), appKey.publicKey.toBuffer()],
+      program.programId
+    );
+
+    const formPda = (formId: string) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from('form_approval'), Buffer.from(formId)],
+        program.programId
+      )[0];
+
+    const signViaApp = (formId: string, withApp: boolean) =>
+      program.methods
+        .signFormSubmission(formId, Array.from(testFormHash), null, null)
+        .accounts({
+          formApproval: formPda(formId),
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          registeredApp: withApp ? registeredAppPda : null,
+          appSigner: withApp ? appKey.publicKey : null,
+          admin: admin1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers(withApp ? [admin1, appKey] : [admin1])
+        .rpc();
+
+    const setRequireAppSignature = (required: boolean) =>
+      program.methods
+        .setRequireAppSignature(required)
+        .accounts({
+          programConfig: programConfigPda,
+          adminConfig: adminConfigPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+
+    it('Stamps the registered app on approvals', async () => {
+      await program.methods
+        .registerApp(appKey.publicKey, 'back-office')
+        .accounts({
+          registeredApp: registeredAppPda,
+          adminConfig: adminConfigPda,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      await signViaApp('app_signed_form', true);
+
+      const approval = await program.account.formApproval.fetch(
+        formPda('app_signed_form')
+      );
+      expect(approval.appId.toString()).to.equal(appKey.publicKey.toString());
+    });
+
+    it('Counts usage per registered app', async () => {
+      await program.methods
+        .recordAppVerification('app_signed_form', Array.from(testFormHash))
+        .accounts({
+          formApproval: formPda('app_signed_form'),
+          registeredApp: registeredAppPda,
+          appSigner: appKey.publicKey,
+        })
+        .signers([appKey])
+        .rpc();
+
+      const registeredApp = await program.account.registeredApp.fetch(
+        registeredAppPda
+      );
+      expect(registeredApp.signings.toNumber()).to.equal(1);
+      expect(registeredApp.verifications.toNumber()).to.equal(1);
+    });
+
+    it('Requires an app signature when configured', async () => {
+      await setRequireAppSignature(true);
+
+      try {
+        await signViaApp('app_missing_form', false);
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('AppSignatureRequired');
+      }
+
+      await program.methods
+        .setAppActive(appKey.publicKey, false)
+        .accounts({
+          registeredApp: registeredAppPda,
+          adminConfig: adminConfigPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+
+      try {
+        await signViaApp('app_inactive_form', true);
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('AppInactive');
+      }
+
+      await setRequireAppSignature(false);
+    });
+  });
+
+  describe('Search Keys', () => {
+    const caseNumberHash = crypto
+      .createHash('sha256')
+      .update('case:2024-00042')
+      .digest();
+    const [searchKeyPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('search_key'), caseNumberHash],
+      program.programId
+    );
+
+    it('Finds an approval by the hash of an external reference', async () => {
+      await program.methods
+        .addSearchKey(testFormId, Array.from(caseNumberHash))
+        .accounts({
+          searchKey: searchKeyPda,
+          formApproval: formApprovalPda,
+          adminConfig: adminConfigPda,
+          admin: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const searchKey = await program.account.searchKey.fetch(searchKeyPda);
+      expect(searchKey.approval.toString()).to.equal(
+        formApprovalPda.toString()
+      );
+      expect(searchKey.formId).to.equal(testFormId);
+    });
+
+    it('Prevents non-admins from removing search keys', async () => {
+      try {
+        await program.methods
+          .removeSearchKey(Array.from(caseNumberHash))
+          .accounts({
+            searchKey: searchKeyPda,
+            adminConfig: adminConfigPda,
+            createdBy: authority.publicKey,
+            admin: unauthorizedUser.publicKey,
+          })
+          .signers([unauthorizedUser])
+          .rpc();
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('UnauthorizedAdmin');
+      }
+
+      await program.methods
+        .removeSearchKey(Array.from(caseNumberHash))
+        .accounts({
+          searchKey: searchKeyPda,
+          adminConfig: adminConfigPda,
+          createdBy: authority.publicKey,
+          admin: authority.publicKey,
+        })
+        .rpc();
+      expect(await provider.connection.getAccountInfo(searchKeyPda)).to.be.null;
+    });
+  });
+
+  describe('Archiving', () => {
+    it('Records the hash and location of an archived approval', async () => {
+      const [archiveRecordPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('archive_record'), formApprovalPda.toBuffer()],
+        program.programId
+      );
+      const archiveUri = 's3://records/form_123.json';
+
+      await program.methods
+        .archiveFormApproval(testFormId, archiveUri)
+        .accounts({
+          archiveRecord: archiveRecordPda,
+          formApproval: formApprovalPda,
+          adminConfig: adminConfigPda,
+          admin: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const approvalAccount = await provider.connection.getAccountInfo(
+        formApprovalPda
+      );
+      const archiveRecord = await program.account.archiveRecord.fetch(
+        archiveRecordPda
+      );
+      expect(archiveRecord.archiveUri).to.equal(archiveUri);
+      expect(Buffer.from(archiveRecord.approvalHash)).to.deep.equal(
+        crypto.createHash('sha256').update(approvalAccount.data).digest()
+      );
+    });
+
+    it('Records approvals exported in an Arweave bundle', async () => {
+      const bundleId = crypto.randomBytes(32);
+      const [archiveBundlePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('archive_bundle'), bundleId],
+        program.programId
+      );
+      const archiver = Keypair.generate().publicKey;
+      const archiverSignature = Array.from(crypto.randomBytes(64));
+
+      await program.methods
+        .archiveApproval(Array.from(bundleId), archiver, archiverSignature)
+        .accounts({
+          archiveBundle: archiveBundlePda,
+          adminConfig: adminConfigPda,
+          admin: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          { pubkey: formApprovalPda, isSigner: false, isWritable: true },
+        ])
+        .rpc();
+
+      const archiveBundle = await program.account.archiveBundle.fetch(
+        archiveBundlePda
+      );
+      expect(archiveBundle.archiver.toString()).to.equal(archiver.toString());
+      expect(
+        archiveBundle.approvals.map((key) => key.toString())
+      ).to.deep.equal([formApprovalPda.toString()]);
+      const formApproval = await program.account.formApproval.fetch(
+        formApprovalPda
+      );
+      expect(formApproval.archived).to.be.true;
+    });
+
+    it('Attests and withdraws a mirror on another chain', async () => {
+      const remoteTxHash = crypto.randomBytes(32);
+      const [mirrorAttestationPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('mirror_attestation'),
+          formApprovalPda.toBuffer(),
+          remoteTxHash,
+        ],
+        program.programId
+      );
+      const attester = Keypair.generate().publicKey;
+
+      await program.methods
+        .recordMirrorAttestation(
+          testFormId,
+          { ethereum: {} },
+          Array.from(remoteTxHash),
+          attester,
+          Array.from(crypto.randomBytes(64))
+        )
+        .accounts({
+          mirrorAttestation: mirrorAttestationPda,
+          formApproval: formApprovalPda,
+          adminConfig: adminConfigPda,
+          admin: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const mirrorAttestation = await program.account.mirrorAttestation.fetch(
+        mirrorAttestationPda
+      );
+      expect(mirrorAttestation.location).to.deep.equal({ ethereum: {} });
+      expect(mirrorAttestation.attester.toString()).to.equal(
+        attester.toString()
+      );
+
+      await program.methods
+        .removeMirrorAttestation(testFormId, Array.from(remoteTxHash))
+        .accounts({
+          mirrorAttestation: mirrorAttestationPda,
+          formApproval: formApprovalPda,
+          adminConfig: adminConfigPda,
+          admin: authority.publicKey,
+        })
+        .rpc();
+      expect(await provider.connection.getAccountInfo(mirrorAttestationPda)).to
+        .be.null;
+    });
+  });
+
+  describe('Annotations', () => {
+    it('Attaches numbered review notes to an approval', async () => {
+      const [annotationLogPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('annotation_log'), formApprovalPda.toBuffer()],
+        program.programId
+      );
+      const annotationPda = (index: number) =>
+        PublicKey.findProgramAddressSync(
+          [
+            Buffer.from('annotation'),
+            formApprovalPda.toBuffer(),
+            new anchor.BN(index).toArrayLike(Buffer, 'le', 2),
+          ],
+          program.programId
+        )[0];
+      const noteHash = crypto
+        .createHash('sha256')
+        .update('Signature page initialled out of order')
+        .digest();
+
+      for (const index of [0, 1]) {
+        await program.methods
+          .addAnnotation(testFormId, Array.from(noteHash), index + 3)
+          .accounts({
+            annotationLog: annotationLogPda,
+            annotation: annotationPda(index),
             formApproval: formApprovalPda,
+            adminConfig: adminConfigPda,
+            admin: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+      }
+
+      const annotationLog = await program.account.annotationLog.fetch(
+        annotationLogPda
+      );
+      const annotation = await program.account.annotation.fetch(
+        annotationPda(1)
+      );
+      expect(annotationLog.count).to.equal(2);
+      expect(annotation.pageRef).to.equal(4);
+      expect(annotation.author.toString()).to.equal(
+        authority.publicKey.toString()
+      );
+    });
+  });
+
+  describe('Access Grants', () => {
+    it('Shares a wrapped metadata key until revoked', async () => {
+      const regulator = Keypair.generate().publicKey;
+      const [accessGrantPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('access_grant'),
+          formApprovalPda.toBuffer(),
+          regulator.toBuffer(),
+        ],
+        program.programId
+      );
+      const wrappedKey = crypto.randomBytes(48);
+      const expiresAt = Math.floor(Date.now() / 1000) + 7 * 24 * 3600;
+
+      await program.methods
+        .grantAccess(
+          testFormId,
+          regulator,
+          wrappedKey,
+          new anchor.BN(expiresAt)
+        )
+        .accounts({
+          accessGrant: accessGrantPda,
+          formApproval: formApprovalPda,
+          adminConfig: adminConfigPda,
+          admin: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const accessGrant = await program.account.accessGrant.fetch(
+        accessGrantPda
+      );
+      expect(Buffer.from(accessGrant.wrappedKey)).to.deep.equal(wrappedKey);
+      expect(accessGrant.expiresAt.toNumber()).to.equal(expiresAt);
+
+      await program.methods
+        .revokeAccess(testFormId, regulator)
+        .accounts({
+          accessGrant: accessGrantPda,
+          formApproval: formApprovalPda,
+          adminConfig: adminConfigPda,
+          grantedBy: authority.publicKey,
+          admin: authority.publicKey,
+        })
+        .rpc();
+      expect(await provider.connection.getAccountInfo(accessGrantPda)).to.be
+        .null;
+    });
+  });
+
+  describe('Escrow', () => {
+    const escrowFormId = 'escrowed_form';
+    const [escrowFormPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('form_approval'), Buffer.from(escrowFormId)],
+      program.programId
+    );
+    const [escrowPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('escrow'),
+        Buffer.from(escrowFormId),
+        authority.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    const counterparty = Keypair.generate();
+    const amount = 10_000_000;
+
+    const settle = (formApproval: PublicKey | null) =>
+      program.methods
+        .settleEscrow(escrowFormId)
+        .accounts({
+          escrow: escrowPda,
+          formApproval,
+          formRejection: null,
+          depositor: authority.publicKey,
+          counterparty: counterparty.publicKey,
+          mint: null,
+          vault: null,
+          recipientTokenAccount: null,
+          tokenProgram: null,
+        })
+        .rpc();
+
+    it('Holds SOL until the form is approved', async () => {
+      await program.methods
+        .createEscrow(
+          escrowFormId,
+          Array.from(testFormHash),
+          counterparty.publicKey,
+          new anchor.BN(amount),
+          new anchor.BN(Math.floor(Date.now() / 1000) + 3600)
+        )
+        .accounts({
+          escrow: escrowPda,
+          depositor: authority.publicKey,
+          mint: null,
+          depositorTokenAccount: null,
+          vault: null,
+          tokenProgram: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      try {
+        await settle(null);
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('EscrowNotSettleable');
+      }
+    });
+
+    it('Releases the deposit to the counterparty on approval', async () => {
+      await program.methods
+        .signFormSubmission(escrowFormId, Array.from(testFormHash), null, null)
+        .accounts({
+          formApproval: escrowFormPda,
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          admin: admin1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin1])
+        .rpc();
+
+      await settle(escrowFormPda);
+      expect(
+        await provider.connection.getBalance(counterparty.publicKey)
+      ).to.equal(amount);
+      expect(await provider.connection.getAccountInfo(escrowPda)).to.be.null;
+    });
+  });
+
+  describe('Rejection', () => {
+    it('Records the reason a submission was declined', async () => {
+      const declinedFormId = 'declined_form';
+      const [formRejectionPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('form_rejection'), Buffer.from(declinedFormId)],
+        program.programId
+      );
+      const [policyCatalogPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('policy_catalog')],
+        program.programId
+      );
+      const catalogHash = crypto
+        .createHash('sha256')
+        .update('{"rejectedIncomplete":"POL-114"}')
+        .digest();
+      await program.methods
+        .setPolicyCatalog(Array.from(catalogHash))
+        .accounts({
+          policyCatalog: policyCatalogPda,
+          adminConfig: adminConfigPda,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const reject = (reasonCode: object) =>
+        program.methods
+          .rejectFormSubmission(
+            declinedFormId,
+            Array.from(testFormHash),
+            reasonCode,
+            'Missing signature page'
+          )
+          .accounts({
+            formRejection: formRejectionPda,
+            adminConfig: adminConfigPda,
+            policyCatalog: policyCatalogPda,
+            admin: admin1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin1])
+          .rpc();
+
+      try {
+        await reject({ approved: {} });
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('InvalidRejectionCode');
+      }
+
+      await reject({ rejectedIncomplete: {} });
+      const formRejection = await program.account.formRejection.fetch(
+        formRejectionPda
+      );
+      expect(formRejection.rejectedBy.toString()).to.equal(
+        admin1.publicKey.toString()
+      );
+      expect(formRejection.reasonCode).to.deep.equal({
+        rejectedIncomplete: {},
+      });
+      expect(formRejection.reason).to.equal('Missing signature page');
+      expect(Buffer.from(formRejection.policyCatalogHash)).to.deep.equal(
+        catalogHash
+      );
+    });
+  });
+
+  describe('Closing', () => {
+    const closedFormId = 'short_lived_form';
+    const [closedFormPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('form_approval'), Buffer.from(closedFormId)],
+      program.programId
+    );
+    const [closedArchivePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('archive_record'), closedFormPda.toBuffer()],
+      program.programId
+    );
+
+    const setRequireArchive = (required: boolean) =>
+      program.methods
+        .setRequireArchiveBeforeClose(required)
+        .accounts({
+          programConfig: programConfigPda,
+          adminConfig: adminConfigPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+
+    const close = (archiveRecord: PublicKey | null) =>
+      program.methods
+        .closeFormApproval(closedFormId)
+        .accounts({
+          formApproval: closedFormPda,
+          archiveRecord,
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          recipient: admin1.publicKey,
+          admin: admin1.publicKey,
+        })
+        .signers([admin1])
+        .rpc();
+
+    it('Closes an approval only once archived when required', async () => {
+      await program.methods
+        .signFormSubmission(closedFormId, Array.from(testFormHash), null, null)
+        .accounts({
+          formApproval: closedFormPda,
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          admin: admin1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin1])
+        .rpc();
+      await setRequireArchive(true);
+
+      try {
+        await close(null);
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('ArchiveRequired');
+      }
+
+      await program.methods
+        .archiveFormApproval(closedFormId, 's3://records/short_lived.json')
+        .accounts({
+          archiveRecord: closedArchivePda,
+          formApproval: closedFormPda,
+          adminConfig: adminConfigPda,
+          admin: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      const balanceBefore = await provider.connection.getBalance(
+        admin1.publicKey
+      );
+      await close(closedArchivePda);
+
+      expect(await provider.connection.getAccountInfo(closedFormPda)).to.be
+        .null;
+      expect(
+        await provider.connection.getBalance(admin1.publicKey)
+      ).to.be.greaterThan(balanceBefore);
+
+      await setRequireArchive(false);
+    });
+  });
+
+  describe('Templates', () => {
+    const templateId = 'nda';
+    const [templateV1Pda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('template_version'),
+        Buffer.from(templateId),
+        new anchor.BN(1).toArrayLike(Buffer, 'le', 4),
+      ],
+      program.programId
+    );
+
+    const signWithTemplate = (
+      formId: string,
+      locale: string,
+      jurisdiction = 'FR'
+    ) =>
+      program.methods
+        .signFormSubmission(formId, Array.from(testFormHash), null, {
+          clientVersion: null,
+          reasonCode: null,
+          timestampTokenHash: null,
+          locale,
+          jurisdiction,
+          compliance: null,
+          clientTimestamp: null,
+          requiredSignatures: null,
+          expiresAt: null,
+          counterparties: null,
+          requiredSigners: null,
+          notBefore: null,
+          notAfter: null,
+          dependsOn: null,
+          documentUri: null,
+          contentType: null,
+          hashAlgorithm: null,
+          formHashTail: null,
+          pageCount: null,
+          allowDuplicateHash: null,
+        })
+        .accounts({
+          formApproval: PublicKey.findProgramAddressSync(
+            [Buffer.from('form_approval'), Buffer.from(formId)],
+            program.programId
+          )[0],
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          templateVersion: templateV1Pda,
+          admin: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    it('Pins the template version on new approvals', async () => {
+      await program.methods
+        .publishTemplateVersion(
+          templateId,
+          1,
+          Array.from(
+            crypto.createHash('sha256').update('nda v1 language').digest()
+          ),
+          ['en-US', 'fr-FR'],
+          ['FR', 'US-NY']
+        )
+        .accounts({
+          templateVersion: templateV1Pda,
+          adminConfig: adminConfigPda,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      await signWithTemplate('templated_form', 'fr-FR');
+
+      const [formPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('form_approval'), Buffer.from('templated_form')],
+        program.programId
+      );
+      const approval = await program.account.formApproval.fetch(formPda);
+      expect(approval.templateVersion.toString()).to.equal(
+        templateV1Pda.toString()
+      );
+      expect(approval.locale).to.equal('fr-FR');
+      expect(approval.jurisdiction).to.equal('FR');
+    });
+
+    it('Rejects jurisdictions the template may not be signed in', async () => {
+      try {
+        await signWithTemplate('californian_templated_form', 'en-US', 'US-CA');
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('JurisdictionNotAllowed');
+      }
+    });
+
+    it('Rejects locales the template is not available in', async () => {
+      try {
+        await signWithTemplate('german_templated_form', 'de-DE');
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('LocaleNotAllowed');
+      }
+
+      try {
+        await signWithTemplate('malformed_locale_form', 'en_US');
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('InvalidLocale');
+      }
+    });
+
+    it('Refuses new signings against a deprecated version', async () => {
+      await program.methods
+        .deprecateTemplateVersion(templateId, 1)
+        .accounts({
+          templateVersion: templateV1Pda,
+          adminConfig: adminConfigPda,
+          authority: authority.publicKey,
+        })
+        .rpc();
+
+      try {
+        await signWithTemplate('late_templated_form', 'en-US');
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('TemplateVersionDeprecated');
+      }
+    });
+  });
+
+  describe('Organizations', () => {
+    const sharedFormId = 'shared_form';
+    const organizationAddress = (orgId: string) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from('organization'), Buffer.from(orgId)],
+        program.programId
+      )[0];
+    const orgFormAddress = (organization: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('org_form_approval'),
+          organization.toBuffer(),
+          Buffer.from(sharedFormId),
+        ],
+        program.programId
+      )[0];
+    const hashIndexAddress = (formHash: Buffer) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from('hash_index'), formHash],
+        program.programId
+      )[0];
+    const tenantA = organizationAddress('tenant_a');
+    const tenantB = organizationAddress('tenant_b');
+
+    it('Lets tenants reuse the same form ID', async () => {
+      await program.methods
+        .createOrganization('tenant_a')
+        .accounts({
+          organization: tenantA,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      await program.methods
+        .createOrganization('tenant_b')
+        .accounts({
+          organization: tenantB,
+          authority: admin1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin1])
+        .rpc();
+
+      const otherFormHash = crypto
+        .createHash('sha256')
+        .update('tenant b form data')
+        .digest();
+      await program.methods
+        .signOrgFormSubmission(
+          sharedFormId,
+          Array.from(testFormHash),
+          null,
+          null
+        )
+        .accounts({
+          formApproval: orgFormAddress(tenantA),
+          organization: tenantA,
+          hashIndex: hashIndexAddress(testFormHash),
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          admin: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      await program.methods
+        .signOrgFormSubmission(
+          sharedFormId,
+          Array.from(otherFormHash),
+          null,
+          null
+        )
+        .accounts({
+          formApproval: orgFormAddress(tenantB),
+          organization: tenantB,
+          hashIndex: hashIndexAddress(otherFormHash),
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          admin: admin1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin1])
+        .rpc();
+
+      const approvalA = await program.account.formApproval.fetch(
+        orgFormAddress(tenantA)
+      );
+      expect(approvalA.organization.toString()).to.equal(tenantA.toString());
+      expect(approvalA.chainIndex.toNumber()).to.be.greaterThan(0);
+      const otherHashIndex = await program.account.hashIndex.fetch(
+        hashIndexAddress(otherFormHash)
+      );
+      expect(otherHashIndex.approval.toString()).to.equal(
+        orgFormAddress(tenantB).toString()
+      );
+      const organizationA = await program.account.organization.fetch(tenantA);
+      expect(organizationA.totalFormsSigned.toNumber()).to.equal(1);
+      expect(organizationA.lastSignedAt.toNumber()).to.equal(
+        approvalA.approvedAt.toNumber()
+      );
+      expect(
+        await program.methods
+          .verifyOrgFormApproval(sharedFormId, Array.from(otherFormHash))
+          .accounts({
+            formApproval: orgFormAddress(tenantB),
+            organization: tenantB,
+          })
+          .view()
+      ).to.be.true;
+      expect(
+        await program.methods
+          .verifyOrgFormApproval(sharedFormId, Array.from(otherFormHash))
+          .accounts({
+            formApproval: orgFormAddress(tenantA),
+            organization: tenantA,
+          })
+          .view()
+      ).to.be.false;
+    });
+
+    it('Only lets the organization admins sign its forms', async () => {
+      const [intruderFormPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('org_form_approval'),
+          tenantA.toBuffer(),
+          Buffer.from('intruder_form'),
+        ],
+        program.programId
+      );
+
+      const signAsAdmin1 = () =>
+        program.methods
+          .signOrgFormSubmission(
+            'intruder_form',
+            Array.from(testFormHash),
+            null,
+            null
+          )
+          .accounts({
+            formApproval: intruderFormPda,
+            organization: tenantA,
+            hashIndex: hashIndexAddress(testFormHash),
+            adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
+            admin: admin1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin1])
+          .rpc();
+
+      try {
+        await signAsAdmin1();
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('UnauthorizedAdmin');
+      }
+
+      await program.methods
+        .addOrgAdmin(admin1.publicKey)
+        .accounts({ organization: tenantA, authority: authority.publicKey })
+        .rpc();
+      await signAsAdmin1();
+
+      const organization = await program.account.organization.fetch(tenantA);
+      expect(organization.admins.map((key) => key.toString())).to.deep.equal([
+        admin1.publicKey.toString(),
+      ]);
+    });
+
+    it('Rejects organization approvals that need co-signers', async () => {
+      const [multiSignFormPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('org_form_approval'),
+          tenantA.toBuffer(),
+          Buffer.from('multi_sign_form'),
+        ],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .signOrgFormSubmission(
+            'multi_sign_form',
+            Array.from(testFormHash),
+            null,
+            {
+              clientVersion: null,
+              reasonCode: null,
+              timestampTokenHash: null,
+              locale: null,
+              jurisdiction: null,
+              compliance: null,
+              clientTimestamp: null,
+              requiredSignatures: 2,
+              expiresAt: null,
+              counterparties: null,
+              requiredSigners: null,
+              notBefore: null,
+              notAfter: null,
+              dependsOn: null,
+              documentUri: null,
+              contentType: null,
+              hashAlgorithm: null,
+              formHashTail: null,
+              pageCount: null,
+              allowDuplicateHash: null,
+            }
+          )
+          .accounts({
+            formApproval: multiSignFormPda,
+            organization: tenantA,
+            hashIndex: hashIndexAddress(testFormHash),
+            adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
+            admin: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('OrgCoSigningUnsupported');
+      }
+    });
+
+    it('Rejects observers registered under another tenant', async () => {
+      const [tenantAObserverPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('org_observer'),
+          tenantA.toBuffer(),
+          Buffer.from(sharedFormId),
+          authority.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+      await program.methods
+        .registerOrgObserver(sharedFormId)
+        .accounts({
+          formObserver: tenantAObserverPda,
+          organization: tenantA,
+          observer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      try {
+        await program.methods
+          .revokeOrgFormApproval(sharedFormId, 'Superseded')
+          .accounts({
+            formApproval: orgFormAddress(tenantB),
+            organization: tenantB,
+            admin: admin1.publicKey,
           })
+          .remainingAccounts([
+            { pubkey: tenantAObserverPda, isSigner: false, isWritable: false },
+          ])
+          .signers([admin1])
+          .rpc();
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('InvalidObserverAccount');
+      }
+
+      await program.methods
+        .unregisterOrgObserver(sharedFormId)
+        .accounts({
+          formObserver: tenantAObserverPda,
+          observer: authority.publicKey,
+        })
+        .rpc();
+    });
+
+    it('Counts revocations on the organization', async () => {
+      await program.methods
+        .revokeOrgFormApproval(sharedFormId, 'Superseded')
+        .accounts({
+          formApproval: orgFormAddress(tenantB),
+          organization: tenantB,
+          admin: admin1.publicKey,
+        })
+        .signers([admin1])
+        .rpc();
+
+      const organizationB = await program.account.organization.fetch(tenantB);
+      expect(organizationB.totalFormsSigned.toNumber()).to.equal(1);
+      expect(organizationB.totalRevoked.toNumber()).to.equal(1);
+
+      const [formHistoryPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('form_history'), orgFormAddress(tenantB).toBuffer()],
+        program.programId
+      );
+      const formHistory = await program.account.formHistory.fetch(
+        formHistoryPda
+      );
+      const latest = formHistory.entries[formHistory.entries.length - 1];
+      expect(latest.action).to.deep.equal({ revoked: {} });
+      expect(latest.actor.toString()).to.equal(admin1.publicKey.toString());
+    });
+
+    it('Holds cross-tenant approvals until the counter-party signs', async () => {
+      const contractFormId = 'b2b_contract';
+      const contractHash = crypto
+        .createHash('sha256')
+        .update('b2b contract data')
+        .digest();
+      const [contractPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('org_form_approval'),
+          tenantA.toBuffer(),
+          Buffer.from(contractFormId),
+        ],
+        program.programId
+      );
+      const verifyContract = () =>
+        program.methods
+          .verifyOrgFormApproval(contractFormId, Array.from(contractHash))
+          .accounts({ formApproval: contractPda, organization: tenantA })
           .view();
 
-      expect(formId).to.equal(testFormId);
-      expect(Buffer.from(formHash)).to.deep.equal(testFormHash);
-      expect(signer.toString()).to.equal(admin1.publicKey.toString());
-      expect(approvedAt.toNumber()).to.be.greaterThan(0);
-      expect(metadata).to.equal('Updated metadata'); // From previous test
+      await program.methods
+        .signOrgFormSubmission(
+          contractFormId,
+          Array.from(contractHash),
+          null,
+          null
+        )
+        .accounts({
+          formApproval: contractPda,
+          organization: tenantA,
+          counterpartyOrganization: tenantB,
+          hashIndex: hashIndexAddress(contractHash),
+          adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
+          admin: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      let contract = await program.account.formApproval.fetch(contractPda);
+      expect(contract.counterpartyOrg.toString()).to.equal(tenantB.toString());
+      expect(contract.status).to.deep.equal({ pending: {} });
+      expect(await verifyContract()).to.be.false;
+
+      try {
+        await program.methods
+          .countersignOrgFormApproval(contractFormId)
+          .accounts({
+            formApproval: contractPda,
+            organization: tenantA,
+            counterpartyOrganization: tenantB,
+            programConfig: programConfigPda,
+            admin: authority.publicKey,
+          })
+          .rpc();
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('UnauthorizedAdmin');
+      }
+
+      await program.methods
+        .countersignOrgFormApproval(contractFormId)
+        .accounts({
+          formApproval: contractPda,
+          organization: tenantA,
+          counterpartyOrganization: tenantB,
+          programConfig: programConfigPda,
+          admin: admin1.publicKey,
+        })
+        .signers([admin1])
+        .rpc();
+
+      contract = await program.account.formApproval.fetch(contractPda);
+      expect(contract.counterpartyOrgSigner.toString()).to.equal(
+        admin1.publicKey.toString()
+      );
+      expect(contract.status).to.deep.equal({ approved: {} });
+      expect(await verifyContract()).to.be.true;
+    });
+
+    it('Suspends and reinstates an organization', async () => {
+      const reasonHash = crypto
+        .createHash('sha256')
+        .update('Abuse report #42')
+        .digest();
+      const suspensionAccounts = {
+        organization: tenantA,
+        adminConfig: adminConfigPda,
+        authority: authority.publicKey,
+      };
+
+      await program.methods
+        .suspendOrganization(Array.from(reasonHash))
+        .accounts(suspensionAccounts)
+        .rpc();
+
+      let organizationA = await program.account.organization.fetch(tenantA);
+      expect(organizationA.suspendedAt.toNumber()).to.be.greaterThan(0);
+      expect(Buffer.from(organizationA.suspensionReasonHash)).to.deep.equal(
+        reasonHash
+      );
+
+      try {
+        await program.methods
+          .addOrgAdmin(admin2.publicKey)
+          .accounts({ organization: tenantA, authority: authority.publicKey })
+          .rpc();
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('OrgSuspended');
+      }
+
+      // Verification keeps working while the organization is suspended
+      expect(
+        await program.methods
+          .verifyOrgFormApproval(sharedFormId, Array.from(testFormHash))
+          .accounts({
+            formApproval: orgFormAddress(tenantA),
+            organization: tenantA,
+          })
+          .view()
+      ).to.be.true;
+
+      await program.methods
+        .reinstateOrganization()
+        .accounts(suspensionAccounts)
+        .rpc();
+
+      organizationA = await program.account.organization.fetch(tenantA);
+      expect(organizationA.suspendedAt.toNumber()).to.equal(0);
+
+      try {
+        await program.methods
+          .reinstateOrganization()
+          .accounts(suspensionAccounts)
+          .rpc();
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('OrgNotSuspended');
+      }
+    });
+
+    it('Only lets the organization authority manage its admins', async () => {
+      try {
+        await program.methods
+          .addOrgAdmin(admin2.publicKey)
+          .accounts({ organization: tenantB, authority: authority.publicKey })
+          .rpc();
+
+        expect.fail('Should have thrown an error');
+      } catch (error) {
+        expect(error.toString()).to.include('UnauthorizedAdmin');
+      }
     });
   });
 
@@ -405,7 +5346,9 @@ describe('sign-document-contract', () => {
           .accounts({
             formApproval: formApprovalPda,
             adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
             admin: admin1.publicKey,
+            systemProgram: SystemProgram.programId,
           })
           .signers([admin1])
           .rpc();
@@ -424,10 +5367,16 @@ describe('sign-document-contract', () => {
       );
 
       await program.methods
-        .signFormSubmission(noMetadataFormId, Array.from(testFormHash), null)
+        .signFormSubmission(
+          noMetadataFormId,
+          Array.from(testFormHash),
+          null,
+          null
+        )
         .accounts({
           formApproval: noMetadataFormPda,
           adminConfig: adminConfigPda,
+          programConfig: programConfigPda,
           admin: admin1.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -443,10 +5392,11 @@ describe('sign-document-contract', () => {
     it('Prevents double approval of the same form', async () => {
       try {
         await program.methods
-          .signFormSubmission(testFormId, Array.from(testFormHash), null)
+          .signFormSubmission(testFormId, Array.from(testFormHash), null, null)
           .accounts({
             formApproval: formApprovalPda,
             adminConfig: adminConfigPda,
+            programConfig: programConfigPda,
             admin: admin1.publicKey,
             systemProgram: SystemProgram.programId,
           })
@@ -27,6 +27,18 @@ export class ContractUtils {
     );
   }
 
+  /**
+   * Derive the program config PDA
+   * @param programId - The program ID
+   * @returns [PDA, bump]
+   */
+  static deriveProgramConfigPda(programId: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from('program_config')],
+      programId
+    );
+  }
+
   /**
    * Derive the form approval PDA
    * @param formId - The form ID
@@ -0,0 +1,52 @@
+use std::env;
+use std::net::SocketAddr;
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+use sign_document_sdk::solana_sdk::pubkey::Pubkey;
+use sign_document_sdk::PROGRAM_ID;
+
+/// Service configuration read from the environment
+pub struct ServiceConfig {
+    /// Solana RPC endpoint (`VERIFY_API_RPC_URL`)
+    pub rpc_url: String,
+
+    /// Address to listen on (`VERIFY_API_BIND`)
+    pub bind: SocketAddr,
+
+    /// Keypair file used to sign verdicts (`VERIFY_API_KEYPAIR`)
+    pub keypair_path: String,
+
+    /// Program deployment to verify against (`VERIFY_API_PROGRAM_ID`)
+    pub program_id: Pubkey,
+}
+
+impl ServiceConfig {
+    /// Load the configuration, falling back to localnet defaults
+    pub fn from_env() -> Result<Self> {
+        let rpc_url =
+            env::var("VERIFY_API_RPC_URL").unwrap_or_else(|_| "http://127.0.0.1:8899".into());
+
+        let bind = env::var("VERIFY_API_BIND")
+            .unwrap_or_else(|_| "0.0.0.0:8080".into())
+            .parse()
+            .context("VERIFY_API_BIND is not a valid socket address")?;
+
+        let keypair_path = env::var("VERIFY_API_KEYPAIR")
+            .context("VERIFY_API_KEYPAIR must point to the verdict signing keypair")?;
+
+        let program_id = match env::var("VERIFY_API_PROGRAM_ID") {
+            Ok(id) => {
+                Pubkey::from_str(&id).context("VERIFY_API_PROGRAM_ID is not a valid pubkey")?
+            }
+            Err(_) => PROGRAM_ID,
+        };
+
+        Ok(Self {
+            rpc_url,
+            bind,
+            keypair_path,
+            program_id,
+        })
+    }
+}
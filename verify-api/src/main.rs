@@ -0,0 +1,133 @@
+//! HTTP verification service for form approvals.
+//!
+//! Exposes `GET /verify?form_id=&hash=[&organization=]` so systems without Solana tooling can
+//! check a document hash against the on-chain approval and receive a verdict
+//! signed by the service key.
+
+mod config;
+mod verdict;
+
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Context};
+use axum::extract::{Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use serde::Deserialize;
+use serde_json::json;
+use sign_document_sdk::solana_client::nonblocking::rpc_client::RpcClient;
+use sign_document_sdk::solana_sdk::commitment_config::CommitmentConfig;
+use sign_document_sdk::solana_sdk::pubkey::Pubkey;
+use sign_document_sdk::solana_sdk::signature::{read_keypair_file, Keypair, Signer};
+use sign_document_sdk::SignDocumentClient;
+
+use config::ServiceConfig;
+use verdict::Verdict;
+
+struct AppState {
+    client: SignDocumentClient,
+    keypair: Keypair,
+}
+
+#[derive(Deserialize)]
+struct VerifyQuery {
+    form_id: String,
+    hash: String,
+    /// Organization whose namespace holds the form (global namespace if absent)
+    organization: Option<String>,
+}
+
+/// Error response with a JSON body
+struct ApiError(StatusCode, String);
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.0, Json(json!({ "error": self.1 }))).into_response()
+    }
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let config = ServiceConfig::from_env()?;
+    let keypair = read_keypair_file(&config.keypair_path)
+        .map_err(|err| anyhow!("failed to read {}: {err}", config.keypair_path))?;
+
+    let rpc = RpcClient::new_with_commitment(config.rpc_url.clone(), CommitmentConfig::confirmed());
+    let state = Arc::new(AppState {
+        client: SignDocumentClient::with_program_id(rpc, config.program_id),
+        keypair,
+    });
+
+    let app = Router::new()
+        .route("/health", get(health))
+        .route("/verify", get(verify))
+        .with_state(state);
+
+    let listener = tokio::net::TcpListener::bind(config.bind)
+        .await
+        .with_context(|| format!("failed to bind {}", config.bind))?;
+    println!("verify-api listening on {}", config.bind);
+    axum::serve(listener, app).await?;
+    Ok(())
+}
+
+async fn health(State(state): State<Arc<AppState>>) -> Json<serde_json::Value> {
+    Json(json!({
+        "status": "ok",
+        "program_id": state.client.program_id().to_string(),
+        "service_key": state.keypair.pubkey().to_string(),
+    }))
+}
+
+async fn verify(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<VerifyQuery>,
+) -> Result<Json<verdict::SignedVerdict>, ApiError> {
+    let expected_hash = parse_hash(&query.hash)?;
+
+    let verification = match query.organization.as_deref() {
+        Some(organization) => {
+            let organization = Pubkey::from_str(organization).map_err(|_| {
+                ApiError(
+                    StatusCode::BAD_REQUEST,
+                    "organization must be a base58 address".into(),
+                )
+            })?;
+            state
+                .client
+                .verify_org_form(&organization, &query.form_id, &expected_hash)
+                .await
+        }
+        None => {
+            state
+                .client
+                .verify_form(&query.form_id, &expected_hash)
+                .await
+        }
+    }
+    .map_err(|err| ApiError(StatusCode::BAD_GATEWAY, err.to_string()))?;
+
+    let verdict = Verdict::from_verification(&verification, &expected_hash, unix_now());
+    Ok(Json(verdict.sign(&state.keypair)))
+}
+
+/// Parse a hex-encoded 32-byte digest
+fn parse_hash(hash: &str) -> Result<[u8; 32], ApiError> {
+    let bytes = hex::decode(hash.trim_start_matches("0x"))
+        .map_err(|_| ApiError(StatusCode::BAD_REQUEST, "hash must be hex encoded".into()))?;
+
+    bytes
+        .try_into()
+        .map_err(|_| ApiError(StatusCode::BAD_REQUEST, "hash must be 32 bytes".into()))
+}
+
+fn unix_now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs() as i64)
+        .unwrap_or_default()
+}
@@ -0,0 +1,125 @@
+use serde::Serialize;
+use sign_document_sdk::solana_sdk::signature::{Keypair, Signer};
+use sign_document_sdk::{ApprovalStatus, ChainVerification};
+
+/// Outcome of a verification request
+#[derive(Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum VerdictStatus {
+    /// An approval exists and its hash matches
+    Approved,
+
+    /// An approval exists but was signed over a different hash
+    HashMismatch,
+
+    /// An approval exists but was revoked
+    Revoked,
+
+    /// An approval exists but is still collecting co-signatures
+    Pending,
+
+    /// An approval exists but was reviewed and declined
+    Rejected,
+
+    /// An approval exists but its validity lapsed
+    Expired,
+
+    /// No approval exists for the form ID
+    NotFound,
+}
+
+/// Verification result as seen by the service at a given slot
+#[derive(Serialize)]
+pub struct Verdict {
+    pub form_id: String,
+    pub expected_hash: String,
+    pub approval_address: String,
+    pub status: VerdictStatus,
+    pub valid: bool,
+    pub signer: Option<String>,
+    pub approved_at: Option<i64>,
+    pub revocation_reason: Option<String>,
+    pub document_uri: Option<String>,
+    pub content_type: Option<String>,
+    pub outstanding_signers: Vec<String>,
+    pub slot: u64,
+    pub checked_at: i64,
+}
+
+/// Verdict plus the service's signature over its compact JSON encoding
+#[derive(Serialize)]
+pub struct SignedVerdict {
+    pub verdict: Verdict,
+    pub signature: String,
+    pub service_key: String,
+}
+
+impl Verdict {
+    /// Build a verdict from an SDK verification result
+    pub fn from_verification(
+        verification: &ChainVerification,
+        expected_hash: &[u8; 32],
+        checked_at: i64,
+    ) -> Self {
+        let status = match (&verification.approval, verification.is_valid) {
+            (None, _) => VerdictStatus::NotFound,
+            (Some(_), true) => VerdictStatus::Approved,
+            (Some(approval), false) if approval.is_revoked() => VerdictStatus::Revoked,
+            (Some(approval), false) if approval.is_expired(checked_at) => VerdictStatus::Expired,
+            (Some(approval), false) => match approval.status {
+                ApprovalStatus::Pending => VerdictStatus::Pending,
+                ApprovalStatus::Rejected => VerdictStatus::Rejected,
+                ApprovalStatus::Expired => VerdictStatus::Expired,
+                ApprovalStatus::Revoked => VerdictStatus::Revoked,
+                ApprovalStatus::Approved => VerdictStatus::HashMismatch,
+            },
+        };
+
+        Self {
+            form_id: verification.form_id.clone(),
+            expected_hash: hex::encode(expected_hash),
+            approval_address: verification.approval_address.to_string(),
+            status,
+            valid: verification.is_valid,
+            signer: verification.approval.as_ref().map(|a| a.signer.to_string()),
+            approved_at: verification.approval.as_ref().map(|a| a.approved_at),
+            revocation_reason: verification
+                .approval
+                .as_ref()
+                .filter(|a| a.is_revoked())
+                .map(|a| a.revocation_reason.clone()),
+            document_uri: verification
+                .approval
+                .as_ref()
+                .filter(|a| !a.document_uri.is_empty())
+                .map(|a| a.document_uri.clone()),
+            content_type: verification
+                .approval
+                .as_ref()
+                .filter(|a| !a.content_type.is_empty())
+                .map(|a| a.content_type.clone()),
+            outstanding_signers: verification
+                .approval
+                .as_ref()
+                .map(|a| a.signing_progress().outstanding)
+                .unwrap_or_default()
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            slot: verification.slot,
+            checked_at,
+        }
+    }
+
+    /// Sign the verdict so it can be relayed and checked independently
+    pub fn sign(self, keypair: &Keypair) -> SignedVerdict {
+        let payload = serde_json::to_vec(&self).expect("verdict serializes to JSON");
+        let signature = keypair.sign_message(&payload);
+
+        SignedVerdict {
+            verdict: self,
+            signature: signature.to_string(),
+            service_key: keypair.pubkey().to_string(),
+        }
+    }
+}